@@ -0,0 +1,16 @@
+-- User-defined output templates (minijinja syntax)
+
+CREATE TABLE IF NOT EXISTS summary_templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TRIGGER IF NOT EXISTS update_summary_templates_timestamp
+    AFTER UPDATE ON summary_templates
+    FOR EACH ROW
+BEGIN
+    UPDATE summary_templates SET updated_at = datetime('now') WHERE id = OLD.id;
+END;
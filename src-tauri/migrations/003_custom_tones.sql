@@ -0,0 +1,16 @@
+-- User-defined LLM tones; built-in tones live in code and are read-only
+
+CREATE TABLE IF NOT EXISTS custom_tones (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    template TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TRIGGER IF NOT EXISTS update_custom_tones_timestamp
+    AFTER UPDATE ON custom_tones
+    FOR EACH ROW
+BEGIN
+    UPDATE custom_tones SET updated_at = datetime('now') WHERE id = OLD.id;
+END;
@@ -0,0 +1,3 @@
+-- Fact-check problems found in the generated narrative (JSON array of strings)
+
+ALTER TABLE daily_summaries ADD COLUMN narrative_warnings TEXT DEFAULT '[]';
@@ -0,0 +1,6 @@
+-- Structured (JSON) narrative mode
+
+ALTER TABLE settings ADD COLUMN llm_output_format TEXT DEFAULT 'prose';
+
+-- Parsed sections when the narrative was generated in structured mode (JSON or NULL)
+ALTER TABLE daily_summaries ADD COLUMN narrative_structured TEXT;
@@ -0,0 +1,3 @@
+-- Configurable Ollama context window; prompts are budgeted to fit it
+
+ALTER TABLE settings ADD COLUMN llm_num_ctx INTEGER DEFAULT 4096;
@@ -0,0 +1,19 @@
+-- Named redaction rule sets applied before the LLM prompt and per delivery target
+
+CREATE TABLE IF NOT EXISTS redaction_profiles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    rules TEXT NOT NULL DEFAULT '{}',  -- JSON RedactionRules
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE TRIGGER IF NOT EXISTS update_redaction_profiles_timestamp
+    AFTER UPDATE ON redaction_profiles
+    FOR EACH ROW
+BEGIN
+    UPDATE redaction_profiles SET updated_at = datetime('now') WHERE id = OLD.id;
+END;
+
+-- Profile applied to data before it is sent to the LLM (NULL = none)
+ALTER TABLE settings ADD COLUMN llm_redaction_profile_id INTEGER;
@@ -0,0 +1,4 @@
+-- Optional JQL filter replacing "assignee = currentUser()" in the Jira search.
+-- jira_project_key may now hold several comma-separated keys.
+
+ALTER TABLE settings ADD COLUMN jira_jql TEXT;
@@ -0,0 +1,5 @@
+-- Jira Server/Data Center support: Bearer personal access tokens instead of
+-- Cloud's email + API token, and an optional extra CA certificate (PEM path)
+
+ALTER TABLE settings ADD COLUMN jira_auth_mode TEXT DEFAULT 'cloud';
+ALTER TABLE settings ADD COLUMN jira_ca_cert_path TEXT;
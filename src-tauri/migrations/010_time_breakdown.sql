@@ -0,0 +1,5 @@
+-- Tracked time per category and project, and the user's rules for which
+-- projects/clients/tags count as focus, meetings or admin time
+
+ALTER TABLE daily_summaries ADD COLUMN time_breakdown TEXT NOT NULL DEFAULT '{}';  -- JSON TimeBreakdown
+ALTER TABLE settings ADD COLUMN time_categories TEXT NOT NULL DEFAULT '{}';       -- JSON TimeCategories
@@ -0,0 +1,8 @@
+-- Clockify and Harvest as alternatives to Toggl. Base URLs are NULL for the
+-- public APIs and can point at self-hosted or stub servers.
+
+ALTER TABLE settings ADD COLUMN time_source TEXT NOT NULL DEFAULT 'toggl';  -- 'toggl', 'clockify', 'harvest'
+ALTER TABLE settings ADD COLUMN clockify_workspace_id TEXT;
+ALTER TABLE settings ADD COLUMN clockify_base_url TEXT;
+ALTER TABLE settings ADD COLUMN harvest_account_id TEXT;
+ALTER TABLE settings ADD COLUMN harvest_base_url TEXT;
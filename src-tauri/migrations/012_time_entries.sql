@@ -0,0 +1,26 @@
+-- Built-in timer. Timestamps are UTC RFC 3339 so they compare as text.
+
+CREATE TABLE IF NOT EXISTS time_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    description TEXT NOT NULL DEFAULT '',
+    jira_key TEXT,
+    project TEXT,
+    tags TEXT NOT NULL DEFAULT '[]',  -- JSON array of strings
+    started_at TEXT NOT NULL,
+    stopped_at TEXT,  -- NULL while running
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_time_entries_started_at ON time_entries(started_at);
+
+-- Only one timer can run at a time
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_running
+    ON time_entries((stopped_at IS NULL)) WHERE stopped_at IS NULL;
+
+CREATE TRIGGER IF NOT EXISTS update_time_entries_timestamp
+    AFTER UPDATE ON time_entries
+    FOR EACH ROW
+BEGIN
+    UPDATE time_entries SET updated_at = datetime('now') WHERE id = OLD.id;
+END;
@@ -0,0 +1,15 @@
+-- Quick-capture activity log. logged_at is UTC RFC 3339 so it compares as text.
+
+CREATE TABLE IF NOT EXISTS log_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    logged_at TEXT NOT NULL,
+    text TEXT NOT NULL,
+    ticket_key TEXT,
+    tags TEXT NOT NULL DEFAULT '[]',  -- JSON array of strings
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_log_entries_logged_at ON log_entries(logged_at);
+
+-- The day's notes as they were when the summary was generated (JSON LogEntry[])
+ALTER TABLE daily_summaries ADD COLUMN log_entries TEXT NOT NULL DEFAULT '[]';
@@ -0,0 +1,5 @@
+-- Meeting classification rules and working hours, and how each day's calendar
+-- split between collaboration, focus blocks and uninterrupted time
+
+ALTER TABLE settings ADD COLUMN meeting_rules TEXT NOT NULL DEFAULT '{}';                -- JSON MeetingRules
+ALTER TABLE daily_summaries ADD COLUMN calendar_breakdown TEXT NOT NULL DEFAULT '{}';    -- JSON CalendarBreakdown
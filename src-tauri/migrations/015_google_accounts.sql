@@ -0,0 +1,7 @@
+-- Connected Google accounts; their refresh tokens are in the encrypted vault
+
+CREATE TABLE IF NOT EXISTS google_accounts (
+    email TEXT PRIMARY KEY,
+    calendar_ids TEXT NOT NULL DEFAULT '[]',  -- JSON array; empty means the primary calendar only
+    connected_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
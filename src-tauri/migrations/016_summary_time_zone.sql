@@ -0,0 +1,7 @@
+-- The time zone and day start summary days are cut by, and the ones each
+-- summary was generated with
+
+ALTER TABLE settings ADD COLUMN time_zone TEXT;                                 -- IANA name; NULL = system zone
+ALTER TABLE settings ADD COLUMN day_start TEXT NOT NULL DEFAULT '00:00';        -- HH:MM
+ALTER TABLE daily_summaries ADD COLUMN time_zone TEXT NOT NULL DEFAULT 'system'; -- IANA name or UTC offset
+ALTER TABLE daily_summaries ADD COLUMN day_start TEXT NOT NULL DEFAULT '00:00';
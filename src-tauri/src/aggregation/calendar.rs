@@ -1,21 +1,84 @@
+use crate::aggregation::day::SummaryDay;
+use crate::aggregation::meetings::{EventFacts, MeetingRules};
 use crate::aggregation::Meeting;
 use crate::error::AppError;
-use chrono::Local;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::time::Duration;
 
+const API_BASE: &str = "https://www.googleapis.com/calendar/v3";
+
+/// Events requested per page (Google allows up to 2500)
+const PAGE_SIZE: usize = 250;
+
+/// Stop paging after this many pages
+const MAX_PAGES: usize = 20;
+
+/// A connected Google account and the calendars that count toward meetings
+#[derive(Debug, Clone)]
+pub struct CalendarAccount {
+    pub email: String,
+    pub access_token: String,
+    /// Empty means the account's primary calendar
+    pub calendar_ids: Vec<String>,
+}
+
+/// Connected accounts: those with a fresh access token, and those whose
+/// token couldn't be refreshed
+#[derive(Debug, Clone, Default)]
+pub struct CalendarAccounts {
+    pub usable: Vec<CalendarAccount>,
+    /// "email: error" for each account left out
+    pub skipped: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
-struct CalendarResponse {
+#[serde(rename_all = "camelCase")]
+struct EventsPage {
+    #[serde(default)]
     items: Vec<CalendarEvent>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CalendarListPage {
+    #[serde(default)]
+    items: Vec<CalendarListEntry>,
+    next_page_token: Option<String>,
+}
+
+/// A calendar the account can see
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarListEntry {
+    pub id: String,
+    #[serde(default)]
+    pub summary: String,
+    /// The account's own calendar; its ID is the account's email address
+    #[serde(default)]
+    pub primary: bool,
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct CalendarEvent {
+    id: Option<String>,
+    /// Shared by the copies of an event on different calendars
+    #[serde(rename = "iCalUID")]
+    ical_uid: Option<String>,
     summary: Option<String>,
     start: EventDateTime,
     end: EventDateTime,
     attendees: Option<Vec<Attendee>>,
+    organizer: Option<Person>,
+    visibility: Option<String>,
+    event_type: Option<String>,
+    hangout_link: Option<String>,
+    conference_data: Option<serde_json::Value>,
+    location: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,128 +88,424 @@ struct EventDateTime {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct Attendee {
-    #[serde(rename = "responseStatus")]
+    email: Option<String>,
+    /// True for the calendar owner's own entry
+    #[serde(rename = "self", default)]
+    is_self: bool,
+    /// Rooms and other resources
+    #[serde(default)]
+    resource: bool,
     response_status: Option<String>,
 }
 
-/// Fetch today's calendar events from Google Calendar
-pub async fn fetch_events_today(access_token: &str) -> Result<Vec<Meeting>, AppError> {
-    let client = Client::builder()
-        .timeout(Duration::from_secs(10))
-        .connect_timeout(Duration::from_secs(10))
-        .build()
-        .map_err(|_e| {
-            AppError::CalendarUnauthorized // Treat build errors as auth issues
-        })?;
-
-    // Get today's start and end in UTC
-    let today = Local::now().date_naive();
-    let start_of_day = today
-        .and_hms_opt(0, 0, 0)
-        .ok_or_else(|| AppError::CalendarError("Cannot create start of day timestamp".to_string()))?
-        .and_local_timezone(Local)
-        .earliest()
-        .ok_or_else(|| {
-            AppError::CalendarError("Cannot convert start of day to local timezone".to_string())
-        })?
-        .to_rfc3339();
-    let end_of_day = today
-        .and_hms_opt(23, 59, 59)
-        .ok_or_else(|| AppError::CalendarError("Cannot create end of day timestamp".to_string()))?
-        .and_local_timezone(Local)
-        .earliest()
-        .ok_or_else(|| {
-            AppError::CalendarError("Cannot convert end of day to local timezone".to_string())
-        })?
-        .to_rfc3339();
+#[derive(Debug, Deserialize)]
+struct Person {
+    email: Option<String>,
+    #[serde(rename = "self", default)]
+    is_self: bool,
+}
 
-    let url = format!(
-        "https://www.googleapis.com/calendar/v3/calendars/primary/events?timeMin={}&timeMax={}&singleEvents=true&maxResults=50",
-        urlencoding::encode(&start_of_day),
-        urlencoding::encode(&end_of_day)
-    );
+/// Hosts whose links in the location count as a conferencing link
+const CONFERENCE_HOSTS: &[&str] = &["meet.google.com", "zoom.us", "teams.microsoft.com"];
 
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("Accept", "application/json")
-        .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                AppError::NetworkTimeout("Google Calendar request timed out".to_string())
-            } else if e.is_connect() {
-                AppError::CalendarError(
-                    "Cannot reach Google Calendar API. Check your internet connection.".to_string(),
-                )
-            } else {
-                AppError::CalendarError(format!("Request failed: {}", e))
-            }
-        })?;
-
-    let status = response.status();
-    if status == 401 || status == 403 {
-        return Err(AppError::CalendarUnauthorized);
-    } else if !status.is_success() {
-        return Err(AppError::CalendarError(format!(
-            "Google Calendar API returned error: HTTP {}",
-            status
-        )));
+impl CalendarEvent {
+    /// Same for every copy of the event; recurring instances differ by start.
+    /// The start is compared in UTC since each calendar may report its own offset.
+    fn dedupe_key(&self) -> Option<String> {
+        let uid = self.ical_uid.as_ref().or(self.id.as_ref())?;
+        let start = self.start.date_time.as_deref().unwrap_or_default();
+        let start = DateTime::parse_from_rfc3339(start)
+            .map(|t| t.with_timezone(&Utc).to_rfc3339())
+            .unwrap_or_else(|_| start.to_string());
+        Some(format!("{}@{}", uid, start))
+    }
+
+    fn declined_by_me(&self) -> bool {
+        self.attendees
+            .iter()
+            .flatten()
+            .any(|a| a.is_self && a.response_status.as_deref() == Some("declined"))
     }
 
-    let calendar_response: CalendarResponse = response.json().await.map_err(|e| {
-        AppError::CalendarError(format!("Failed to parse calendar response: {}", e))
-    })?;
+    /// None for all-day events and events the user declined
+    fn into_meeting(self, calendar_id: &str, rules: &MeetingRules) -> Option<Meeting> {
+        if self.declined_by_me() {
+            return None;
+        }
+        let start = self.start.date_time?;
+        let end = self.end.date_time?;
+        let attendees = self.attendees.unwrap_or_default();
+
+        let duration_minutes = match (
+            chrono::DateTime::parse_from_rfc3339(&start),
+            chrono::DateTime::parse_from_rfc3339(&end),
+        ) {
+            (Ok(s), Ok(e)) => (e.timestamp() - s.timestamp()) / 60,
+            _ => 0,
+        };
+
+        let title = self
+            .summary
+            .unwrap_or_else(|| "Untitled meeting".to_string());
+        let organizer = self.organizer.as_ref().and_then(|o| o.email.clone());
+        // Google leaves the organizer out of events only the user is in
+        let organized_by_me = self.organizer.as_ref().map_or(true, |o| o.is_self);
+        let guests: Vec<&str> = attendees
+            .iter()
+            .filter(|a| !a.is_self && !a.resource)
+            .filter(|a| a.response_status.as_deref() != Some("declined"))
+            .filter_map(|a| a.email.as_deref())
+            .collect();
+        let user_email = attendees
+            .iter()
+            .find(|a| a.is_self)
+            .and_then(|a| a.email.as_deref())
+            .or_else(|| organizer.as_deref().filter(|_| organized_by_me));
+
+        let kind = rules.classify(&EventFacts {
+            title: &title,
+            event_type: self.event_type.as_deref().unwrap_or("default"),
+            guests: guests.clone(),
+            organizer: organizer.as_deref(),
+            organized_by_me,
+            user_email,
+        });
+        let has_conference_link = self.hangout_link.is_some()
+            || self.conference_data.is_some()
+            || self
+                .location
+                .as_deref()
+                .is_some_and(|l| CONFERENCE_HOSTS.iter().any(|host| l.contains(host)));
 
+        Some(Meeting {
+            attendee_count: guests.len() as u32,
+            title,
+            start,
+            end,
+            duration_minutes: duration_minutes as i32,
+            visibility: self.visibility,
+            calendar_id: Some(calendar_id.to_string()),
+            organizer,
+            organized_by_me,
+            has_conference_link,
+            kind,
+        })
+    }
+}
+
+/// Turn each calendar's events into meetings, oldest first. An event on
+/// several calendars is kept once, from the first calendar it appears on, and
+/// dropped entirely if any copy shows the user declined it.
+fn merge_events(
+    calendars: Vec<(String, Vec<CalendarEvent>)>,
+    rules: &MeetingRules,
+) -> Vec<Meeting> {
+    let declined: HashSet<String> = calendars
+        .iter()
+        .flat_map(|(_, events)| events)
+        .filter(|event| event.declined_by_me())
+        .filter_map(CalendarEvent::dedupe_key)
+        .collect();
+
+    let mut seen = HashSet::new();
     let mut meetings = Vec::new();
+    for (calendar_id, events) in calendars {
+        for event in events {
+            if let Some(key) = event.dedupe_key() {
+                if declined.contains(&key) || !seen.insert(key) {
+                    continue;
+                }
+            }
+            meetings.extend(event.into_meeting(&calendar_id, rules));
+        }
+    }
+
+    let start_of = |m: &Meeting| {
+        chrono::DateTime::parse_from_rfc3339(&m.start)
+            .map(|t| t.timestamp())
+            .unwrap_or_default()
+    };
+    meetings.sort_by_key(start_of);
+    meetings
+}
+
+struct GoogleClient<'a> {
+    client: Client,
+    access_token: &'a str,
+}
+
+impl GoogleClient<'_> {
+    fn new(access_token: &str) -> Result<GoogleClient<'_>, AppError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|_e| {
+                AppError::CalendarUnauthorized // Treat build errors as auth issues
+            })?;
+        Ok(GoogleClient {
+            client,
+            access_token,
+        })
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, AppError> {
+        let response = self
+            .client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", self.access_token))
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AppError::NetworkTimeout("Google Calendar request timed out".to_string())
+                } else if e.is_connect() {
+                    AppError::CalendarError(
+                        "Cannot reach Google Calendar API. Check your internet connection."
+                            .to_string(),
+                    )
+                } else {
+                    AppError::CalendarError(format!("Request failed: {}", e))
+                }
+            })?;
 
-    for event in calendar_response.items {
-        // Skip all-day events (they have date but not dateTime)
-        if event.start.date_time.is_none() {
-            continue;
+        let status = response.status();
+        if status == 401 || status == 403 {
+            return Err(AppError::CalendarUnauthorized);
+        } else if status == 404 {
+            return Err(AppError::CalendarError(
+                "Calendar not found. Check the selected calendars in Settings.".to_string(),
+            ));
+        } else if !status.is_success() {
+            return Err(AppError::CalendarError(format!(
+                "Google Calendar API returned error: HTTP {}",
+                status
+            )));
         }
 
-        // Skip declined events
-        if let Some(attendees) = &event.attendees {
-            let user_declined = attendees.iter().any(|a| {
-                a.response_status
-                    .as_ref()
-                    .map(|s| s == "declined")
-                    .unwrap_or(false)
-            });
-            if user_declined {
-                continue;
+        response.json().await.map_err(|e| {
+            AppError::CalendarError(format!("Failed to parse calendar response: {}", e))
+        })
+    }
+
+    /// Every page of `url`; `next` returns the page's items and next page token
+    async fn get_all<P, T>(
+        &self,
+        url: &str,
+        next: impl Fn(P) -> (Vec<T>, Option<String>),
+    ) -> Result<Vec<T>, AppError>
+    where
+        P: serde::de::DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut page_token: Option<String> = None;
+        for _ in 0..MAX_PAGES {
+            let page_url = match &page_token {
+                Some(token) => format!("{}&pageToken={}", url, urlencoding::encode(token)),
+                None => url.to_string(),
+            };
+            let (batch, token) = next(self.get(&page_url).await?);
+            items.extend(batch);
+            match token {
+                Some(token) => page_token = Some(token),
+                None => break,
             }
         }
+        Ok(items)
+    }
+}
+
+/// Calendars the account can see, its primary calendar first
+pub async fn fetch_calendar_list(access_token: &str) -> Result<Vec<CalendarListEntry>, AppError> {
+    let google = GoogleClient::new(access_token)?;
+    let url = format!(
+        "{}/users/me/calendarList?maxResults={}",
+        API_BASE, PAGE_SIZE
+    );
+    let mut calendars = google
+        .get_all(&url, |page: CalendarListPage| {
+            (page.items, page.next_page_token)
+        })
+        .await?;
+    calendars.sort_by_key(|c| !c.primary);
+    Ok(calendars)
+}
 
-        let start = event
-            .start
-            .date_time
-            .ok_or_else(|| AppError::CalendarError("Event missing start dateTime".to_string()))?;
-        let end = event
-            .end
-            .date_time
-            .ok_or_else(|| AppError::CalendarError("Event missing end dateTime".to_string()))?;
-
-        // Calculate duration
-        let start_dt = chrono::DateTime::parse_from_rfc3339(&start).ok();
-        let end_dt = chrono::DateTime::parse_from_rfc3339(&end).ok();
-        let duration_minutes = if let (Some(s), Some(e)) = (start_dt, end_dt) {
-            (e.timestamp() - s.timestamp()) / 60
-        } else {
-            0
+/// Fetch the events of `day` from every selected calendar of every account,
+/// classified by `rules`. A calendar that can't be read is skipped so the
+/// others still count; the second value lists the skipped ones.
+pub async fn fetch_events_today(
+    accounts: &[CalendarAccount],
+    rules: &MeetingRules,
+    day: &SummaryDay,
+) -> Result<(Vec<Meeting>, Vec<String>), AppError> {
+    // timeMax is exclusive, like the day's end
+    let (start_of_day, end_of_day) = day.utc_bounds();
+    // Event times come back in the summary zone rather than each calendar's
+    let time_zone = day
+        .boundary
+        .iana_name()
+        .map(|zone| format!("&timeZone={}", urlencoding::encode(zone)))
+        .unwrap_or_default();
+
+    let mut calendars = Vec::new();
+    let mut skipped = Vec::new();
+    let mut last_error = None;
+    for account in accounts {
+        let google = GoogleClient::new(&account.access_token)?;
+        let primary = ["primary".to_string()];
+        let calendar_ids = match account.calendar_ids.as_slice() {
+            [] => &primary[..],
+            ids => ids,
         };
+        for calendar_id in calendar_ids {
+            let url = format!(
+                "{}/calendars/{}/events?timeMin={}&timeMax={}&singleEvents=true&orderBy=startTime&maxResults={}{}",
+                API_BASE,
+                urlencoding::encode(calendar_id),
+                urlencoding::encode(&start_of_day),
+                urlencoding::encode(&end_of_day),
+                PAGE_SIZE,
+                time_zone
+            );
+            let events = match google
+                .get_all(&url, |page: EventsPage| (page.items, page.next_page_token))
+                .await
+            {
+                Ok(events) => events,
+                Err(e) => {
+                    let message = match &e {
+                        AppError::CalendarError(message) => message.clone(),
+                        e => e.to_string(),
+                    };
+                    let failure = format!("{} ({}): {}", account.email, calendar_id, message);
+                    eprintln!("[Google] Skipping {}", failure);
+                    skipped.push(failure.clone());
+                    last_error = Some(AppError::CalendarError(failure));
+                    continue;
+                }
+            };
+            // "primary" is reported as the account's address, which redaction
+            // rules and deduplication can recognise
+            let id = if calendar_id == "primary" {
+                account.email.clone()
+            } else {
+                calendar_id.clone()
+            };
+            calendars.push((id, events));
+        }
+    }
 
-        meetings.push(Meeting {
-            title: event
-                .summary
-                .unwrap_or_else(|| "Untitled meeting".to_string()),
-            start,
-            end,
-            duration_minutes: duration_minutes as i32,
-        });
+    // Nothing could be read: report the source as failed
+    if let (true, Some(e)) = (calendars.is_empty(), last_error) {
+        return Err(e);
     }
+    Ok((merge_events(calendars, rules), skipped))
+}
 
-    Ok(meetings)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::meetings::MeetingKind;
+
+    #[test]
+    fn maps_attendees_organizer_and_conference_link() {
+        let page: EventsPage = serde_json::from_str(
+            r#"{"items": [
+                {"summary": "Roadmap review", "eventType": "default",
+                 "start": {"dateTime": "2026-02-14T10:00:00Z"},
+                 "end": {"dateTime": "2026-02-14T10:45:00Z"},
+                 "organizer": {"email": "lead@acme.com"},
+                 "hangoutLink": "https://meet.google.com/abc-defg-hij",
+                 "attendees": [
+                    {"email": "me@acme.com", "self": true, "responseStatus": "accepted"},
+                    {"email": "lead@acme.com", "organizer": true, "responseStatus": "accepted"},
+                    {"email": "dev@acme.com", "responseStatus": "declined"},
+                    {"email": "room-4@resource.calendar.google.com", "resource": true}
+                 ]},
+                {"summary": "Skipped", "start": {"dateTime": "2026-02-14T12:00:00Z"},
+                 "end": {"dateTime": "2026-02-14T13:00:00Z"},
+                 "attendees": [{"email": "me@acme.com", "self": true, "responseStatus": "declined"}]},
+                {"summary": "Holiday", "start": {"date": "2026-02-14"}, "end": {"date": "2026-02-15"}},
+                {"summary": "Writing", "start": {"dateTime": "2026-02-14T14:00:00Z"},
+                 "end": {"dateTime": "2026-02-14T16:00:00Z"},
+                 "organizer": {"email": "me@acme.com", "self": true}}
+            ]}"#,
+        )
+        .expect("events page should parse");
+        let meetings = merge_events(
+            vec![("me@acme.com".to_string(), page.items)],
+            &MeetingRules::default(),
+        );
+
+        assert_eq!(meetings.len(), 2);
+        assert_eq!(meetings[0].attendee_count, 1);
+        assert_eq!(meetings[0].organizer.as_deref(), Some("lead@acme.com"));
+        assert!(!meetings[0].organized_by_me);
+        assert!(meetings[0].has_conference_link);
+        assert_eq!(meetings[0].kind, MeetingKind::OneOnOne);
+        assert_eq!(meetings[1].kind, MeetingKind::FocusBlock);
+        assert_eq!(meetings[1].duration_minutes, 120);
+        assert_eq!(meetings[1].calendar_id.as_deref(), Some("me@acme.com"));
+    }
+
+    #[test]
+    fn dedupes_events_across_calendars() {
+        let page = |json: &str| {
+            serde_json::from_str::<EventsPage>(json)
+                .expect("events page should parse")
+                .items
+        };
+        let primary = page(
+            r#"{"items": [
+                {"id": "a1", "iCalUID": "standup@acme.com", "summary": "Standup",
+                 "start": {"dateTime": "2026-02-14T09:30:00Z"},
+                 "end": {"dateTime": "2026-02-14T09:45:00Z"}},
+                {"id": "b1", "iCalUID": "offsite@acme.com", "summary": "Offsite prep",
+                 "start": {"dateTime": "2026-02-14T08:00:00Z"},
+                 "end": {"dateTime": "2026-02-14T09:00:00Z"},
+                 "attendees": [{"email": "me@acme.com", "self": true, "responseStatus": "declined"}]}
+            ], "nextPageToken": null}"#,
+        );
+        let team = page(
+            r#"{"items": [
+                {"id": "x9", "iCalUID": "standup@acme.com", "summary": "Standup",
+                 "start": {"dateTime": "2026-02-14T09:30:00Z"},
+                 "end": {"dateTime": "2026-02-14T09:45:00Z"}},
+                {"id": "x8", "iCalUID": "standup@acme.com", "summary": "Standup",
+                 "start": {"dateTime": "2026-02-15T09:30:00Z"},
+                 "end": {"dateTime": "2026-02-15T09:45:00Z"}},
+                {"id": "y2", "iCalUID": "offsite@acme.com", "summary": "Offsite prep",
+                 "start": {"dateTime": "2026-02-14T09:00:00+01:00"},
+                 "end": {"dateTime": "2026-02-14T10:00:00+01:00"}}
+            ]}"#,
+        );
+
+        let meetings = merge_events(
+            vec![
+                ("me@acme.com".to_string(), primary),
+                ("team@group.calendar.google.com".to_string(), team),
+            ],
+            &MeetingRules::default(),
+        );
+        let seen: Vec<(&str, &str)> = meetings
+            .iter()
+            .map(|m| {
+                (
+                    m.start.as_str(),
+                    m.calendar_id.as_deref().unwrap_or_default(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            seen,
+            [
+                ("2026-02-14T09:30:00Z", "me@acme.com"),
+                ("2026-02-15T09:30:00Z", "team@group.calendar.google.com"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,237 @@
+use super::day::SummaryDay;
+use crate::aggregation::time::TimeEntry;
+use crate::error::AppError;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Public Clockify API; overridable in settings for self-hosted or stub servers
+pub const DEFAULT_BASE_URL: &str = "https://api.clockify.me";
+
+/// Entries requested per page
+const PAGE_SIZE: usize = 200;
+
+/// Stop paging after this many pages
+const MAX_PAGES: usize = 20;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClockifyUser {
+    id: String,
+    active_workspace: Option<String>,
+}
+
+/// `hydrated=true` entry: project, client and tag names are inlined
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClockifyTimeEntry {
+    description: Option<String>,
+    time_interval: ClockifyInterval,
+    project: Option<ClockifyProject>,
+    #[serde(default)]
+    tags: Option<Vec<ClockifyTag>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClockifyInterval {
+    start: String,
+    /// None while the timer is running
+    end: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClockifyProject {
+    name: String,
+    client_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClockifyTag {
+    name: String,
+}
+
+impl ClockifyTimeEntry {
+    /// The part of this entry inside `[from, to)`; a running timer counts up to `now`
+    fn into_time_entry(
+        self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> TimeEntry {
+        let start = DateTime::parse_from_rfc3339(&self.time_interval.start)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or(now);
+        let end = self
+            .time_interval
+            .end
+            .as_deref()
+            .and_then(|end| DateTime::parse_from_rfc3339(end).ok())
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or(now);
+
+        TimeEntry {
+            description: self.description.unwrap_or_default(),
+            start: self.time_interval.start,
+            stop: self.time_interval.end,
+            duration_seconds: (end.min(to) - start.max(from)).num_seconds().max(0),
+            project: self.project.as_ref().map(|p| p.name.clone()),
+            client: self.project.and_then(|p| p.client_name),
+            tags: self
+                .tags
+                .unwrap_or_default()
+                .into_iter()
+                .map(|t| t.name)
+                .collect(),
+        }
+    }
+}
+
+struct ClockifyClient<'a> {
+    client: Client,
+    base_url: &'a str,
+    api_key: &'a str,
+}
+
+impl ClockifyClient<'_> {
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, AppError> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.base_url, path))
+            .header("X-Api-Key", self.api_key)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AppError::NetworkTimeout("Clockify request timed out".to_string())
+                } else if e.is_connect() {
+                    AppError::ClockifyError(
+                        "Cannot reach Clockify API. Check your internet connection.".to_string(),
+                    )
+                } else {
+                    AppError::ClockifyError(format!("Request failed: {}", e))
+                }
+            })?;
+
+        let status = response.status();
+        if status == 401 || status == 403 {
+            return Err(AppError::ClockifyError(
+                "Authentication failed. Check your API key in Settings.".to_string(),
+            ));
+        } else if status == 404 {
+            return Err(AppError::ClockifyError(
+                "Workspace not found. Check the workspace ID in Settings.".to_string(),
+            ));
+        } else if !status.is_success() {
+            return Err(AppError::ClockifyError(format!(
+                "Clockify API returned error: HTTP {}",
+                status
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| AppError::ClockifyError(format!("Failed to parse response: {}", e)))
+    }
+}
+
+/// Fetch the user's Clockify time entries for a summary day. A blank
+/// `workspace_id` uses the user's active workspace.
+pub async fn fetch_time_entries(
+    base_url: &str,
+    api_key: &str,
+    workspace_id: &str,
+    day: &SummaryDay,
+) -> Result<Vec<TimeEntry>, AppError> {
+    let clockify = ClockifyClient {
+        client: Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| AppError::NotConfigured(format!("HTTP client error: {}", e)))?,
+        base_url: base_url.trim_end_matches('/'),
+        api_key,
+    };
+
+    let user: ClockifyUser = clockify.get("/api/v1/user").await?;
+    let workspace_id = match workspace_id.trim() {
+        "" => user.active_workspace.ok_or_else(|| {
+            AppError::NotConfigured("Clockify workspace ID not configured".to_string())
+        })?,
+        id => id.to_string(),
+    };
+
+    let (day_start, day_end) = day.utc_bounds();
+
+    let now = Utc::now();
+    let mut entries = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let path = format!(
+            "/api/v1/workspaces/{}/user/{}/time-entries?start={}&end={}&hydrated=true&page={}&page-size={}",
+            urlencoding::encode(&workspace_id),
+            urlencoding::encode(&user.id),
+            urlencoding::encode(&day_start),
+            urlencoding::encode(&day_end),
+            page,
+            PAGE_SIZE
+        );
+        let batch: Vec<ClockifyTimeEntry> = clockify.get(&path).await?;
+        let last_page = batch.len() < PAGE_SIZE;
+        entries.extend(
+            batch
+                .into_iter()
+                .map(|e| e.into_time_entry(day.start, day.end, now)),
+        );
+        if last_page {
+            break;
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_hydrated_entries() {
+        let entries: Vec<ClockifyTimeEntry> = serde_json::from_str(
+            r#"[
+                {"description": "PROJ-7 export", "timeInterval": {"start": "2026-02-14T09:00:00Z",
+                 "end": "2026-02-14T10:30:00Z", "duration": "PT1H30M"},
+                 "project": {"name": "Billing", "clientName": "Acme"},
+                 "tags": [{"id": "t1", "name": "deep-work"}]},
+                {"description": null, "timeInterval": {"start": "2026-02-14T11:00:00Z",
+                 "end": null, "duration": null}, "project": null, "tags": null},
+                {"description": "late night", "timeInterval": {"start": "2026-02-13T23:00:00Z",
+                 "end": "2026-02-14T01:00:00Z", "duration": "PT2H"}}
+            ]"#,
+        )
+        .expect("entries should parse");
+        let time = |t: &str| {
+            DateTime::parse_from_rfc3339(t)
+                .expect("valid timestamp")
+                .with_timezone(&Utc)
+        };
+        let (from, to) = (time("2026-02-14T00:00:00Z"), time("2026-02-15T00:00:00Z"));
+        let now = time("2026-02-14T11:20:00Z");
+        let mapped: Vec<TimeEntry> = entries
+            .into_iter()
+            .map(|e| e.into_time_entry(from, to, now))
+            .collect();
+
+        assert_eq!(mapped[0].duration_seconds, 5400);
+        assert_eq!(mapped[0].project.as_deref(), Some("Billing"));
+        assert_eq!(mapped[0].client.as_deref(), Some("Acme"));
+        assert_eq!(mapped[0].tags, ["deep-work"]);
+        // Running timer counts up to now
+        assert_eq!(mapped[1].duration_seconds, 1200);
+        assert_eq!(mapped[1].stop, None);
+        // Only the hour after midnight belongs to the day
+        assert_eq!(mapped[2].duration_seconds, 3600);
+    }
+}
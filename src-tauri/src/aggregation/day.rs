@@ -0,0 +1,192 @@
+//! The summary time zone and day boundary. Every source uses the same
+//! `SummaryDay`, so a meeting, a ticket update and a time entry all land on
+//! the same day however far the user is from their Jira server or UTC.
+
+use crate::error::AppError;
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat,
+    TimeZone, Utc,
+};
+use chrono_tz::Tz;
+
+/// Latest accepted day start; later would put most of a day on the previous date
+const MAX_DAY_START_HOUR: u32 = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Zone {
+    /// Whatever the computer is set to
+    System,
+    Named(Tz),
+}
+
+/// How summary days are cut: a time zone and the wall-clock time a day
+/// starts, e.g. 04:00 for someone who works past midnight
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayBoundary {
+    zone: Zone,
+    start: NaiveTime,
+}
+
+impl Default for DayBoundary {
+    fn default() -> Self {
+        DayBoundary {
+            zone: Zone::System,
+            start: NaiveTime::MIN,
+        }
+    }
+}
+
+/// `naive` in `tz` as UTC. A wall-clock time skipped by a DST change counts
+/// as the first valid time after it.
+fn resolve<Z: TimeZone>(tz: &Z, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+    [naive, naive + Duration::hours(1)]
+        .into_iter()
+        .find_map(|t| tz.from_local_datetime(&t).earliest())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+impl DayBoundary {
+    /// A blank `time_zone` means the system zone; `day_start` is `HH:MM`
+    pub fn new(time_zone: Option<&str>, day_start: &str) -> Result<DayBoundary, AppError> {
+        let zone = match time_zone.map(str::trim).filter(|z| !z.is_empty()) {
+            None => Zone::System,
+            Some(name) => Zone::Named(name.parse().map_err(|_| {
+                AppError::NotConfigured(format!(
+                    "Unknown time zone '{}'. Use a name like Europe/Berlin.",
+                    name
+                ))
+            })?),
+        };
+        let start = NaiveTime::parse_from_str(day_start.trim(), "%H:%M").map_err(|_| {
+            AppError::NotConfigured(format!("Invalid day start '{}'. Use HH:MM.", day_start))
+        })?;
+        if start >= NaiveTime::from_hms_opt(MAX_DAY_START_HOUR, 0, 0).expect("valid time") {
+            return Err(AppError::NotConfigured(format!(
+                "The day must start before {}:00",
+                MAX_DAY_START_HOUR
+            )));
+        }
+        Ok(DayBoundary { zone, start })
+    }
+
+    /// The configured IANA zone; None when following the system zone
+    pub fn iana_name(&self) -> Option<&'static str> {
+        match self.zone {
+            Zone::System => None,
+            Zone::Named(tz) => Some(tz.name()),
+        }
+    }
+
+    /// `HH:MM` the day starts
+    pub fn day_start(&self) -> String {
+        self.start.format("%H:%M").to_string()
+    }
+
+    /// `date` at wall-clock `time` in the summary zone, as UTC
+    pub fn at(&self, date: NaiveDate, time: NaiveTime) -> Option<DateTime<Utc>> {
+        let naive = date.and_time(time);
+        match self.zone {
+            Zone::System => resolve(&Local, naive),
+            Zone::Named(tz) => resolve(&tz, naive),
+        }
+    }
+
+    /// `instant` in the summary zone
+    pub fn localize(&self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self.zone {
+            Zone::System => instant.with_timezone(&Local).fixed_offset(),
+            Zone::Named(tz) => instant.with_timezone(&tz).fixed_offset(),
+        }
+    }
+
+    /// The summary day `instant` belongs to
+    pub fn date_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        let wall_clock = self.localize(instant).naive_local();
+        (wall_clock - (self.start - NaiveTime::MIN)).date()
+    }
+
+    pub fn day(&self, date: NaiveDate) -> Result<SummaryDay, AppError> {
+        let next_day = date.succ_opt().ok_or_else(|| {
+            AppError::NotConfigured("Cannot calculate tomorrow's date".to_string())
+        })?;
+        let start_of = |date: NaiveDate| {
+            self.at(date, self.start).ok_or_else(|| {
+                AppError::NotConfigured(format!("Cannot find the start of {}", date))
+            })
+        };
+        Ok(SummaryDay {
+            date,
+            start: start_of(date)?,
+            end: start_of(next_day)?,
+            boundary: *self,
+        })
+    }
+
+    /// The summary day in progress
+    pub fn today(&self) -> Result<SummaryDay, AppError> {
+        self.day(self.date_of(Utc::now()))
+    }
+}
+
+/// One summary day: its date and the instants it runs between
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SummaryDay {
+    pub date: NaiveDate,
+    /// Inclusive
+    pub start: DateTime<Utc>,
+    /// Exclusive; the next day's start
+    pub end: DateTime<Utc>,
+    pub boundary: DayBoundary,
+}
+
+impl SummaryDay {
+    /// Zone stored with the summary: the IANA name, or the system zone's
+    /// offset at the start of the day such as "UTC+02:00"
+    pub fn zone_label(&self) -> String {
+        match self.boundary.iana_name() {
+            Some(name) => name.to_string(),
+            None => format!("UTC{}", self.boundary.localize(self.start).offset()),
+        }
+    }
+
+    pub fn contains(&self, instant: DateTime<Utc>) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// RFC 3339 bounds with a `Z` suffix, for APIs that take UTC
+    pub fn utc_bounds(&self) -> (String, String) {
+        let format = |t: DateTime<Utc>| t.to_rfc3339_opts(SecondsFormat::Secs, true);
+        (format(self.start), format(self.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().expect("valid timestamp")
+    }
+
+    #[test]
+    fn night_owl_day_in_a_named_zone() {
+        let boundary = DayBoundary::new(Some("Asia/Kolkata"), "04:00").expect("valid boundary");
+        let date = NaiveDate::from_ymd_opt(2026, 2, 14).expect("valid date");
+        let day = boundary.day(date).expect("day should resolve");
+
+        // 04:00 IST is 22:30 UTC the evening before
+        assert_eq!(day.start, utc("2026-02-13T22:30:00Z"));
+        assert_eq!(day.end, utc("2026-02-14T22:30:00Z"));
+        assert_eq!(day.utc_bounds().0, "2026-02-13T22:30:00Z".to_string());
+
+        // 02:00 IST on the 15th still belongs to the 14th
+        assert_eq!(boundary.date_of(utc("2026-02-14T20:30:00Z")), date);
+        assert!(day.contains(utc("2026-02-14T20:30:00Z")));
+        assert!(!day.contains(utc("2026-02-14T22:30:00Z")));
+        assert_eq!(day.zone_label(), "Asia/Kolkata");
+
+        assert!(DayBoundary::new(Some("Mars/Olympus"), "00:00").is_err());
+        assert!(DayBoundary::new(None, "13:00").is_err());
+        assert!(DayBoundary::new(Some(" "), "4am").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use crate::aggregation::time::TimeEntry;
+use crate::error::AppError;
+use chrono::{DateTime, NaiveDate};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Public Harvest API; overridable in settings for stub servers
+pub const DEFAULT_BASE_URL: &str = "https://api.harvestapp.com";
+
+/// Entries requested per page (Harvest allows up to 2000)
+const PAGE_SIZE: usize = 500;
+
+/// Stop paging after this many pages
+const MAX_PAGES: usize = 20;
+
+#[derive(Debug, Deserialize)]
+struct HarvestUser {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarvestPage {
+    time_entries: Vec<HarvestTimeEntry>,
+    next_page: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarvestTimeEntry {
+    notes: Option<String>,
+    /// Decimal hours; includes the elapsed time of a running timer
+    hours: f64,
+    #[serde(default)]
+    is_running: bool,
+    timer_started_at: Option<String>,
+    created_at: String,
+    project: Option<HarvestNamed>,
+    client: Option<HarvestNamed>,
+    task: Option<HarvestNamed>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarvestNamed {
+    name: String,
+}
+
+impl HarvestTimeEntry {
+    /// Harvest has no tags; the task ("Development", "Meetings", …) is used
+    /// as one so categories can match it
+    fn into_time_entry(self) -> TimeEntry {
+        let duration_seconds = (self.hours * 3600.0).round() as i64;
+        let start = self.timer_started_at.unwrap_or(self.created_at);
+        let stop = if self.is_running {
+            None
+        } else {
+            DateTime::parse_from_rfc3339(&start)
+                .ok()
+                .map(|t| (t + chrono::Duration::seconds(duration_seconds)).to_rfc3339())
+        };
+
+        TimeEntry {
+            description: self.notes.unwrap_or_default(),
+            start,
+            stop,
+            duration_seconds,
+            project: self.project.map(|p| p.name),
+            client: self.client.map(|c| c.name),
+            tags: self.task.map(|t| t.name).into_iter().collect(),
+        }
+    }
+}
+
+struct HarvestClient<'a> {
+    client: Client,
+    base_url: &'a str,
+    access_token: &'a str,
+    account_id: &'a str,
+}
+
+impl HarvestClient<'_> {
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T, AppError> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.base_url, path))
+            .header("Authorization", format!("Bearer {}", self.access_token))
+            .header("Harvest-Account-Id", self.account_id)
+            .header("User-Agent", "WorkdayDebrief")
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AppError::NetworkTimeout("Harvest request timed out".to_string())
+                } else if e.is_connect() {
+                    AppError::HarvestError(
+                        "Cannot reach Harvest API. Check your internet connection.".to_string(),
+                    )
+                } else {
+                    AppError::HarvestError(format!("Request failed: {}", e))
+                }
+            })?;
+
+        let status = response.status();
+        if status == 401 || status == 403 {
+            return Err(AppError::HarvestError(
+                "Authentication failed. Check your access token and account ID in Settings."
+                    .to_string(),
+            ));
+        } else if !status.is_success() {
+            return Err(AppError::HarvestError(format!(
+                "Harvest API returned error: HTTP {}",
+                status
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| AppError::HarvestError(format!("Failed to parse response: {}", e)))
+    }
+}
+
+/// Fetch the user's Harvest time entries for a given day
+pub async fn fetch_time_entries(
+    base_url: &str,
+    access_token: &str,
+    account_id: &str,
+    date: NaiveDate,
+) -> Result<Vec<TimeEntry>, AppError> {
+    if account_id.trim().is_empty() {
+        return Err(AppError::NotConfigured(
+            "Harvest account ID not configured".to_string(),
+        ));
+    }
+
+    let harvest = HarvestClient {
+        client: Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| AppError::NotConfigured(format!("HTTP client error: {}", e)))?,
+        base_url: base_url.trim_end_matches('/'),
+        access_token,
+        account_id: account_id.trim(),
+    };
+
+    // Harvest returns the whole account's entries to admins; keep only ours
+    let user: HarvestUser = harvest.get("/v2/users/me").await?;
+
+    let mut entries = Vec::new();
+    let mut page = 1;
+    for _ in 0..MAX_PAGES {
+        let path = format!(
+            "/v2/time_entries?user_id={}&from={}&to={}&page={}&per_page={}",
+            user.id, date, date, page, PAGE_SIZE
+        );
+        let batch: HarvestPage = harvest.get(&path).await?;
+        entries.extend(
+            batch
+                .time_entries
+                .into_iter()
+                .map(HarvestTimeEntry::into_time_entry),
+        );
+        match batch.next_page {
+            Some(next) => page = next,
+            None => break,
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_entries_with_task_as_tag() {
+        let page: HarvestPage = serde_json::from_str(
+            r#"{"time_entries": [
+                {"id": 1, "spent_date": "2026-02-14", "hours": 1.25, "notes": "PROJ-7 export",
+                 "is_running": false, "timer_started_at": "2026-02-14T09:00:00Z",
+                 "created_at": "2026-02-14T08:59:00Z",
+                 "project": {"id": 5, "name": "Billing"}, "client": {"id": 9, "name": "Acme"},
+                 "task": {"id": 3, "name": "Development"}},
+                {"id": 2, "spent_date": "2026-02-14", "hours": 0.5, "notes": null,
+                 "is_running": true, "timer_started_at": null,
+                 "created_at": "2026-02-14T13:00:00Z", "project": null, "client": null, "task": null}
+            ], "next_page": null}"#,
+        )
+        .expect("page should parse");
+        let entries: Vec<TimeEntry> = page
+            .time_entries
+            .into_iter()
+            .map(HarvestTimeEntry::into_time_entry)
+            .collect();
+
+        assert_eq!(entries[0].duration_seconds, 4500);
+        assert_eq!(entries[0].tags, ["Development"]);
+        assert_eq!(entries[0].client.as_deref(), Some("Acme"));
+        assert_eq!(
+            entries[0].stop.as_deref(),
+            Some("2026-02-14T10:15:00+00:00")
+        );
+        assert_eq!(entries[1].start, "2026-02-14T13:00:00Z");
+        assert_eq!(entries[1].stop, None);
+    }
+}
@@ -1,18 +1,147 @@
-use crate::aggregation::Ticket;
+use super::day::SummaryDay;
+use crate::aggregation::{StatusTransition, Ticket, TicketActivity};
 use crate::error::AppError;
-use reqwest::Client;
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+/// Fields requested for every issue; the changelog is requested via `expand`
+const SEARCH_FIELDS: &str = "summary,status,resolutiondate,security,comment,worklog";
+
+/// Issues requested per page (Jira caps this at 100)
+const PAGE_SIZE: usize = 100;
+
+/// Stop paging after this many pages so a broad custom JQL can't run forever
+const MAX_PAGES: usize = 20;
+
+/// Default filter when no custom JQL is configured
+const DEFAULT_FILTER: &str = "assignee = currentUser()";
+
+/// Which issues to fetch: one or more projects, or a user-supplied JQL filter
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JiraQuery {
+    pub project_keys: Vec<String>,
+    pub jql: Option<String>,
+}
+
+impl JiraQuery {
+    /// Build from settings. `project_keys` is a comma or space separated list,
+    /// e.g. "PROJ, OPS"; a blank `jql` means no override.
+    pub fn from_settings(project_keys: Option<&str>, jql: Option<&str>) -> JiraQuery {
+        JiraQuery {
+            project_keys: project_keys
+                .unwrap_or_default()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|k| !k.is_empty())
+                .map(|k| k.to_uppercase())
+                .collect(),
+            jql: jql
+                .map(str::trim)
+                .filter(|q| !q.is_empty())
+                .map(str::to_string),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.project_keys.is_empty() && self.jql.is_none()
+    }
+
+    /// Reject project keys Jira could never have issued
+    pub fn validate(&self) -> Result<(), AppError> {
+        for key in &self.project_keys {
+            let valid = key.starts_with(|c: char| c.is_ascii_alphabetic())
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                return Err(AppError::NotConfigured(format!(
+                    "Invalid Jira project key '{}'",
+                    key
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The search JQL. A custom filter replaces the default
+    /// `assignee = currentUser()` and is wrapped in parentheses; the project
+    /// list, the update window and ordering are always appended. The window is
+    /// relative so it doesn't depend on the Jira user's time zone.
+    pub fn to_jql(&self, updated_within: chrono::Duration) -> String {
+        let mut clauses = vec![match &self.jql {
+            Some(custom) => format!("({})", strip_order_by(custom)),
+            None => DEFAULT_FILTER.to_string(),
+        }];
+
+        if !self.project_keys.is_empty() {
+            let keys: Vec<String> = self.project_keys.iter().map(|k| quote_jql(k)).collect();
+            clauses.push(format!("project in ({})", keys.join(", ")));
+        }
+
+        // Whole minutes, rounded up so the day's first update isn't missed
+        let minutes = (updated_within.num_seconds() + 59) / 60;
+        clauses.push(format!("updated >= \"-{}m\"", minutes.max(1)));
+        format!("{} ORDER BY updated DESC", clauses.join(" AND "))
+    }
+}
+
+/// Quote a value as a JQL string literal
+pub fn quote_jql(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Drop a trailing ORDER BY so the filter can be combined with other clauses
+fn strip_order_by(jql: &str) -> &str {
+    let upper = jql.to_ascii_uppercase();
+    match upper.rfind("ORDER BY") {
+        // Only when outside a quoted string: an even number of quotes before it
+        Some(i) if jql[..i].matches('"').count() % 2 == 0 => jql[..i].trim_end(),
+        _ => jql.trim_end(),
+    }
+}
+
+/// Jira Cloud's `/rest/api/3/search/jql` response: token-based paging
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JqlSearchPage {
+    issues: Vec<JiraIssue>,
+    next_page_token: Option<String>,
+    #[serde(default)]
+    is_last: Option<bool>,
+}
+
+impl JqlSearchPage {
+    fn next_token(&self) -> Option<&str> {
+        match self.is_last {
+            Some(true) => None,
+            _ => self.next_page_token.as_deref(),
+        }
+    }
+}
+
+/// Classic `/rest/api/2/search` response: offset paging
 #[derive(Debug, Deserialize)]
-struct JiraResponse {
+#[serde(rename_all = "camelCase")]
+struct OffsetSearchPage {
     issues: Vec<JiraIssue>,
+    #[serde(default)]
+    start_at: usize,
+    #[serde(default)]
+    total: usize,
+}
+
+impl OffsetSearchPage {
+    fn next_start(&self) -> Option<usize> {
+        let next = self.start_at + self.issues.len();
+        (!self.issues.is_empty() && next < self.total).then_some(next)
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct JiraIssue {
     key: String,
     fields: JiraFields,
+    #[serde(default)]
+    changelog: Option<JiraChangelog>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -20,98 +149,628 @@ struct JiraFields {
     summary: String,
     status: JiraStatus,
     resolutiondate: Option<String>,
+    security: Option<JiraSecurityLevel>,
+    #[serde(default)]
+    comment: Option<JiraComments>,
+    #[serde(default)]
+    worklog: Option<JiraWorklogs>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraSecurityLevel {
+    name: String,
 }
 
 #[derive(Debug, Deserialize)]
 struct JiraStatus {
     name: String,
+    #[serde(rename = "statusCategory")]
+    status_category: Option<JiraStatusCategory>,
 }
 
-/// Fetch tickets updated today from Jira
-/// Returns (tickets_closed, tickets_in_progress)
-pub async fn fetch_tickets_today(
-    base_url: &str,
-    email: &str,
-    api_token: &str,
-    project_key: &str,
-) -> Result<(Vec<Ticket>, Vec<Ticket>), AppError> {
-    let client = Client::builder()
-        .timeout(Duration::from_secs(10))
-        .connect_timeout(Duration::from_secs(10))
-        .build()
-        .map_err(|e| AppError::JiraUnreachable(format!("Failed to create HTTP client: {}", e)))?;
-
-    // JQL: assignee = currentUser() AND updated >= startOfDay() ORDER BY updated DESC
-    let jql = format!(
-        "assignee = currentUser() AND project = {} AND updated >= startOfDay() ORDER BY updated DESC",
-        project_key
-    );
+/// Jira's fixed status categories: "new", "indeterminate" or "done"
+#[derive(Debug, Deserialize)]
+struct JiraStatusCategory {
+    key: String,
+}
 
-    let url = format!(
-        "{}/rest/api/2/search?jql={}&fields=summary,status,resolutiondate",
-        base_url,
-        urlencoding::encode(&jql)
-    );
+/// A user reference. Jira Cloud identifies users by `accountId`, Server and
+/// Data Center by `name`/`key`.
+#[derive(Debug, Default, Deserialize)]
+struct JiraUser {
+    #[serde(rename = "accountId")]
+    account_id: Option<String>,
+    name: Option<String>,
+    key: Option<String>,
+}
 
-    // Basic auth with email and API token
-    let auth = format!("{}:{}", email, api_token);
-    let auth_b64 =
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, auth.as_bytes());
+impl JiraUser {
+    fn same_as(&self, other: &JiraUser) -> bool {
+        fn eq(a: &Option<String>, b: &Option<String>) -> bool {
+            matches!((a, b), (Some(a), Some(b)) if a == b)
+        }
+        eq(&self.account_id, &other.account_id)
+            || eq(&self.key, &other.key)
+            || eq(&self.name, &other.name)
+    }
 
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Basic {}", auth_b64))
-        .header("Accept", "application/json")
-        .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                AppError::JiraUnreachable("Request timed out (>10s)".to_string())
-            } else if e.is_connect() {
-                AppError::JiraUnreachable("Cannot connect to Jira server".to_string())
+    fn is(&self, author: &Option<JiraUser>) -> bool {
+        author.as_ref().is_some_and(|a| a.same_as(self))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraChangelog {
+    #[serde(default)]
+    histories: Vec<JiraHistory>,
+    #[serde(default)]
+    total: Option<usize>,
+}
+
+impl JiraChangelog {
+    /// Search results embed at most 100 histories per issue
+    fn is_complete(&self) -> bool {
+        self.total
+            .map_or(true, |total| self.histories.len() >= total)
+    }
+}
+
+/// `/rest/api/2/issue/{key}/changelog` response: offset paging
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangelogPage {
+    #[serde(default)]
+    values: Vec<JiraHistory>,
+    #[serde(default)]
+    start_at: usize,
+    #[serde(default)]
+    total: usize,
+}
+
+impl ChangelogPage {
+    fn next_start(&self) -> Option<usize> {
+        let next = self.start_at + self.values.len();
+        (!self.values.is_empty() && next < self.total).then_some(next)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraHistory {
+    author: Option<JiraUser>,
+    created: String,
+    #[serde(default)]
+    items: Vec<JiraChangeItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraChangeItem {
+    field: String,
+    #[serde(rename = "fromString")]
+    from: Option<String>,
+    #[serde(rename = "toString")]
+    to: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraComments {
+    #[serde(default)]
+    comments: Vec<JiraComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraComment {
+    author: Option<JiraUser>,
+    created: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraWorklogs {
+    #[serde(default)]
+    worklogs: Vec<JiraWorklog>,
+    #[serde(default)]
+    total: usize,
+}
+
+impl JiraWorklogs {
+    /// Search results embed at most 20 worklogs per issue
+    fn is_complete(&self) -> bool {
+        self.worklogs.len() >= self.total
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraWorklog {
+    author: Option<JiraUser>,
+    started: String,
+    #[serde(rename = "timeSpentSeconds", default)]
+    time_spent_seconds: i64,
+}
+
+/// Whether a Jira timestamp (e.g. "2026-02-14T09:30:00.000+0100") falls
+/// within the summary day
+fn on_day(timestamp: &str, day: &SummaryDay) -> bool {
+    DateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .map(|t| day.contains(t.with_timezone(&Utc)))
+        .unwrap_or(false)
+}
+
+/// What `user` did to the issue on `day`: status transitions and other edits
+/// from the changelog, comments written and time logged
+fn activity_on(
+    issue: &JiraIssue,
+    worklogs: &[JiraWorklog],
+    user: &JiraUser,
+    day: &SummaryDay,
+) -> TicketActivity {
+    let mut activity = TicketActivity::default();
+
+    let histories = issue.changelog.iter().flat_map(|c| &c.histories);
+    let mut mine: Vec<&JiraHistory> = histories
+        .filter(|h| user.is(&h.author) && on_day(&h.created, day))
+        .collect();
+    mine.sort_by(|a, b| a.created.cmp(&b.created));
+    for history in mine {
+        for item in &history.items {
+            if item.field == "status" {
+                activity.transitions.push(StatusTransition {
+                    from: item.from.clone().unwrap_or_default(),
+                    to: item.to.clone().unwrap_or_default(),
+                    at: history.created.clone(),
+                });
             } else {
-                AppError::JiraUnreachable(e.to_string())
+                activity.edits += 1;
             }
-        })?;
+        }
+    }
+
+    activity.comments_authored = issue
+        .fields
+        .comment
+        .iter()
+        .flat_map(|c| &c.comments)
+        .filter(|c| user.is(&c.author) && on_day(&c.created, day))
+        .count() as u32;
+
+    let seconds: i64 = worklogs
+        .iter()
+        .filter(|w| user.is(&w.author) && on_day(&w.started, day))
+        .map(|w| w.time_spent_seconds.max(0))
+        .sum();
+    activity.time_logged_minutes = (seconds / 60) as u32;
+
+    activity
+}
+
+/// Closed when the status is in Jira's "done" category. Statuses without a
+/// category (very old servers) fall back to matching the name.
+fn is_done(status: &JiraStatus) -> bool {
+    match &status.status_category {
+        Some(category) => category.key == "done",
+        None => {
+            let name = status.name.to_lowercase();
+            name.contains("done") || name.contains("closed") || name.contains("resolved")
+        }
+    }
+}
+
+/// Whether the user moved the ticket into a status in `done_statuses` during
+/// the day and it is still done. A ticket that was already done, was only
+/// closed by someone else, or was reopened afterwards stays in progress.
+fn closed_on_day(status: &JiraStatus, activity: &TicketActivity, done_statuses: &[String]) -> bool {
+    is_done(status)
+        && activity.transitions.iter().any(|transition| {
+            done_statuses
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&transition.to))
+        })
+}
+
+/// How requests authenticate
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JiraAuthMode {
+    /// Jira Cloud: Basic auth with account email and API token
+    #[default]
+    Cloud,
+    /// Jira Server / Data Center: Bearer personal access token
+    Datacenter,
+}
+
+impl JiraAuthMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JiraAuthMode::Cloud => "cloud",
+            JiraAuthMode::Datacenter => "datacenter",
+        }
+    }
+
+    /// Unknown values stored in settings fall back to Cloud
+    pub fn parse(value: &str) -> JiraAuthMode {
+        match value {
+            "datacenter" => JiraAuthMode::Datacenter,
+            _ => JiraAuthMode::Cloud,
+        }
+    }
+}
 
-    // Check status code
+/// Where Jira is and how to talk to it; shared by aggregation and delivery
+#[derive(Debug, Clone, Default)]
+pub struct JiraConnection {
+    pub base_url: String,
+    pub auth_mode: JiraAuthMode,
+    /// Account email; only used for Cloud
+    pub email: String,
+    /// Cloud API token or Data Center personal access token
+    pub api_token: String,
+    /// PEM file with an extra CA certificate, for servers behind a private CA
+    pub ca_cert_path: Option<String>,
+}
+
+impl JiraConnection {
+    pub fn is_configured(&self) -> bool {
+        !self.base_url.is_empty()
+            && !self.api_token.is_empty()
+            && (self.auth_mode == JiraAuthMode::Datacenter || !self.email.is_empty())
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.trim_end_matches('/')
+    }
+
+    pub fn auth_header(&self) -> String {
+        match self.auth_mode {
+            JiraAuthMode::Cloud => {
+                let auth = format!("{}:{}", self.email, self.api_token);
+                format!(
+                    "Basic {}",
+                    base64::Engine::encode(
+                        &base64::engine::general_purpose::STANDARD,
+                        auth.as_bytes()
+                    )
+                )
+            }
+            JiraAuthMode::Datacenter => format!("Bearer {}", self.api_token),
+        }
+    }
+
+    /// HTTP client trusting the configured CA certificate in addition to the
+    /// system roots
+    pub fn http_client(&self) -> Result<Client, AppError> {
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10));
+        if let Some(path) = self.ca_cert_path.as_deref().filter(|p| !p.is_empty()) {
+            builder = builder.add_root_certificate(load_ca_certificate(path)?);
+        }
+        builder
+            .build()
+            .map_err(|e| AppError::JiraUnreachable(format!("Failed to create HTTP client: {}", e)))
+    }
+}
+
+/// Read a PEM (or DER) CA certificate from disk
+pub fn load_ca_certificate(path: &str) -> Result<reqwest::Certificate, AppError> {
+    let bytes = std::fs::read(path).map_err(|e| {
+        AppError::NotConfigured(format!("Cannot read CA certificate {}: {}", path, e))
+    })?;
+    reqwest::Certificate::from_pem(&bytes)
+        .or_else(|_| reqwest::Certificate::from_der(&bytes))
+        .map_err(|e| AppError::NotConfigured(format!("Invalid CA certificate {}: {}", path, e)))
+}
+
+/// Authenticated GET against the Jira REST API
+struct JiraClient<'a> {
+    client: Client,
+    connection: &'a JiraConnection,
+    auth_header: String,
+}
+
+impl<'a> JiraClient<'a> {
+    fn new(connection: &'a JiraConnection) -> Result<Self, AppError> {
+        Ok(JiraClient {
+            client: connection.http_client()?,
+            connection,
+            auth_header: connection.auth_header(),
+        })
+    }
+
+    fn base_url(&self) -> &str {
+        self.connection.base_url()
+    }
+
+    async fn get(&self, path_and_query: &str) -> Result<reqwest::Response, AppError> {
+        self.client
+            .get(format!("{}{}", self.base_url(), path_and_query))
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AppError::JiraUnreachable("Request timed out (>10s)".to_string())
+                } else if e.is_connect() {
+                    AppError::JiraUnreachable(
+                        "Cannot connect to Jira server (check the URL and CA certificate)"
+                            .to_string(),
+                    )
+                } else {
+                    AppError::JiraUnreachable(e.to_string())
+                }
+            })
+    }
+}
+
+/// Turn error statuses into readable errors; 400 carries Jira's JQL messages
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, AppError> {
     let status = response.status();
     if status == 401 {
         return Err(AppError::JiraUnreachable(
-            "Authentication failed - check API token".to_string(),
+            "Authentication failed - check the API token or personal access token".to_string(),
         ));
     } else if status == 403 {
         return Err(AppError::JiraUnreachable(
             "No access to project - check project key".to_string(),
         ));
+    } else if status == 400 {
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        let messages: Vec<&str> = body["errorMessages"]
+            .as_array()
+            .map(|m| m.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        return Err(AppError::JiraUnreachable(format!(
+            "Invalid search: {}",
+            if messages.is_empty() {
+                "check the project keys and JQL".to_string()
+            } else {
+                messages.join("; ")
+            }
+        )));
     } else if !status.is_success() {
         return Err(AppError::JiraUnreachable(format!("HTTP error: {}", status)));
     }
+    Ok(response)
+}
 
-    let jira_response: JiraResponse = response
+async fn parse_page<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, AppError> {
+    check_status(response)
+        .await?
         .json()
         .await
-        .map_err(|e| AppError::JiraUnreachable(format!("Failed to parse JSON response: {}", e)))?;
+        .map_err(|e| AppError::JiraUnreachable(format!("Failed to parse JSON response: {}", e)))
+}
+
+/// Run a search across all pages. Jira Cloud's `/rest/api/3/search/jql` is
+/// tried first; servers without it answer 404 and are searched with the
+/// offset-paged `/rest/api/2/search` instead, as is Data Center straight away.
+async fn search_all(jira: &JiraClient<'_>, jql: &str) -> Result<Vec<JiraIssue>, AppError> {
+    let jql = urlencoding::encode(jql);
+    if jira.connection.auth_mode == JiraAuthMode::Datacenter {
+        return search_all_offset(jira, &jql).await;
+    }
+    let mut issues = Vec::new();
+
+    let mut token: Option<String> = None;
+    for page_number in 0..MAX_PAGES {
+        let mut path = format!(
+            "/rest/api/3/search/jql?jql={}&fields={}&expand=changelog&maxResults={}",
+            jql, SEARCH_FIELDS, PAGE_SIZE
+        );
+        if let Some(token) = &token {
+            path.push_str(&format!("&nextPageToken={}", urlencoding::encode(token)));
+        }
+
+        let response = jira.get(&path).await?;
+        if page_number == 0 && response.status() == StatusCode::NOT_FOUND {
+            return search_all_offset(jira, &jql).await;
+        }
+
+        let page: JqlSearchPage = parse_page(response).await?;
+        token = page.next_token().map(str::to_string);
+        issues.extend(page.issues);
+        if token.is_none() {
+            return Ok(issues);
+        }
+    }
+    warn_truncated(issues.len());
+    Ok(issues)
+}
+
+/// The search matched more than `MAX_PAGES` pages; the rest is left out
+fn warn_truncated(fetched: usize) {
+    eprintln!(
+        "[Jira] Search stopped after {} pages ({} issues); narrow the JQL filter to include the rest",
+        MAX_PAGES, fetched
+    );
+}
+
+async fn search_all_offset(
+    jira: &JiraClient<'_>,
+    encoded_jql: &str,
+) -> Result<Vec<JiraIssue>, AppError> {
+    let mut issues = Vec::new();
+    let mut start_at = 0;
+    for _ in 0..MAX_PAGES {
+        let path = format!(
+            "/rest/api/2/search?jql={}&fields={}&expand=changelog&startAt={}&maxResults={}",
+            encoded_jql, SEARCH_FIELDS, start_at, PAGE_SIZE
+        );
+        let page: OffsetSearchPage = parse_page(jira.get(&path).await?).await?;
+        let next = page.next_start();
+        issues.extend(page.issues);
+        match next {
+            Some(next) => start_at = next,
+            None => return Ok(issues),
+        }
+    }
+    warn_truncated(issues.len());
+    Ok(issues)
+}
+
+/// Jira's own description of the deployment, from `/rest/api/2/serverInfo`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    /// "Cloud", "Server" or "DataCenter"
+    #[serde(default)]
+    pub deployment_type: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl ServerInfo {
+    pub fn is_cloud(&self) -> bool {
+        self.deployment_type.as_deref() == Some("Cloud")
+    }
+
+    /// Auth mode the deployment expects
+    pub fn expected_auth_mode(&self) -> JiraAuthMode {
+        if self.is_cloud() {
+            JiraAuthMode::Cloud
+        } else {
+            JiraAuthMode::Datacenter
+        }
+    }
+
+    /// e.g. "Jira Data Center 9.12.4"
+    pub fn describe(&self) -> String {
+        let deployment = match self.deployment_type.as_deref() {
+            Some("DataCenter") => "Data Center",
+            Some(other) => other,
+            None => "Server",
+        };
+        match &self.version {
+            Some(version) if !self.is_cloud() => format!("Jira {} {}", deployment, version),
+            _ => format!("Jira {}", deployment),
+        }
+    }
+}
+
+/// Detect the deployment type; works before authentication is set up correctly
+pub async fn server_info(connection: &JiraConnection) -> Result<ServerInfo, AppError> {
+    let jira = JiraClient::new(connection)?;
+    parse_page(jira.get("/rest/api/2/serverInfo").await?).await
+}
+
+/// The authenticated user, to tell their activity apart from everyone else's
+async fn current_user(jira: &JiraClient<'_>) -> Result<JiraUser, AppError> {
+    parse_page(jira.get("/rest/api/2/myself").await?).await
+}
+
+/// All worklogs started on `day`, for issues whose search result was truncated
+async fn fetch_worklogs(
+    jira: &JiraClient<'_>,
+    issue_key: &str,
+    day: &SummaryDay,
+) -> Result<Vec<JiraWorklog>, AppError> {
+    let path = format!(
+        "/rest/api/2/issue/{}/worklog?startedAfter={}",
+        urlencoding::encode(issue_key),
+        day.start.timestamp_millis()
+    );
+    let page: JiraWorklogs = parse_page(jira.get(&path).await?).await?;
+    Ok(page.worklogs)
+}
+
+/// Full changelog of an issue, for when the search result embeds only part of it
+async fn fetch_changelog(
+    jira: &JiraClient<'_>,
+    issue_key: &str,
+) -> Result<Vec<JiraHistory>, AppError> {
+    let mut histories = Vec::new();
+    let mut start_at = 0;
+    for _ in 0..MAX_PAGES {
+        let path = format!(
+            "/rest/api/2/issue/{}/changelog?startAt={}&maxResults={}",
+            urlencoding::encode(issue_key),
+            start_at,
+            PAGE_SIZE
+        );
+        let page: ChangelogPage = parse_page(jira.get(&path).await?).await?;
+        let next = page.next_start();
+        histories.extend(page.values);
+        match next {
+            Some(next) => start_at = next,
+            None => break,
+        }
+    }
+    Ok(histories)
+}
+
+/// Names of the statuses in Jira's "done" category, from `/rest/api/2/status`
+async fn done_statuses(jira: &JiraClient<'_>) -> Result<Vec<String>, AppError> {
+    let statuses: Vec<JiraStatus> = parse_page(jira.get("/rest/api/2/status").await?).await?;
+    Ok(statuses
+        .into_iter()
+        .filter(is_done)
+        .map(|status| status.name)
+        .collect())
+}
+
+/// Fetch the tickets the user worked on during `day` from Jira: issues
+/// matching the query where they changed the status or a field, commented or
+/// logged time. Returns (tickets_closed, tickets_in_progress)
+pub async fn fetch_tickets_today(
+    connection: &JiraConnection,
+    query: &JiraQuery,
+    day: &SummaryDay,
+) -> Result<(Vec<Ticket>, Vec<Ticket>), AppError> {
+    if query.is_empty() {
+        return Err(AppError::NotConfigured(
+            "Jira project keys or JQL not configured".to_string(),
+        ));
+    }
+    query.validate()?;
+
+    let jira = JiraClient::new(connection)?;
+    let user = current_user(&jira).await?;
+    let issues = search_all(&jira, &query.to_jql(Utc::now() - day.start)).await?;
 
     // Split into closed vs in-progress
     let mut tickets_closed = Vec::new();
     let mut tickets_in_progress = Vec::new();
+    // Looked up once, on the first ticket the user moved today
+    let mut done: Option<Vec<String>> = None;
+
+    for mut issue in issues {
+        let worklogs = match issue.fields.worklog.take() {
+            Some(embedded) if embedded.is_complete() => embedded.worklogs,
+            Some(_) => fetch_worklogs(&jira, &issue.key, day).await?,
+            None => Vec::new(),
+        };
+        if let Some(changelog) = issue.changelog.as_mut().filter(|c| !c.is_complete()) {
+            changelog.histories = fetch_changelog(&jira, &issue.key).await?;
+        }
+        let activity = activity_on(&issue, &worklogs, &user, day);
+        if activity.is_empty() {
+            // Updated today, but only by someone else
+            continue;
+        }
 
-    for issue in jira_response.issues {
         let ticket = Ticket {
             id: issue.key.clone(),
             title: issue.fields.summary.clone(),
             status: issue.fields.status.name.clone(),
-            url: format!("{}/browse/{}", base_url, issue.key),
+            url: format!("{}/browse/{}", jira.base_url(), issue.key),
             resolved_at: issue.fields.resolutiondate.clone(),
+            security_level: issue.fields.security.as_ref().map(|s| s.name.clone()),
+            activity,
         };
 
-        // Determine if closed or in-progress
-        let status_lower = issue.fields.status.name.to_lowercase();
-        if status_lower.contains("done")
-            || status_lower.contains("closed")
-            || status_lower.contains("resolved")
-        {
+        let closed = if ticket.activity.transitions.is_empty() {
+            false
+        } else {
+            if done.is_none() {
+                done = Some(done_statuses(&jira).await?);
+            }
+            closed_on_day(
+                &issue.fields.status,
+                &ticket.activity,
+                done.as_deref().unwrap_or_default(),
+            )
+        };
+        if closed {
             tickets_closed.push(ticket);
         } else {
             tickets_in_progress.push(ticket);
@@ -120,3 +779,192 @@ pub async fn fetch_tickets_today(
 
     Ok((tickets_closed, tickets_in_progress))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_quoted_jql_for_projects_and_overrides() {
+        let query = JiraQuery::from_settings(Some("proj, OPS"), None);
+        assert_eq!(
+            query.to_jql(chrono::Duration::seconds(5381)),
+            "assignee = currentUser() AND project in (\"PROJ\", \"OPS\") \
+             AND updated >= \"-90m\" ORDER BY updated DESC"
+        );
+
+        let query = JiraQuery::from_settings(
+            None,
+            Some("  labels = \"order by\" OR watcher = currentUser() ORDER BY key "),
+        );
+        assert_eq!(
+            query.to_jql(chrono::Duration::zero()),
+            "(labels = \"order by\" OR watcher = currentUser()) \
+             AND updated >= \"-1m\" ORDER BY updated DESC"
+        );
+
+        assert_eq!(quote_jql(r#"a"b\c"#), r#""a\"b\\c""#);
+        assert!(JiraQuery::from_settings(Some("PROJ) OR (1=1"), None)
+            .validate()
+            .is_err());
+        assert!(JiraQuery::from_settings(Some(" , "), Some("  ")).is_empty());
+    }
+
+    #[test]
+    fn follows_both_paging_schemes() {
+        let page: JqlSearchPage =
+            serde_json::from_str(r#"{"issues": [], "nextPageToken": "abc", "isLast": false}"#)
+                .expect("token page should parse");
+        assert_eq!(page.next_token(), Some("abc"));
+        let page: JqlSearchPage =
+            serde_json::from_str(r#"{"issues": [], "nextPageToken": "abc", "isLast": true}"#)
+                .expect("last token page should parse");
+        assert_eq!(page.next_token(), None);
+
+        let issue = r#"{"key": "PROJ-1", "fields": {"summary": "s", "status": {"name": "Done"}}}"#;
+        let page: OffsetSearchPage = serde_json::from_str(&format!(
+            r#"{{"issues": [{issue}, {issue}], "startAt": 100, "total": 250}}"#
+        ))
+        .expect("offset page should parse");
+        assert_eq!(page.next_start(), Some(102));
+        let page: OffsetSearchPage = serde_json::from_str(&format!(
+            r#"{{"issues": [{issue}], "startAt": 249, "total": 250}}"#
+        ))
+        .expect("last offset page should parse");
+        assert_eq!(page.next_start(), None);
+
+        let page: ChangelogPage = serde_json::from_str(
+            r#"{"values": [{"created": "2026-02-14T10:00:00.000+0000"}], "startAt": 0, "total": 3}"#,
+        )
+        .expect("changelog page should parse");
+        assert_eq!(page.next_start(), Some(1));
+    }
+
+    #[test]
+    fn authenticates_per_deployment() {
+        let mut connection = JiraConnection {
+            base_url: "https://jira.example.com/".to_string(),
+            email: "me@example.com".to_string(),
+            api_token: "secret".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            connection.auth_header(),
+            "Basic bWVAZXhhbXBsZS5jb206c2VjcmV0"
+        );
+        assert_eq!(connection.base_url(), "https://jira.example.com");
+
+        connection.auth_mode = JiraAuthMode::Datacenter;
+        connection.email.clear();
+        assert!(connection.is_configured());
+        assert_eq!(connection.auth_header(), "Bearer secret");
+
+        connection.ca_cert_path = Some("/nonexistent/ca.pem".to_string());
+        assert!(matches!(
+            connection.http_client(),
+            Err(AppError::NotConfigured(_))
+        ));
+
+        let info: ServerInfo = serde_json::from_str(
+            r#"{"baseUrl": "https://jira.example.com", "version": "9.12.4",
+                "deploymentType": "DataCenter", "serverTitle": "Jira"}"#,
+        )
+        .expect("server info should parse");
+        assert_eq!(info.describe(), "Jira Data Center 9.12.4");
+        assert_eq!(info.expected_auth_mode(), JiraAuthMode::Datacenter);
+    }
+
+    #[test]
+    fn records_only_the_users_own_activity_today() {
+        let issue: JiraIssue = serde_json::from_str(
+            r#"{
+                "key": "PROJ-7",
+                "fields": {
+                    "summary": "Billing export",
+                    "status": {"name": "Code Review", "statusCategory": {"key": "indeterminate"}},
+                    "comment": {"comments": [
+                        {"author": {"accountId": "me"}, "created": "2026-02-14T11:00:00.000+0000"},
+                        {"author": {"accountId": "bob"}, "created": "2026-02-14T12:00:00.000+0000"},
+                        {"author": {"accountId": "me"}, "created": "2026-02-13T12:00:00.000+0000"}
+                    ]},
+                    "worklog": {"total": 2, "worklogs": [
+                        {"author": {"accountId": "me"}, "started": "2026-02-14T09:00:00.000+0000", "timeSpentSeconds": 5400},
+                        {"author": {"accountId": "bob"}, "started": "2026-02-14T09:00:00.000+0000", "timeSpentSeconds": 3600}
+                    ]}
+                },
+                "changelog": {"histories": [
+                    {"author": {"accountId": "me"}, "created": "2026-02-14T15:00:00.000+0000",
+                     "items": [{"field": "status", "fromString": "In Progress", "toString": "Code Review"}]},
+                    {"author": {"accountId": "me"}, "created": "2026-02-14T10:00:00.000+0000",
+                     "items": [{"field": "status", "fromString": "To Do", "toString": "In Progress"},
+                               {"field": "assignee", "fromString": null, "toString": "Me"}]},
+                    {"author": {"accountId": "bob"}, "created": "2026-02-14T16:00:00.000+0000",
+                     "items": [{"field": "labels", "fromString": "", "toString": "billing"}]}
+                ], "total": 3}
+            }"#,
+        )
+        .expect("issue should parse");
+        let me = JiraUser {
+            account_id: Some("me".to_string()),
+            ..Default::default()
+        };
+        let day = crate::aggregation::day::DayBoundary::new(Some("UTC"), "00:00")
+            .expect("UTC is a valid time zone")
+            .day(chrono::NaiveDate::from_ymd_opt(2026, 2, 14).expect("valid date"))
+            .expect("day should resolve");
+        let worklogs = &issue
+            .fields
+            .worklog
+            .as_ref()
+            .expect("worklogs are embedded")
+            .worklogs;
+        assert!(issue
+            .changelog
+            .as_ref()
+            .is_some_and(JiraChangelog::is_complete));
+
+        let activity = activity_on(&issue, worklogs, &me, &day);
+        let moves: Vec<(&str, &str)> = activity
+            .transitions
+            .iter()
+            .map(|t| (t.from.as_str(), t.to.as_str()))
+            .collect();
+        assert_eq!(
+            moves,
+            [("To Do", "In Progress"), ("In Progress", "Code Review")]
+        );
+        assert_eq!(activity.edits, 1);
+        assert_eq!(activity.comments_authored, 1);
+        assert_eq!(activity.time_logged_minutes, 90);
+        assert_eq!(
+            activity.describe(),
+            "To Do → In Progress → Code Review; 1 comment; 90m logged"
+        );
+        assert!(!is_done(&issue.fields.status));
+
+        // Closed only when the user moved it into a done status today
+        let done = ["Done".to_string(), "Won't Do".to_string()];
+        let done_status = JiraStatus {
+            name: "Done".to_string(),
+            status_category: Some(JiraStatusCategory {
+                key: "done".to_string(),
+            }),
+        };
+        assert!(!closed_on_day(&done_status, &activity, &done));
+        let mut closing = activity.clone();
+        closing.transitions.push(StatusTransition {
+            from: "Code Review".to_string(),
+            to: "done".to_string(),
+            at: "2026-02-14T17:00:00.000+0000".to_string(),
+        });
+        assert!(closed_on_day(&done_status, &closing, &done));
+        // Reopened later in the day
+        assert!(!closed_on_day(&issue.fields.status, &closing, &done));
+
+        let bob_only = JiraUser {
+            account_id: Some("carol".to_string()),
+            ..Default::default()
+        };
+        assert!(activity_on(&issue, worklogs, &bob_only, &day).is_empty());
+    }
+}
@@ -0,0 +1,406 @@
+//! Meeting classification, and how the day's calendar splits into
+//! collaboration, self-scheduled focus blocks and uninterrupted time.
+
+use super::day::SummaryDay;
+use crate::aggregation::Meeting;
+use crate::error::AppError;
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What kind of event a meeting is
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MeetingKind {
+    OneOnOne,
+    TeamSync,
+    External,
+    Interview,
+    FocusBlock,
+    OutOfOffice,
+    #[default]
+    Other,
+}
+
+impl MeetingKind {
+    /// Focus blocks and time off are on the calendar but aren't time spent
+    /// with other people
+    pub fn is_collaboration(self) -> bool {
+        !matches!(self, MeetingKind::FocusBlock | MeetingKind::OutOfOffice)
+    }
+
+    /// Label shown next to the meeting in summaries and prompts
+    pub fn label(self) -> &'static str {
+        match self {
+            MeetingKind::OneOnOne => "1:1",
+            MeetingKind::TeamSync => "team sync",
+            MeetingKind::External => "external",
+            MeetingKind::Interview => "interview",
+            MeetingKind::FocusBlock => "focus block",
+            MeetingKind::OutOfOffice => "out of office",
+            MeetingKind::Other => "meeting",
+        }
+    }
+}
+
+/// What the calendar says about an event, as used for classification
+#[derive(Debug, Default)]
+pub struct EventFacts<'a> {
+    pub title: &'a str,
+    /// Google's event type: "default", "focusTime", "outOfOffice", …
+    pub event_type: &'a str,
+    /// Emails of everyone invited apart from the user and rooms
+    pub guests: Vec<&'a str>,
+    pub organizer: Option<&'a str>,
+    pub organized_by_me: bool,
+    /// The user's own address, when the calendar reports it
+    pub user_email: Option<&'a str>,
+}
+
+/// Events whose title contains any keyword, or whose organizer or any guest
+/// is listed, get `kind`. Addresses match case-insensitively; an entry
+/// starting with `@` matches the whole domain.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MeetingRule {
+    pub kind: MeetingKind,
+    pub title_keywords: Vec<String>,
+    pub organizers: Vec<String>,
+    pub attendees: Vec<String>,
+}
+
+/// True if `email` is `pattern`, or is in the domain when `pattern` is "@domain"
+fn address_matches(pattern: &str, email: &str) -> bool {
+    let email = email.to_lowercase();
+    let pattern = pattern.trim().to_lowercase();
+    match pattern.strip_prefix('@') {
+        Some(domain) => email.rsplit_once('@').is_some_and(|(_, d)| d == domain),
+        None => !pattern.is_empty() && email == pattern,
+    }
+}
+
+fn domain_of(email: &str) -> Option<String> {
+    email.rsplit_once('@').map(|(_, d)| d.to_lowercase())
+}
+
+impl MeetingRule {
+    fn matches(&self, facts: &EventFacts<'_>) -> bool {
+        let title = facts.title.to_lowercase();
+        let listed =
+            |patterns: &[String], email: &str| patterns.iter().any(|p| address_matches(p, email));
+        self.title_keywords
+            .iter()
+            .any(|k| !k.trim().is_empty() && title.contains(&k.trim().to_lowercase()))
+            || facts.organizer.is_some_and(|o| listed(&self.organizers, o))
+            || facts.guests.iter().any(|g| listed(&self.attendees, g))
+    }
+}
+
+/// User-defined classification and working hours, stored as JSON in
+/// settings. The first matching rule wins; other events are classified from
+/// their guests and event type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MeetingRules {
+    pub rules: Vec<MeetingRule>,
+    /// Domains that count as colleagues; blank means the user's own domain
+    pub internal_domains: Vec<String>,
+    /// `HH:MM` the working day starts in the summary time zone, for the
+    /// longest-gap figure
+    pub workday_start: String,
+    pub workday_end: String,
+}
+
+impl Default for MeetingRules {
+    fn default() -> Self {
+        MeetingRules {
+            rules: Vec::new(),
+            internal_domains: Vec::new(),
+            workday_start: "09:00".to_string(),
+            workday_end: "17:00".to_string(),
+        }
+    }
+}
+
+impl MeetingRules {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let (start, end) = self.workday_times()?;
+        if start >= end {
+            return Err(AppError::NotConfigured(
+                "The working day must end after it starts".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn workday_times(&self) -> Result<(NaiveTime, NaiveTime), AppError> {
+        let parse = |time: &str| {
+            NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| {
+                AppError::NotConfigured(format!(
+                    "Invalid working hours time '{}'. Use HH:MM.",
+                    time
+                ))
+            })
+        };
+        Ok((parse(&self.workday_start)?, parse(&self.workday_end)?))
+    }
+
+    /// The working hours of `day` in UTC; None if they are misconfigured
+    pub fn workday(&self, day: &SummaryDay) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let (start, end) = self.workday_times().ok()?;
+        let at = |time: NaiveTime| day.boundary.at(day.date, time);
+        Some((at(start)?, at(end)?)).filter(|(start, end)| start < end)
+    }
+
+    pub fn classify(&self, facts: &EventFacts<'_>) -> MeetingKind {
+        if let Some(rule) = self.rules.iter().find(|rule| rule.matches(facts)) {
+            return rule.kind;
+        }
+
+        match facts.event_type {
+            "outOfOffice" => return MeetingKind::OutOfOffice,
+            "focusTime" => return MeetingKind::FocusBlock,
+            _ => {}
+        }
+        if facts.title.to_lowercase().contains("interview") {
+            return MeetingKind::Interview;
+        }
+        if facts.guests.is_empty() {
+            // A block the user put in their own calendar
+            return if facts.organized_by_me {
+                MeetingKind::FocusBlock
+            } else {
+                MeetingKind::Other
+            };
+        }
+
+        let internal: Vec<String> = if self.internal_domains.iter().any(|d| !d.trim().is_empty()) {
+            self.internal_domains
+                .iter()
+                .map(|d| d.trim().trim_start_matches('@').to_lowercase())
+                .filter(|d| !d.is_empty())
+                .collect()
+        } else {
+            facts.user_email.and_then(domain_of).into_iter().collect()
+        };
+        let is_external = |email: &&str| domain_of(email).is_some_and(|d| !internal.contains(&d));
+        if !internal.is_empty() && facts.guests.iter().any(is_external) {
+            return MeetingKind::External;
+        }
+
+        if facts.guests.len() == 1 {
+            MeetingKind::OneOnOne
+        } else {
+            MeetingKind::TeamSync
+        }
+    }
+}
+
+/// How the calendar day divides between people, focus blocks and time off
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CalendarBreakdown {
+    pub collaboration_minutes: u32,
+    /// Focus blocks the user scheduled for themselves
+    pub focus_block_minutes: u32,
+    pub out_of_office_minutes: u32,
+    /// Longest stretch of working hours without a meeting or time off;
+    /// focus blocks don't interrupt it
+    pub longest_gap_minutes: u32,
+}
+
+/// Minutes covered by `intervals`, counting overlapping stretches once
+fn covered_minutes(intervals: &mut [(DateTime<Utc>, DateTime<Utc>)]) -> u32 {
+    intervals.sort();
+    let mut total = chrono::Duration::zero();
+    let mut current: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+    for &(start, end) in intervals.iter() {
+        match &mut current {
+            Some((_, current_end)) if start <= *current_end => {
+                *current_end = (*current_end).max(end);
+            }
+            _ => {
+                if let Some((from, to)) = current.replace((start, end)) {
+                    total += to - from;
+                }
+            }
+        }
+    }
+    if let Some((from, to)) = current {
+        total += to - from;
+    }
+    total.num_minutes().max(0) as u32
+}
+
+impl CalendarBreakdown {
+    /// `workday` is the working hours the gap is measured in
+    pub fn from_events(
+        events: &[Meeting],
+        workday: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    ) -> CalendarBreakdown {
+        let mut breakdown = CalendarBreakdown::default();
+        // Timed events per category, so overlapping ones are counted once
+        let mut collaboration = Vec::new();
+        let mut focus_blocks = Vec::new();
+        let mut out_of_office = Vec::new();
+
+        for event in events {
+            let (total, intervals) = match event.kind {
+                MeetingKind::FocusBlock => (&mut breakdown.focus_block_minutes, &mut focus_blocks),
+                MeetingKind::OutOfOffice => {
+                    (&mut breakdown.out_of_office_minutes, &mut out_of_office)
+                }
+                _ => (&mut breakdown.collaboration_minutes, &mut collaboration),
+            };
+            let parse = |t: &str| {
+                DateTime::parse_from_rfc3339(t)
+                    .ok()
+                    .map(|t| t.with_timezone(&Utc))
+            };
+            match (parse(&event.start), parse(&event.end)) {
+                (Some(start), Some(end)) if start < end => intervals.push((start, end)),
+                // All-day events only have dates
+                _ => *total += event.duration_minutes.max(0) as u32,
+            }
+        }
+
+        breakdown.collaboration_minutes += covered_minutes(&mut collaboration);
+        breakdown.focus_block_minutes += covered_minutes(&mut focus_blocks);
+        breakdown.out_of_office_minutes += covered_minutes(&mut out_of_office);
+
+        if let Some((day_start, day_end)) = workday {
+            let mut busy = collaboration;
+            busy.extend(out_of_office);
+            busy.sort();
+            let mut cursor = day_start;
+            let mut longest = chrono::Duration::zero();
+            for (start, end) in busy {
+                let start = start.clamp(day_start, day_end);
+                longest = longest.max(start - cursor);
+                cursor = cursor.max(end.clamp(day_start, day_end));
+            }
+            longest = longest.max(day_end - cursor);
+            breakdown.longest_gap_minutes = longest.num_minutes().max(0) as u32;
+        }
+        breakdown
+    }
+
+    /// One line for prompts, e.g. "3h 30m in meetings, 2h in focus blocks,
+    /// longest uninterrupted stretch 1h 45m"
+    pub fn describe(&self) -> String {
+        let format = crate::aggregation::time::format_minutes;
+        let mut parts = vec![format!(
+            "{} in meetings",
+            format(self.collaboration_minutes)
+        )];
+        if self.focus_block_minutes > 0 {
+            parts.push(format!(
+                "{} in focus blocks",
+                format(self.focus_block_minutes)
+            ));
+        }
+        if self.out_of_office_minutes > 0 {
+            parts.push(format!(
+                "{} out of office",
+                format(self.out_of_office_minutes)
+            ));
+        }
+        parts.push(format!(
+            "longest uninterrupted stretch {}",
+            format(self.longest_gap_minutes)
+        ));
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(start: &str, end: &str, minutes: i32, kind: MeetingKind) -> Meeting {
+        Meeting {
+            title: "Event".to_string(),
+            start: format!("2026-02-14T{}:00Z", start),
+            end: format!("2026-02-14T{}:00Z", end),
+            duration_minutes: minutes,
+            kind,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn classifies_by_rules_then_guests() {
+        let rules: MeetingRules = serde_json::from_str(
+            r#"{"rules": [{"kind": "teamSync", "titleKeywords": ["standup"]}],
+                "internalDomains": []}"#,
+        )
+        .expect("rules should parse");
+        let facts = |title, guests: Vec<&'static str>| EventFacts {
+            title,
+            guests,
+            organized_by_me: true,
+            user_email: Some("me@acme.com"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rules.classify(&facts("Daily Standup", vec!["a@acme.com"])),
+            MeetingKind::TeamSync
+        );
+        assert_eq!(
+            rules.classify(&facts("Catch-up", vec!["a@ACME.com"])),
+            MeetingKind::OneOnOne
+        );
+        assert_eq!(
+            rules.classify(&facts("Planning", vec!["a@acme.com", "b@acme.com"])),
+            MeetingKind::TeamSync
+        );
+        assert_eq!(
+            rules.classify(&facts("Demo", vec!["a@acme.com", "buyer@client.io"])),
+            MeetingKind::External
+        );
+        assert_eq!(
+            rules.classify(&facts("Interview: backend", vec!["x@gmail.com"])),
+            MeetingKind::Interview
+        );
+        assert_eq!(
+            rules.classify(&facts("Deep work", vec![])),
+            MeetingKind::FocusBlock
+        );
+        assert_eq!(
+            rules.classify(&EventFacts {
+                title: "Dentist",
+                event_type: "outOfOffice",
+                ..Default::default()
+            }),
+            MeetingKind::OutOfOffice
+        );
+    }
+
+    #[test]
+    fn breakdown_measures_the_longest_gap_in_working_hours() {
+        let workday = Some((
+            "2026-02-14T09:00:00Z".parse().expect("valid timestamp"),
+            "2026-02-14T17:00:00Z".parse().expect("valid timestamp"),
+        ));
+        let events = [
+            event("08:30", "09:30", 60, MeetingKind::TeamSync),
+            event("10:00", "12:00", 120, MeetingKind::FocusBlock),
+            event("13:00", "13:30", 30, MeetingKind::OneOnOne),
+            event("13:15", "14:00", 45, MeetingKind::External),
+            event("16:00", "18:00", 120, MeetingKind::OutOfOffice),
+        ];
+
+        let breakdown = CalendarBreakdown::from_events(&events, workday);
+        // The 13:00 and 13:15 meetings overlap: 14:00 - 13:00 is one hour
+        assert_eq!(breakdown.collaboration_minutes, 120);
+        assert_eq!(breakdown.focus_block_minutes, 120);
+        assert_eq!(breakdown.out_of_office_minutes, 120);
+        // 09:30–13:00; the focus block doesn't break it up
+        assert_eq!(breakdown.longest_gap_minutes, 210);
+        assert_eq!(
+            breakdown.describe(),
+            "2h in meetings, 2h in focus blocks, 2h out of office, \
+             longest uninterrupted stretch 3h 30m"
+        );
+    }
+}
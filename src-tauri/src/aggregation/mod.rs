@@ -1,5 +1,10 @@
 pub mod calendar;
+pub mod clockify;
+pub mod day;
+pub mod harvest;
 pub mod jira;
+pub mod meetings;
+pub mod time;
 pub mod toggl;
 
 use crate::error::AppError;
@@ -13,15 +18,157 @@ pub struct Ticket {
     pub url: String,
     #[serde(rename = "resolvedAt")]
     pub resolved_at: Option<String>,
+    /// Jira issue security level, if the issue has one
+    #[serde(
+        rename = "securityLevel",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub security_level: Option<String>,
+    /// What the user did to the ticket today
+    #[serde(default, skip_serializing_if = "TicketActivity::is_empty")]
+    pub activity: TicketActivity,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// A status change, e.g. "To Do" → "In Progress"
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StatusTransition {
+    pub from: String,
+    pub to: String,
+    /// When the transition happened (Jira timestamp)
+    pub at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TicketActivity {
+    /// Status changes made by the user, oldest first
+    pub transitions: Vec<StatusTransition>,
+    /// Other field changes made by the user
+    pub edits: u32,
+    pub comments_authored: u32,
+    /// Worklogs the user added in Jira
+    pub time_logged_minutes: u32,
+    /// Time-tracker entries mentioning the ticket key
+    pub time_tracked_minutes: u32,
+}
+
+impl TicketActivity {
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+            && self.edits == 0
+            && self.comments_authored == 0
+            && self.time_logged_minutes == 0
+            && self.time_tracked_minutes == 0
+    }
+
+    /// Short description for prompts, e.g.
+    /// "To Do → In Progress → Review; 2 comments; 90m logged"
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+
+        let mut chain = String::new();
+        for transition in &self.transitions {
+            if chain.is_empty() {
+                chain = transition.from.clone();
+            } else if !chain.ends_with(&format!(" → {}", transition.from)) {
+                chain.push_str(&format!(", {}", transition.from));
+            }
+            chain.push_str(&format!(" → {}", transition.to));
+        }
+        if !chain.is_empty() {
+            parts.push(chain);
+        }
+
+        match self.comments_authored {
+            0 => {}
+            1 => parts.push("1 comment".to_string()),
+            n => parts.push(format!("{} comments", n)),
+        }
+        if self.time_logged_minutes > 0 {
+            parts.push(format!("{}m logged", self.time_logged_minutes));
+        }
+        if self.time_tracked_minutes > 0 {
+            parts.push(format!("{}m tracked", self.time_tracked_minutes));
+        }
+        parts.join("; ")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct Meeting {
     pub title: String,
     pub start: String,
     pub end: String,
     #[serde(rename = "durationMinutes")]
     pub duration_minutes: i32,
+    /// Google Calendar visibility ("default", "public", "private", "confidential")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    /// Calendar the event came from
+    #[serde(
+        rename = "calendarId",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub calendar_id: Option<String>,
+    /// People invited besides the user, rooms excluded
+    #[serde(rename = "attendeeCount", default)]
+    pub attendee_count: u32,
+    /// Organizer's email address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<String>,
+    #[serde(rename = "organizedByMe", default)]
+    pub organized_by_me: bool,
+    /// Whether the event has a Meet, Zoom or Teams link
+    #[serde(rename = "hasConferenceLink", default)]
+    pub has_conference_link: bool,
+    #[serde(default)]
+    pub kind: meetings::MeetingKind,
+}
+
+impl Meeting {
+    /// "45m", or "45m, 1:1" when the kind is known
+    pub fn length_label(&self) -> String {
+        match self.kind {
+            meetings::MeetingKind::Other => format!("{}m", self.duration_minutes),
+            kind => format!("{}m, {}", self.duration_minutes, kind.label()),
+        }
+    }
+}
+
+/// A note captured during the day
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    pub id: i64,
+    /// RFC 3339 in the summary time zone
+    pub logged_at: String,
+    pub text: String,
+    #[serde(default)]
+    pub ticket_key: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl LogEntry {
+    /// `HH:MM` the note was taken, in the zone it was listed in
+    pub fn time_of_day(&self) -> String {
+        chrono::DateTime::parse_from_rfc3339(&self.logged_at)
+            .map(|t| t.format("%H:%M").to_string())
+            .unwrap_or_default()
+    }
+
+    /// "10:42 PROJ-7 fixed the login redirect"; the key is left out when the
+    /// text already mentions it
+    pub fn describe(&self) -> String {
+        match &self.ticket_key {
+            Some(key) if !time::mentions_key(&self.text, key) => {
+                format!("{} {} {}", self.time_of_day(), key, self.text)
+            }
+            _ => format!("{} {}", self.time_of_day(), self.text),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -30,6 +177,9 @@ pub enum SourceStatusDetail {
     Ok {
         #[serde(rename = "fetchedAt")]
         fetched_at: String,
+        /// Parts that couldn't be read, e.g. one of several calendars
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        skipped: Vec<String>,
     },
     Failed {
         error: String,
@@ -41,6 +191,8 @@ pub enum SourceStatusDetail {
 pub struct DataSourcesStatus {
     pub jira: SourceStatusDetail,
     pub calendar: SourceStatusDetail,
+    /// The configured time-tracking source (Toggl, Clockify or Harvest); the
+    /// name is kept so stored summaries still deserialize
     pub toggl: SourceStatusDetail,
 }
 
@@ -53,39 +205,56 @@ pub struct AggregatedData {
     pub meetings: Vec<Meeting>,
     #[serde(rename = "focusHours")]
     pub focus_hours: f32,
+    /// Tracked time per category and project
+    #[serde(rename = "timeBreakdown", default)]
+    pub time_breakdown: time::TimeBreakdown,
+    /// Notes captured during the day, oldest first
+    #[serde(rename = "logEntries", default)]
+    pub log_entries: Vec<LogEntry>,
+    /// Collaboration vs. focus blocks, and the longest uninterrupted stretch
+    #[serde(rename = "calendarBreakdown", default)]
+    pub calendar_breakdown: meetings::CalendarBreakdown,
     #[serde(rename = "dataSourcesStatus")]
     pub data_sources_status: DataSourcesStatus,
 }
 
 /// Main aggregation function - fetches from all sources in parallel
+#[allow(clippy::too_many_arguments)]
 pub async fn aggregate_today(
-    jira_base_url: Option<String>,
-    jira_email: Option<String>,
-    jira_api_token: Option<String>,
-    jira_project_key: Option<String>,
-    calendar_access_token: Option<String>,
-    toggl_api_token: Option<String>,
-    toggl_workspace_id: Option<String>,
+    day: day::SummaryDay,
+    jira_connection: Option<jira::JiraConnection>,
+    jira_query: jira::JiraQuery,
+    calendar_accounts: calendar::CalendarAccounts,
+    meeting_rules: meetings::MeetingRules,
+    time_source: Option<time::TimeSource>,
+    time_categories: time::TimeCategories,
+    log_entries: Vec<LogEntry>,
 ) -> AggregatedData {
     let now = chrono::Local::now().to_rfc3339();
 
     // Fetch all sources in parallel
     let (jira_result, calendar_result, toggl_result) = tokio::join!(
         async {
-            if let (Some(url), Some(email), Some(token), Some(project)) = (
-                jira_base_url.as_ref(),
-                jira_email.as_ref(),
-                jira_api_token.as_ref(),
-                jira_project_key.as_ref(),
-            ) {
-                jira::fetch_tickets_today(url, email, token, project).await
+            if let Some(connection) = jira_connection.as_ref().filter(|c| c.is_configured()) {
+                jira::fetch_tickets_today(connection, &jira_query, &day).await
             } else {
                 Err(AppError::NotConfigured("Jira not configured".to_string()))
             }
         },
         async {
-            if let Some(token) = calendar_access_token.as_ref() {
-                calendar::fetch_events_today(token).await
+            if !calendar_accounts.usable.is_empty() {
+                calendar::fetch_events_today(&calendar_accounts.usable, &meeting_rules, &day)
+                    .await
+                    .map(|(events, skipped)| {
+                        let mut all = calendar_accounts.skipped.clone();
+                        all.extend(skipped);
+                        (events, all)
+                    })
+            } else if !calendar_accounts.skipped.is_empty() {
+                // Accounts are connected but none could be used
+                Err(AppError::CalendarError(
+                    calendar_accounts.skipped.join("; "),
+                ))
             } else {
                 Err(AppError::NotConfigured(
                     "Calendar not configured".to_string(),
@@ -93,23 +262,24 @@ pub async fn aggregate_today(
             }
         },
         async {
-            if let (Some(token), Some(workspace)) =
-                (toggl_api_token.as_ref(), toggl_workspace_id.as_ref())
-            {
-                toggl::fetch_focus_hours_today(token, workspace).await
+            if let Some(source) = time_source.as_ref() {
+                source.fetch_entries(&day).await
             } else {
-                Err(AppError::NotConfigured("Toggl not configured".to_string()))
+                Err(AppError::NotConfigured(
+                    "Time tracking not configured".to_string(),
+                ))
             }
         }
     );
 
     // Process Jira result
-    let (tickets_closed, tickets_in_progress, jira_status) = match jira_result {
+    let (mut tickets_closed, mut tickets_in_progress, jira_status) = match jira_result {
         Ok((closed, in_progress)) => (
             closed,
             in_progress,
             SourceStatusDetail::Ok {
                 fetched_at: now.clone(),
+                skipped: Vec::new(),
             },
         ),
         Err(AppError::NotConfigured(_)) => (vec![], vec![], SourceStatusDetail::NotConfigured),
@@ -123,11 +293,12 @@ pub async fn aggregate_today(
     };
 
     // Process Calendar result
-    let (meetings, calendar_status) = match calendar_result {
-        Ok(events) => (
+    let (events, calendar_status) = match calendar_result {
+        Ok((events, skipped)) => (
             events,
             SourceStatusDetail::Ok {
                 fetched_at: now.clone(),
+                skipped,
             },
         ),
         Err(AppError::NotConfigured(_)) => (vec![], SourceStatusDetail::NotConfigured),
@@ -139,28 +310,45 @@ pub async fn aggregate_today(
         ),
     };
 
-    // Process Toggl result
-    let (focus_hours, toggl_status) = match toggl_result {
-        Ok(hours) => (
-            hours,
+    // Process time-tracking result
+    let (time_entries, toggl_status) = match toggl_result {
+        Ok(entries) => (
+            entries,
             SourceStatusDetail::Ok {
                 fetched_at: now.clone(),
+                skipped: Vec::new(),
             },
         ),
-        Err(AppError::NotConfigured(_)) => (0.0, SourceStatusDetail::NotConfigured),
+        Err(AppError::NotConfigured(_)) => (vec![], SourceStatusDetail::NotConfigured),
         Err(e) => (
-            0.0,
+            vec![],
             SourceStatusDetail::Failed {
                 error: e.to_string(),
             },
         ),
     };
 
+    // Focus blocks and time off count towards the breakdown but aren't meetings
+    let calendar_breakdown =
+        meetings::CalendarBreakdown::from_events(&events, meeting_rules.workday(&day));
+    let meetings = events
+        .into_iter()
+        .filter(|m| m.kind.is_collaboration())
+        .collect();
+
+    // Classify tracked time and attach it to the tickets it mentions
+    let time_breakdown = time::TimeBreakdown::from_entries(&time_entries, &time_categories);
+    time::link_tickets(&time_entries, &mut tickets_closed);
+    time::link_tickets(&time_entries, &mut tickets_in_progress);
+
     AggregatedData {
         tickets_closed,
         tickets_in_progress,
         meetings,
-        focus_hours,
+        focus_hours: time_breakdown.focus_hours(),
+        time_breakdown,
+        log_entries,
+        calendar_breakdown,
         data_sources_status: DataSourcesStatus {
             jira: jira_status,
             calendar: calendar_status,
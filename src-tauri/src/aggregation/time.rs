@@ -0,0 +1,314 @@
+//! Time-tracking entries shared by every time source, and how they become the
+//! summary's focus time, category totals and time per ticket.
+
+use crate::aggregation::day::SummaryDay;
+use crate::aggregation::{clockify, harvest, toggl, Ticket};
+use crate::error::AppError;
+use serde::{Deserialize, Serialize};
+
+/// Focus time is capped so a forgotten timer can't report more than a day
+const MAX_FOCUS_HOURS: f32 = 24.0;
+
+/// A single time entry with its elapsed duration resolved
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntry {
+    pub description: String,
+    /// RFC 3339 start time
+    pub start: String,
+    /// RFC 3339 stop time; None while the timer is still running
+    #[serde(default)]
+    pub stop: Option<String>,
+    pub duration_seconds: i64,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub client: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// The configured time-tracking service and its credentials
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "service", rename_all = "lowercase")]
+pub enum TimeSource {
+    Toggl {
+        #[serde(rename = "apiToken")]
+        api_token: String,
+        /// Blank means every workspace
+        #[serde(rename = "workspaceId", default)]
+        workspace_id: String,
+    },
+    Clockify {
+        #[serde(rename = "baseUrl")]
+        base_url: String,
+        #[serde(rename = "apiKey")]
+        api_key: String,
+        /// Blank means the user's active workspace
+        #[serde(rename = "workspaceId", default)]
+        workspace_id: String,
+    },
+    Harvest {
+        #[serde(rename = "baseUrl")]
+        base_url: String,
+        #[serde(rename = "accessToken")]
+        access_token: String,
+        #[serde(rename = "accountId")]
+        account_id: String,
+    },
+    /// The built-in timer; its entries are read from the database for the
+    /// summary day when the source is loaded
+    Native { entries: Vec<TimeEntry> },
+}
+
+impl TimeSource {
+    /// Name shown in messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeSource::Toggl { .. } => "Toggl Track",
+            TimeSource::Clockify { .. } => "Clockify",
+            TimeSource::Harvest { .. } => "Harvest",
+            TimeSource::Native { .. } => "the built-in timer",
+        }
+    }
+
+    /// The user's entries for `day`. Harvest records dates rather than times,
+    /// so it reports the entries dated `day.date`. Native entries were loaded
+    /// for the summary day already and are returned as they are.
+    pub async fn fetch_entries(&self, day: &SummaryDay) -> Result<Vec<TimeEntry>, AppError> {
+        match self {
+            TimeSource::Toggl {
+                api_token,
+                workspace_id,
+            } => {
+                let workspace_id = toggl::parse_workspace_id(workspace_id)?;
+                toggl::fetch_time_entries(api_token, workspace_id, day).await
+            }
+            TimeSource::Clockify {
+                base_url,
+                api_key,
+                workspace_id,
+            } => clockify::fetch_time_entries(base_url, api_key, workspace_id, day).await,
+            TimeSource::Harvest {
+                base_url,
+                access_token,
+                account_id,
+            } => harvest::fetch_time_entries(base_url, access_token, account_id, day.date).await,
+            TimeSource::Native { entries } => Ok(entries.clone()),
+        }
+    }
+}
+
+/// What kind of time an entry is
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeCategory {
+    #[default]
+    Focus,
+    Meetings,
+    Admin,
+}
+
+/// Entries in any of the listed projects, clients or tags get `category`.
+/// Names match case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CategoryRule {
+    pub category: TimeCategory,
+    pub projects: Vec<String>,
+    pub clients: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl CategoryRule {
+    fn matches(&self, entry: &TimeEntry) -> bool {
+        let listed =
+            |names: &[String], value: &str| names.iter().any(|n| n.eq_ignore_ascii_case(value));
+        entry
+            .project
+            .as_deref()
+            .is_some_and(|p| listed(&self.projects, p))
+            || entry
+                .client
+                .as_deref()
+                .is_some_and(|c| listed(&self.clients, c))
+            || entry.tags.iter().any(|t| listed(&self.tags, t))
+    }
+}
+
+/// User-defined classification, stored as JSON in settings. The first
+/// matching rule wins; unmatched entries count as focus time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimeCategories {
+    pub rules: Vec<CategoryRule>,
+}
+
+impl TimeCategories {
+    pub fn classify(&self, entry: &TimeEntry) -> TimeCategory {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(entry))
+            .map(|rule| rule.category)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTime {
+    pub project: String,
+    #[serde(default)]
+    pub client: Option<String>,
+    pub minutes: u32,
+}
+
+/// Tracked time per category and per project
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimeBreakdown {
+    pub focus_minutes: u32,
+    pub meeting_minutes: u32,
+    pub admin_minutes: u32,
+    /// Largest first; entries without a project are listed as "(no project)"
+    pub projects: Vec<ProjectTime>,
+}
+
+impl TimeBreakdown {
+    pub fn from_entries(entries: &[TimeEntry], categories: &TimeCategories) -> TimeBreakdown {
+        let mut breakdown = TimeBreakdown::default();
+        let mut project_seconds: Vec<(String, Option<String>, i64)> = Vec::new();
+
+        let mut seconds = [0i64; 3];
+        for entry in entries {
+            let duration = entry.duration_seconds.max(0);
+            seconds[categories.classify(entry) as usize] += duration;
+
+            let project = entry.project.as_deref().unwrap_or("(no project)");
+            match project_seconds.iter_mut().find(|(p, _, _)| p == project) {
+                Some((_, _, total)) => *total += duration,
+                None => project_seconds.push((project.to_string(), entry.client.clone(), duration)),
+            }
+        }
+
+        breakdown.focus_minutes = (seconds[TimeCategory::Focus as usize] / 60) as u32;
+        breakdown.meeting_minutes = (seconds[TimeCategory::Meetings as usize] / 60) as u32;
+        breakdown.admin_minutes = (seconds[TimeCategory::Admin as usize] / 60) as u32;
+
+        project_seconds.sort_by_key(|(_, _, total)| std::cmp::Reverse(*total));
+        breakdown.projects = project_seconds
+            .into_iter()
+            .map(|(project, client, total)| ProjectTime {
+                project,
+                client,
+                minutes: (total / 60) as u32,
+            })
+            .filter(|p| p.minutes > 0)
+            .collect();
+        breakdown
+    }
+
+    /// Focus time in hours, capped at a day
+    pub fn focus_hours(&self) -> f32 {
+        (self.focus_minutes as f32 / 60.0).min(MAX_FOCUS_HOURS)
+    }
+}
+
+/// True if `text` mentions `key` as a whole ticket key (PROJ-1 but not PROJ-12)
+pub fn mentions_key(text: &str, key: &str) -> bool {
+    text.match_indices(key).any(|(index, _)| {
+        let before = text[..index].chars().next_back();
+        let after = text[index + key.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+/// Record on each ticket how long the entries mentioning its key ran
+pub fn link_tickets(entries: &[TimeEntry], tickets: &mut [Ticket]) {
+    for ticket in tickets {
+        let seconds: i64 = entries
+            .iter()
+            .filter(|e| mentions_key(&e.description, &ticket.id))
+            .map(|e| e.duration_seconds.max(0))
+            .sum();
+        ticket.activity.time_tracked_minutes = ((seconds + 30) / 60) as u32;
+    }
+}
+
+/// "1h 30m", "45m"
+pub fn format_minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(description: &str, minutes: i64, project: Option<&str>, tags: &[&str]) -> TimeEntry {
+        TimeEntry {
+            description: description.to_string(),
+            start: "2026-02-14T09:00:00Z".to_string(),
+            duration_seconds: minutes * 60,
+            project: project.map(str::to_string),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn mentions_key_respects_boundaries() {
+        assert!(mentions_key("Fixed PROJ-1.", "PROJ-1"));
+        assert!(mentions_key("[PROJ-1] login", "PROJ-1"));
+        assert!(!mentions_key("Fixed PROJ-12", "PROJ-1"));
+        assert!(!mentions_key("XPROJ-1", "PROJ-1"));
+    }
+
+    #[test]
+    fn classifies_by_project_and_tag_and_links_tickets() {
+        let categories: TimeCategories = serde_json::from_str(
+            r#"{"rules": [
+                {"category": "meetings", "tags": ["meeting"]},
+                {"category": "admin", "projects": ["Internal"]}
+            ]}"#,
+        )
+        .expect("categories should parse");
+        let entries = [
+            entry("PROJ-7 billing export", 90, Some("Billing"), &[]),
+            entry("Review PROJ-7", 20, Some("Billing"), &["Meeting"]),
+            entry("Expenses", 15, Some("internal"), &[]),
+            entry("PROJ-70 spike", 30, None, &[]),
+        ];
+
+        let breakdown = TimeBreakdown::from_entries(&entries, &categories);
+        assert_eq!(
+            (
+                breakdown.focus_minutes,
+                breakdown.meeting_minutes,
+                breakdown.admin_minutes
+            ),
+            (120, 20, 15)
+        );
+        assert_eq!(breakdown.focus_hours(), 2.0);
+        assert_eq!(breakdown.projects[0].project, "Billing");
+        assert_eq!(breakdown.projects[0].minutes, 110);
+        assert_eq!(breakdown.projects[1].project, "(no project)");
+
+        let mut tickets = [Ticket {
+            id: "PROJ-7".to_string(),
+            title: "Billing export".to_string(),
+            status: "In Progress".to_string(),
+            url: String::new(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }];
+        link_tickets(&entries, &mut tickets);
+        assert_eq!(tickets[0].activity.time_tracked_minutes, 110);
+        assert_eq!(format_minutes(110), "1h 50m");
+    }
+}
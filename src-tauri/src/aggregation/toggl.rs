@@ -1,36 +1,85 @@
+use super::day::SummaryDay;
+use crate::aggregation::time::TimeEntry;
 use crate::error::AppError;
-use chrono::Local;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use std::time::Duration;
 
+/// `/me/time_entries?meta=true` entry; `meta` adds project and client names
 #[derive(Debug, Deserialize)]
 struct TogglTimeEntry {
+    description: Option<String>,
     start: String,
+    stop: Option<String>,
     duration: i64, // seconds, negative if timer is running
+    workspace_id: Option<i64>,
+    project_name: Option<String>,
+    client_name: Option<String>,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
 }
 
-/// Fetch focus hours from Toggl Track for today
-pub async fn fetch_focus_hours_today(
+impl TogglTimeEntry {
+    /// The part of this entry inside `[from, to)`; a running timer counts up to `now`
+    fn into_time_entry(
+        self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> TimeEntry {
+        let start = DateTime::parse_from_rfc3339(&self.start)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or(now);
+        let stop = if self.duration < 0 {
+            now
+        } else {
+            start + chrono::Duration::seconds(self.duration)
+        };
+
+        TimeEntry {
+            description: self.description.unwrap_or_default(),
+            start: self.start,
+            stop: self.stop,
+            duration_seconds: (stop.min(to) - start.max(from)).num_seconds().max(0),
+            project: self.project_name,
+            client: self.client_name,
+            tags: self.tags.unwrap_or_default(),
+        }
+    }
+}
+
+/// Parse the configured workspace id; blank means every workspace
+pub fn parse_workspace_id(workspace_id: &str) -> Result<Option<i64>, AppError> {
+    let workspace_id = workspace_id.trim();
+    if workspace_id.is_empty() {
+        return Ok(None);
+    }
+    workspace_id.parse().map(Some).map_err(|_| {
+        AppError::NotConfigured(format!(
+            "Toggl workspace ID must be a number, got '{}'",
+            workspace_id
+        ))
+    })
+}
+
+/// Fetch Toggl Track time entries for a summary day, optionally limited to one
+/// workspace (the API returns entries from all of the user's workspaces)
+pub async fn fetch_time_entries(
     api_token: &str,
-    _workspace_id: &str, // Not used in v9 API for time entries endpoint
-) -> Result<f32, AppError> {
+    workspace_id: Option<i64>,
+    day: &SummaryDay,
+) -> Result<Vec<TimeEntry>, AppError> {
     let client = Client::builder()
         .timeout(Duration::from_secs(10))
         .connect_timeout(Duration::from_secs(10))
         .build()
         .map_err(|e| AppError::NotConfigured(format!("HTTP client error: {}", e)))?;
 
-    // Get today's date range
-    let today = Local::now().date_naive();
-    let today_start = format!("{}T00:00:00Z", today);
-    let tomorrow = today
-        .succ_opt()
-        .ok_or_else(|| AppError::TogglError("Cannot calculate tomorrow's date".to_string()))?;
-    let tomorrow_start = format!("{}T00:00:00Z", tomorrow);
+    let (today_start, tomorrow_start) = day.utc_bounds();
 
     let url = format!(
-        "https://api.track.toggl.com/api/v9/me/time_entries?start_date={}&end_date={}",
+        "https://api.track.toggl.com/api/v9/me/time_entries?meta=true&start_date={}&end_date={}",
         urlencoding::encode(&today_start),
         urlencoding::encode(&tomorrow_start)
     );
@@ -75,25 +124,48 @@ pub async fn fetch_focus_hours_today(
         .await
         .map_err(|e| AppError::TogglError(format!("Failed to parse time entries: {}", e)))?;
 
-    // Sum durations
-    let now = chrono::Utc::now().timestamp();
-    let mut total_seconds: i64 = 0;
-
-    for entry in entries {
-        if entry.duration < 0 {
-            // Timer is running, calculate current duration
-            let start_timestamp = chrono::DateTime::parse_from_rfc3339(&entry.start)
-                .map(|dt| dt.timestamp())
-                .unwrap_or(0);
-            total_seconds += now - start_timestamp;
-        } else {
-            total_seconds += entry.duration;
-        }
-    }
+    let now = Utc::now();
 
-    // Convert to hours and cap at 24
-    let hours = (total_seconds as f32) / 3600.0;
-    let capped_hours = hours.min(24.0);
+    Ok(entries
+        .into_iter()
+        .filter(|entry| workspace_id.is_none() || entry.workspace_id == workspace_id)
+        .map(|entry| entry.into_time_entry(day.start, day.end, now))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(capped_hours)
+    #[test]
+    fn clips_entries_to_the_day() {
+        let entries: Vec<TogglTimeEntry> = serde_json::from_str(
+            r#"[
+                {"description": "PROJ-7 export", "start": "2026-02-14T09:00:00Z",
+                 "stop": "2026-02-14T10:30:00Z", "duration": 5400, "workspace_id": 1},
+                {"description": null, "start": "2026-02-14T11:00:00Z", "stop": null,
+                 "duration": -1771066800, "workspace_id": 1},
+                {"description": "late night", "start": "2026-02-13T23:00:00Z",
+                 "stop": "2026-02-14T01:00:00Z", "duration": 7200, "workspace_id": 1}
+            ]"#,
+        )
+        .expect("entries should parse");
+        let time = |t: &str| {
+            DateTime::parse_from_rfc3339(t)
+                .expect("valid timestamp")
+                .with_timezone(&Utc)
+        };
+        let (from, to) = (time("2026-02-14T00:00:00Z"), time("2026-02-15T00:00:00Z"));
+        let now = time("2026-02-14T11:20:00Z");
+        let mapped: Vec<TimeEntry> = entries
+            .into_iter()
+            .map(|e| e.into_time_entry(from, to, now))
+            .collect();
+
+        assert_eq!(mapped[0].duration_seconds, 5400);
+        // Running timer counts up to now
+        assert_eq!(mapped[1].duration_seconds, 1200);
+        // Only the hour after midnight belongs to the day
+        assert_eq!(mapped[2].duration_seconds, 3600);
+    }
 }
@@ -0,0 +1,177 @@
+//! Quick-capture activity log: short timestamped notes taken during the day
+//! from the app, the tray menu or the global shortcut.
+//!
+//! A note may name a ticket explicitly; otherwise the first ticket key in the
+//! text is used. `#words` become tags.
+
+use crate::aggregation::day::SummaryDay;
+use crate::aggregation::LogEntry;
+use crate::error::AppError;
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{Row, SqlitePool};
+
+/// Longest note accepted, in characters
+const MAX_NOTE_CHARS: usize = 2000;
+
+/// `#tag` words in `text`, lowercased and without duplicates
+fn hashtags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        let Some(tag) = word.strip_prefix('#') else {
+            continue;
+        };
+        let tag = tag
+            .trim_end_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Validate a note and fill in its ticket key and tags
+fn prepare(
+    text: &str,
+    ticket_key: Option<String>,
+    tags: Vec<String>,
+) -> Result<(String, Option<String>, Vec<String>), AppError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AppError::NotConfigured("Note is empty".to_string()));
+    }
+    if text.chars().count() > MAX_NOTE_CHARS {
+        return Err(AppError::NotConfigured(format!(
+            "Notes are limited to {} characters",
+            MAX_NOTE_CHARS
+        )));
+    }
+
+    let ticket_key = match ticket_key
+        .map(|k| k.trim().to_ascii_uppercase())
+        .filter(|k| !k.is_empty())
+    {
+        Some(key) => match crate::llm::factcheck::ticket_keys(&key).as_slice() {
+            [only] if *only == key => Some(key),
+            _ => {
+                return Err(AppError::NotConfigured(format!(
+                    "'{}' is not a Jira issue key (expected e.g. PROJ-123)",
+                    key
+                )))
+            }
+        },
+        None => crate::llm::factcheck::ticket_keys(text).into_iter().next(),
+    };
+
+    let mut all_tags = hashtags(text);
+    for tag in tags {
+        let tag = tag.trim().trim_start_matches('#').to_lowercase();
+        if !tag.is_empty() && !all_tags.contains(&tag) {
+            all_tags.push(tag);
+        }
+    }
+
+    Ok((text.to_string(), ticket_key, all_tags))
+}
+
+fn entry_from_row(row: &sqlx::sqlite::SqliteRow) -> LogEntry {
+    LogEntry {
+        id: row.get("id"),
+        logged_at: row.get("logged_at"),
+        text: row.get("text"),
+        ticket_key: row.get("ticket_key"),
+        tags: serde_json::from_str(&row.get::<String, _>("tags")).unwrap_or_default(),
+    }
+}
+
+/// Store a note taken at `at`
+pub async fn capture(
+    db: &SqlitePool,
+    text: &str,
+    ticket_key: Option<String>,
+    tags: Vec<String>,
+    at: DateTime<Utc>,
+) -> Result<LogEntry, AppError> {
+    let (text, ticket_key, tags) = prepare(text, ticket_key, tags)?;
+    let tags = serde_json::to_string(&tags)
+        .map_err(|e| AppError::DatabaseError(format!("Cannot serialize tags: {}", e)))?;
+
+    let row = sqlx::query(
+        r#"
+        INSERT INTO log_entries (logged_at, text, ticket_key, tags)
+        VALUES (?1, ?2, ?3, ?4)
+        RETURNING id, logged_at, text, ticket_key, tags
+        "#,
+    )
+    .bind(crate::timer::format_timestamp(at))
+    .bind(&text)
+    .bind(&ticket_key)
+    .bind(&tags)
+    .fetch_one(db)
+    .await?;
+    Ok(entry_from_row(&row))
+}
+
+/// Notes taken during the summary day, oldest first, timestamped in its zone
+pub async fn list_for_day(db: &SqlitePool, day: &SummaryDay) -> Result<Vec<LogEntry>, AppError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT id, logged_at, text, ticket_key, tags FROM log_entries
+        WHERE logged_at >= ?1 AND logged_at < ?2
+        ORDER BY logged_at
+        "#,
+    )
+    .bind(crate::timer::format_timestamp(day.start))
+    .bind(crate::timer::format_timestamp(day.end))
+    .fetch_all(db)
+    .await?;
+    Ok(rows
+        .iter()
+        .map(entry_from_row)
+        .map(|mut entry| {
+            if let Some(at) = crate::timer::parse_timestamp(&entry.logged_at) {
+                entry.logged_at = day
+                    .boundary
+                    .localize(at)
+                    .to_rfc3339_opts(SecondsFormat::Secs, false);
+            }
+            entry
+        })
+        .collect())
+}
+
+pub async fn delete(db: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM log_entries WHERE id = ?1")
+        .bind(id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_ticket_key_and_tags() {
+        let (text, key, tags) = prepare(
+            "  Paired with Sam on PROJ-7, found the cache bug #pairing #Bug. ",
+            None,
+            vec!["#bug".to_string(), "review".to_string()],
+        )
+        .expect("note should be valid");
+        assert_eq!(
+            text,
+            "Paired with Sam on PROJ-7, found the cache bug #pairing #Bug."
+        );
+        assert_eq!(key.as_deref(), Some("PROJ-7"));
+        assert_eq!(tags, ["pairing", "bug", "review"]);
+
+        let (_, key, _) = prepare("Spike on OPS-3", Some("proj-9".to_string()), vec![])
+            .expect("note should be valid");
+        assert_eq!(key.as_deref(), Some("PROJ-9"));
+
+        assert!(prepare("   ", None, vec![]).is_err());
+        assert!(prepare("note", Some("PROJ".to_string()), vec![]).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::db::queries;
 use crate::error::AppError;
-use chrono::Local;
+use crate::llm::structured::StructuredNarrative;
+use chrono::NaiveDate;
 use serde::Deserialize;
 use sqlx::{Row, SqlitePool};
 use tauri::{AppHandle, State};
@@ -21,7 +22,11 @@ pub struct SummaryInput {
 pub async fn get_today_summary(
     db: State<'_, SqlitePool>,
 ) -> Result<Option<serde_json::Value>, AppError> {
-    let today = Local::now().format("%Y-%m-%d").to_string();
+    let today = load_day_boundary(db.inner())
+        .await?
+        .today()?
+        .date
+        .to_string();
     let summary = queries::get_summary_by_date(&db, &today).await?;
     Ok(summary)
 }
@@ -31,7 +36,11 @@ pub async fn save_summary(
     db: State<'_, SqlitePool>,
     input: SummaryInput,
 ) -> Result<serde_json::Value, AppError> {
-    let today = Local::now().format("%Y-%m-%d").to_string();
+    let today = load_day_boundary(db.inner())
+        .await?
+        .today()?
+        .date
+        .to_string();
 
     // Upsert the summary
     let _id = queries::upsert_summary(
@@ -78,56 +87,46 @@ pub async fn generate_summary(
     db: State<'_, SqlitePool>,
     app: AppHandle,
 ) -> Result<serde_json::Value, AppError> {
-    let today = Local::now().format("%Y-%m-%d").to_string();
+    let day = load_day_boundary(db.inner()).await?.today()?;
+    let today = day.date.to_string();
 
     // Load settings from database
     let settings_row = sqlx::query(
         r#"
-        SELECT jira_base_url, jira_project_key, toggl_workspace_id
+        SELECT jira_project_key, jira_jql, time_categories, meeting_rules
         FROM settings WHERE id = 1
         "#,
     )
     .fetch_one(db.inner())
     .await?;
 
-    let jira_base_url: Option<String> = settings_row.get("jira_base_url");
-    let jira_project_key: Option<String> = settings_row.get("jira_project_key");
-    let toggl_workspace_id: Option<String> = settings_row.get("toggl_workspace_id");
+    let jira_query = crate::aggregation::jira::JiraQuery::from_settings(
+        settings_row
+            .get::<Option<String>, _>("jira_project_key")
+            .as_deref(),
+        settings_row.get::<Option<String>, _>("jira_jql").as_deref(),
+    );
+    let time_categories = parse_time_categories(&settings_row.get::<String, _>("time_categories"));
+    let meeting_rules = parse_meeting_rules(&settings_row.get::<String, _>("meeting_rules"));
 
     // Load secrets from encrypted storage
-    let jira_email = crate::stronghold::get_secret(&app, crate::stronghold::keys::JIRA_EMAIL)?;
-    let jira_api_token =
-        crate::stronghold::get_secret(&app, crate::stronghold::keys::JIRA_API_TOKEN)?;
-    let toggl_api_token =
-        crate::stronghold::get_secret(&app, crate::stronghold::keys::TOGGL_API_TOKEN)?;
-
-    // Get Google Calendar access token (refresh if needed)
-    let calendar_access_token = if let Some(refresh_token) =
-        crate::stronghold::get_secret(&app, crate::stronghold::keys::GOOGLE_REFRESH_TOKEN)?
-    {
-        // Refresh the access token
-        let client_id = std::env::var("GOOGLE_CLIENT_ID")
-            .unwrap_or_else(|_| "YOUR_CLIENT_ID.apps.googleusercontent.com".to_string());
-        let client_secret = std::env::var("GOOGLE_CLIENT_SECRET")
-            .unwrap_or_else(|_| "YOUR_CLIENT_SECRET".to_string());
-
-        match crate::oauth::GoogleOAuthClient::new(client_id, client_secret) {
-            Ok(oauth_client) => oauth_client.refresh_access_token(refresh_token).await.ok(),
-            Err(_) => None,
-        }
-    } else {
-        None
-    };
+    let jira_connection = load_jira_connection(db.inner(), &app).await?;
+    let time_source = load_time_source(db.inner(), &app, &day).await?;
+    let log_entries = crate::capture::list_for_day(db.inner(), &day).await?;
+
+    // Fresh access tokens for every connected Google account
+    let calendar_accounts = crate::google_accounts::calendar_accounts(db.inner(), &app).await?;
 
     // Aggregate data from all sources
     let aggregated_data = crate::aggregation::aggregate_today(
-        jira_base_url,
-        jira_email,
-        jira_api_token,
-        jira_project_key,
-        calendar_access_token,
-        toggl_api_token,
-        toggl_workspace_id,
+        day,
+        jira_connection,
+        jira_query,
+        calendar_accounts,
+        meeting_rules,
+        time_source,
+        time_categories,
+        log_entries,
     )
     .await;
 
@@ -142,6 +141,14 @@ pub async fn generate_summary(
         .map_err(|e| AppError::DatabaseError(format!("Cannot serialize meetings: {}", e)))?;
     let sources_status_json = serde_json::to_string(&aggregated_data.data_sources_status)
         .map_err(|e| AppError::DatabaseError(format!("Cannot serialize sources_status: {}", e)))?;
+    let time_breakdown_json = serde_json::to_string(&aggregated_data.time_breakdown)
+        .map_err(|e| AppError::DatabaseError(format!("Cannot serialize time_breakdown: {}", e)))?;
+    let log_entries_json = serde_json::to_string(&aggregated_data.log_entries)
+        .map_err(|e| AppError::DatabaseError(format!("Cannot serialize log_entries: {}", e)))?;
+    let calendar_breakdown_json = serde_json::to_string(&aggregated_data.calendar_breakdown)
+        .map_err(|e| {
+            AppError::DatabaseError(format!("Cannot serialize calendar_breakdown: {}", e))
+        })?;
 
     // Insert/update in database
     sqlx::query(
@@ -152,14 +159,24 @@ pub async fn generate_summary(
             tickets_in_progress,
             meetings,
             focus_hours,
-            sources_status
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            sources_status,
+            time_breakdown,
+            log_entries,
+            calendar_breakdown,
+            time_zone,
+            day_start
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
         ON CONFLICT(summary_date) DO UPDATE SET
             tickets_closed = ?2,
             tickets_in_progress = ?3,
             meetings = ?4,
             focus_hours = ?5,
             sources_status = ?6,
+            time_breakdown = ?7,
+            log_entries = ?8,
+            calendar_breakdown = ?9,
+            time_zone = ?10,
+            day_start = ?11,
             updated_at = datetime('now')
         "#,
     )
@@ -169,6 +186,11 @@ pub async fn generate_summary(
     .bind(&meetings_json)
     .bind(aggregated_data.focus_hours)
     .bind(&sources_status_json)
+    .bind(&time_breakdown_json)
+    .bind(&log_entries_json)
+    .bind(&calendar_breakdown_json)
+    .bind(day.zone_label())
+    .bind(day.boundary.day_start())
     .execute(db.inner())
     .await?;
 
@@ -189,12 +211,13 @@ pub async fn regenerate_narrative(
     db: State<'_, SqlitePool>,
     summary_id: i64,
     tone: String,
-) -> Result<String, AppError> {
+) -> Result<crate::llm::CheckedNarrative, AppError> {
     // Load the summary from database
     let row = sqlx::query(
         r#"
         SELECT summary_date, tickets_closed, tickets_in_progress, meetings, focus_hours,
-               blockers, tomorrow_priorities, manual_notes
+               time_breakdown, log_entries, calendar_breakdown, blockers, tomorrow_priorities,
+               manual_notes
         FROM daily_summaries
         WHERE id = ?1
         "#,
@@ -237,6 +260,12 @@ pub async fn regenerate_narrative(
             Vec::new()
         });
     let focus_hours: f32 = row.get("focus_hours");
+    let time_breakdown =
+        serde_json::from_str(&row.get::<String, _>("time_breakdown")).unwrap_or_default();
+    let log_entries =
+        serde_json::from_str(&row.get::<String, _>("log_entries")).unwrap_or_default();
+    let calendar_breakdown =
+        serde_json::from_str(&row.get::<String, _>("calendar_breakdown")).unwrap_or_default();
 
     // Build AggregatedData from stored data
     let aggregated_data = crate::aggregation::AggregatedData {
@@ -244,6 +273,9 @@ pub async fn regenerate_narrative(
         tickets_in_progress,
         meetings,
         focus_hours,
+        time_breakdown,
+        log_entries,
+        calendar_breakdown,
         data_sources_status: crate::aggregation::DataSourcesStatus {
             jira: crate::aggregation::SourceStatusDetail::NotConfigured,
             calendar: crate::aggregation::SourceStatusDetail::NotConfigured,
@@ -265,40 +297,93 @@ pub async fn regenerate_narrative(
     let temperature = 0.7;
     let timeout_secs = 15;
 
-    // Try to generate narrative with LLM
-    let narrative = match crate::llm::generate_narrative(
-        &aggregated_data,
-        &user_fields,
-        &tone,
-        model,
+    let prompt_template = crate::llm::tones::resolve_template(db.inner(), &tone).await?;
+    let llm_settings = sqlx::query(
+        "SELECT llm_output_format, llm_num_ctx, llm_redaction_profile_id FROM settings WHERE id = 1",
+    )
+    .fetch_one(db.inner())
+    .await?;
+    let params = crate::llm::LlmParams {
+        model: model.to_string(),
         temperature,
         timeout_secs,
+        num_ctx: llm_settings.get::<i64, _>("llm_num_ctx") as u32,
+        format: crate::llm::structured::NarrativeFormat::parse(
+            &llm_settings.get::<String, _>("llm_output_format"),
+        ),
+    };
+
+    // Mask sensitive titles and notes before anything is put in the prompt
+    let (aggregated_data, user_fields) = match llm_settings
+        .get::<Option<i64>, _>("llm_redaction_profile_id")
+    {
+        Some(profile_id) => {
+            let redactor = crate::redaction::load_redactor(db.inner(), profile_id).await?;
+            let mut report = Vec::new();
+            let data = redactor.aggregated(&aggregated_data, &mut report);
+            let mut redact = |field: &str, text: &Option<String>| {
+                text.as_deref()
+                    .map(|text| redactor.free_text(text, field, &mut report))
+            };
+            let fields = SummaryInput {
+                blockers: redact("blockers", &user_fields.blockers),
+                tomorrow_priorities: redact("tomorrowPriorities", &user_fields.tomorrow_priorities),
+                manual_notes: redact("manualNotes", &user_fields.manual_notes),
+                narrative: None,
+                tone: user_fields.tone.clone(),
+            };
+            (data, fields)
+        }
+        None => (aggregated_data, user_fields),
+    };
+
+    // Try to generate narrative with LLM, checked against the aggregated data
+    let checked = match crate::llm::generate_checked_narrative(
+        &aggregated_data,
+        &user_fields,
+        &prompt_template,
+        &params,
     )
     .await
     {
-        Ok(text) => text,
+        Ok(checked) => checked,
         Err(e) => {
             // LLM failed, use bullet fallback
             eprintln!("LLM generation failed: {}. Using bullet fallback.", e);
-            crate::llm::generate_bullet_fallback(&aggregated_data, &user_fields)
+            crate::llm::CheckedNarrative {
+                narrative: crate::llm::generate_bullet_fallback(&aggregated_data, &user_fields),
+                structured: None,
+                warnings: Vec::new(),
+            }
         }
     };
 
+    let warnings_json = serde_json::to_string(&checked.warnings)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to serialize warnings: {}", e)))?;
+    let structured_json = checked
+        .structured
+        .as_ref()
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| AppError::DatabaseError(format!("Failed to serialize sections: {}", e)))?;
+
     // Update narrative in database
     sqlx::query(
         r#"
         UPDATE daily_summaries
-        SET narrative = ?1, tone = ?2
-        WHERE id = ?3
+        SET narrative = ?1, tone = ?2, narrative_warnings = ?3, narrative_structured = ?4
+        WHERE id = ?5
         "#,
     )
-    .bind(&narrative)
+    .bind(&checked.narrative)
     .bind(&tone)
+    .bind(&warnings_json)
+    .bind(&structured_json)
     .bind(summary_id)
     .execute(db.inner())
     .await?;
 
-    Ok(narrative)
+    Ok(checked)
 }
 
 // ── Phase 4: Delivery ──
@@ -319,55 +404,88 @@ pub async fn send_summary(
     app: AppHandle,
 ) -> Result<Vec<crate::delivery::DeliveryConfirmation>, AppError> {
     // Convert frontend configs to backend enum format, injecting secrets
-    let mut backend_configs: Vec<crate::delivery::DeliveryConfig> = Vec::new();
+    let mut backend_configs: Vec<(
+        crate::delivery::DeliveryConfig,
+        Option<crate::redaction::Redactor>,
+    )> = Vec::new();
 
     for input in delivery_configs {
         if !input.is_enabled {
             continue;
         }
 
+        if !crate::delivery::DELIVERY_TYPES.contains(&input.delivery_type.as_str()) {
+            return Err(AppError::NotConfigured(format!(
+                "Unsupported delivery type: {}",
+                input.delivery_type
+            )));
+        }
+
         let mut config_map = input.config;
 
         // Inject secrets from vault
-        if input.delivery_type == "email" {
-            if let Some(password) =
-                crate::stronghold::get_secret(&app, crate::stronghold::keys::SMTP_PASSWORD)?
+        if let Some((field, key)) = delivery_secret_field(&input.delivery_type) {
+            if let Some(secret) = crate::stronghold::get_secret(&app, key)? {
+                config_map.insert(field.to_string(), serde_json::Value::String(secret));
+            }
+        } else if input.delivery_type == "webhook" {
+            if let Some(stored) =
+                crate::stronghold::get_secret(&app, crate::stronghold::keys::WEBHOOK_SECRETS)?
             {
-                config_map.insert("password".to_string(), serde_json::Value::String(password));
+                crate::delivery::webhook::inject_secrets(&mut config_map, &stored);
             }
+        } else if input.delivery_type == "jira" {
+            let summary_date: String =
+                sqlx::query_scalar("SELECT summary_date FROM daily_summaries WHERE id = ?1")
+                    .bind(summary_id)
+                    .fetch_optional(db.inner())
+                    .await?
+                    .ok_or_else(|| {
+                        AppError::DatabaseError(format!("Summary {} not found", summary_id))
+                    })?;
+            inject_jira_credentials(db.inner(), &app, &summary_date, &mut config_map).await?;
+        }
 
-            // Convert to enum variant
-            let json_value = serde_json::Value::Object(config_map);
-            let email_config = serde_json::from_value(json_value).map_err(|e| {
-                AppError::NotConfigured(format!("Invalid email delivery config: {}", e))
+        // Attach the body of the chosen user template
+        if let Some(template_id) = config_map.get("templateId").and_then(|v| v.as_i64()) {
+            let body: Option<String> =
+                sqlx::query_scalar("SELECT body FROM summary_templates WHERE id = ?1")
+                    .bind(template_id)
+                    .fetch_optional(db.inner())
+                    .await?;
+            let body = body.ok_or_else(|| {
+                AppError::NotConfigured(format!(
+                    "Template {} used by {} delivery no longer exists",
+                    template_id, input.delivery_type
+                ))
             })?;
-            backend_configs.push(crate::delivery::DeliveryConfig::Email(email_config));
-        } else if input.delivery_type == "slack" {
-            if let Some(webhook) =
-                crate::stronghold::get_secret(&app, crate::stronghold::keys::SLACK_WEBHOOK_URL)?
-            {
-                config_map.insert("webhookUrl".to_string(), serde_json::Value::String(webhook));
+            config_map.insert("template".to_string(), serde_json::Value::String(body));
+        }
+
+        // Redaction profile for this target, applied just before rendering
+        let redactor = match config_map
+            .get("redactionProfileId")
+            .and_then(|v| v.as_i64())
+        {
+            Some(profile_id) => {
+                Some(crate::redaction::load_redactor(db.inner(), profile_id).await?)
             }
+            None => None,
+        };
 
-            // Convert to enum variant
-            let json_value = serde_json::Value::Object(config_map);
-            let slack_config = serde_json::from_value(json_value).map_err(|e| {
-                AppError::NotConfigured(format!("Invalid slack delivery config: {}", e))
+        // Convert to enum variant
+        config_map.insert(
+            "type".to_string(),
+            serde_json::Value::String(input.delivery_type.clone()),
+        );
+        let backend_config = serde_json::from_value(serde_json::Value::Object(config_map))
+            .map_err(|e| {
+                AppError::NotConfigured(format!(
+                    "Invalid {} delivery config: {}",
+                    input.delivery_type, e
+                ))
             })?;
-            backend_configs.push(crate::delivery::DeliveryConfig::Slack(slack_config));
-        } else if input.delivery_type == "file" {
-            // Convert to enum variant
-            let json_value = serde_json::Value::Object(config_map);
-            let file_config = serde_json::from_value(json_value).map_err(|e| {
-                AppError::NotConfigured(format!("Invalid file delivery config: {}", e))
-            })?;
-            backend_configs.push(crate::delivery::DeliveryConfig::File(file_config));
-        } else {
-            return Err(AppError::NotConfigured(format!(
-                "Unsupported delivery type: {}",
-                input.delivery_type
-            )));
-        }
+        backend_configs.push((backend_config, redactor));
     }
 
     if backend_configs.is_empty() {
@@ -380,7 +498,8 @@ pub async fn send_summary(
     let row = sqlx::query(
         r#"
         SELECT summary_date, tickets_closed, tickets_in_progress, meetings, focus_hours,
-               blockers, tomorrow_priorities, manual_notes, narrative, delivered_to
+               log_entries, blockers, tomorrow_priorities, manual_notes, narrative, delivered_to,
+               narrative_structured, calendar_breakdown
         FROM daily_summaries
         WHERE id = ?1
         "#,
@@ -428,24 +547,42 @@ pub async fn send_summary(
     let manual_notes: String = row.get("manual_notes");
     let narrative: String = row.get("narrative");
     let summary_date: String = row.get("summary_date");
+    let log_entries: Vec<crate::aggregation::LogEntry> =
+        serde_json::from_str(&row.get::<String, _>("log_entries")).unwrap_or_default();
 
-    // Render to markdown
-    let markdown =
-        crate::markdown::render_summary_to_markdown(crate::markdown::SummaryMarkdownInput {
-            date: &summary_date,
-            narrative: &narrative,
-            tickets_closed: &tickets_closed,
-            tickets_in_progress: &tickets_in_progress,
-            meetings: &meetings,
-            focus_hours,
-            blockers: &blockers,
-            tomorrow_priorities: &tomorrow_priorities,
-            manual_notes: &manual_notes,
-        });
+    let summary = StoredSummary {
+        date: summary_date,
+        narrative,
+        tickets_closed,
+        tickets_in_progress,
+        meetings,
+        focus_hours,
+        blockers,
+        tomorrow_priorities,
+        manual_notes,
+        log_entries,
+        narrative_structured: row
+            .get::<Option<String>, _>("narrative_structured")
+            .and_then(|json| serde_json::from_str(&json).ok()),
+        calendar_breakdown: serde_json::from_str(&row.get::<String, _>("calendar_breakdown"))
+            .unwrap_or_default(),
+    };
 
-    // Send to all targets
-    let confirmations =
-        crate::delivery::send_summary(&markdown, &summary_date, backend_configs).await;
+    // Send to all targets, each with its own redaction profile applied
+    let mut confirmations = Vec::new();
+    for (config, redactor) in backend_configs {
+        let mut redacted;
+        let target_summary = match &redactor {
+            Some(redactor) => {
+                redacted = summary.clone();
+                redacted.redact(redactor);
+                &redacted
+            }
+            None => &summary,
+        };
+        confirmations
+            .extend(crate::delivery::send_summary(target_summary.as_input(), vec![config]).await);
+    }
 
     // Update delivered_to field with successful deliveries
     let successful_deliveries: Vec<String> = confirmations
@@ -479,15 +616,393 @@ pub async fn send_summary(
     Ok(confirmations)
 }
 
+/// Jira URL and auth settings plus the vault's credentials; None without a URL
+async fn load_jira_connection(
+    db: &SqlitePool,
+    app: &AppHandle,
+) -> Result<Option<crate::aggregation::jira::JiraConnection>, AppError> {
+    use crate::stronghold::keys;
+
+    let row = sqlx::query(
+        "SELECT jira_base_url, jira_auth_mode, jira_ca_cert_path FROM settings WHERE id = 1",
+    )
+    .fetch_one(db)
+    .await?;
+    let Some(base_url) = row.get::<Option<String>, _>("jira_base_url") else {
+        return Ok(None);
+    };
+
+    Ok(Some(crate::aggregation::jira::JiraConnection {
+        base_url,
+        auth_mode: crate::aggregation::jira::JiraAuthMode::parse(
+            &row.get::<String, _>("jira_auth_mode"),
+        ),
+        email: crate::stronghold::get_secret(app, keys::JIRA_EMAIL)?.unwrap_or_default(),
+        api_token: crate::stronghold::get_secret(app, keys::JIRA_API_TOKEN)?.unwrap_or_default(),
+        ca_cert_path: row.get("jira_ca_cert_path"),
+    }))
+}
+
+/// Jira delivery reuses the aggregation credentials instead of storing its own.
+/// The built-in timer's entries are those of `summary_date`.
+async fn inject_jira_credentials(
+    db: &SqlitePool,
+    app: &AppHandle,
+    summary_date: &str,
+    config_map: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<(), AppError> {
+    use serde_json::Value;
+
+    if let Some(connection) = load_jira_connection(db, app).await? {
+        config_map.insert("baseUrl".to_string(), Value::String(connection.base_url));
+        config_map.insert(
+            "authMode".to_string(),
+            Value::String(connection.auth_mode.as_str().to_string()),
+        );
+        config_map.insert("email".to_string(), Value::String(connection.email));
+        config_map.insert("apiToken".to_string(), Value::String(connection.api_token));
+        if let Some(path) = connection.ca_cert_path {
+            config_map.insert("caCertPath".to_string(), Value::String(path));
+        }
+    }
+    let date = NaiveDate::parse_from_str(summary_date, "%Y-%m-%d")
+        .map_err(|e| AppError::DatabaseError(format!("Invalid summary date: {}", e)))?;
+    let day = load_day_boundary(db).await?.day(date)?;
+    if let Some(zone) = day.boundary.iana_name() {
+        config_map.insert("timeZone".to_string(), Value::String(zone.to_string()));
+    }
+    config_map.insert(
+        "dayStart".to_string(),
+        Value::String(day.boundary.day_start()),
+    );
+    if let Some(source) = load_time_source(db, app, &day).await? {
+        let source = serde_json::to_value(source)
+            .map_err(|e| AppError::DatabaseError(format!("Cannot serialize time source: {}", e)))?;
+        config_map.insert("timeSource".to_string(), source);
+    }
+
+    Ok(())
+}
+
+/// The summary time zone and day start from settings
+pub async fn load_day_boundary(
+    db: &SqlitePool,
+) -> Result<crate::aggregation::day::DayBoundary, AppError> {
+    let row = sqlx::query("SELECT time_zone, day_start FROM settings WHERE id = 1")
+        .fetch_one(db)
+        .await?;
+    crate::aggregation::day::DayBoundary::new(
+        row.get::<Option<String>, _>("time_zone").as_deref(),
+        &row.get::<String, _>("day_start"),
+    )
+}
+
+/// The selected time-tracking service with its vault credentials; None when
+/// its token has not been stored. The built-in timer is loaded for `day`.
+async fn load_time_source(
+    db: &SqlitePool,
+    app: &AppHandle,
+    day: &crate::aggregation::day::SummaryDay,
+) -> Result<Option<crate::aggregation::time::TimeSource>, AppError> {
+    use crate::aggregation::time::TimeSource;
+    use crate::aggregation::{clockify, harvest};
+    use crate::stronghold::keys;
+
+    let row = sqlx::query(
+        r#"
+        SELECT time_source, toggl_workspace_id, clockify_workspace_id, clockify_base_url,
+               harvest_account_id, harvest_base_url
+        FROM settings WHERE id = 1
+        "#,
+    )
+    .fetch_one(db)
+    .await?;
+    let text = |column: &str| row.get::<Option<String>, _>(column).unwrap_or_default();
+    let base_url = |column: &str, default: &str| {
+        row.get::<Option<String>, _>(column)
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| default.to_string())
+    };
+
+    let source = match row.get::<String, _>("time_source").as_str() {
+        "native" => Some(TimeSource::Native {
+            entries: crate::timer::time_entries_for_day(db, day).await?,
+        }),
+        "clockify" => crate::stronghold::get_secret(app, keys::CLOCKIFY_API_KEY)?.map(|api_key| {
+            TimeSource::Clockify {
+                base_url: base_url("clockify_base_url", clockify::DEFAULT_BASE_URL),
+                api_key,
+                workspace_id: text("clockify_workspace_id"),
+            }
+        }),
+        "harvest" => {
+            crate::stronghold::get_secret(app, keys::HARVEST_ACCESS_TOKEN)?.map(|access_token| {
+                TimeSource::Harvest {
+                    base_url: base_url("harvest_base_url", harvest::DEFAULT_BASE_URL),
+                    access_token,
+                    account_id: text("harvest_account_id"),
+                }
+            })
+        }
+        _ => crate::stronghold::get_secret(app, keys::TOGGL_API_TOKEN)?.map(|api_token| {
+            TimeSource::Toggl {
+                api_token,
+                workspace_id: text("toggl_workspace_id"),
+            }
+        }),
+    };
+    Ok(source)
+}
+
+/// Show exactly which Jira issues a Jira delivery would comment on or log work to
+#[tauri::command]
+pub async fn preview_jira_delivery(
+    db: State<'_, SqlitePool>,
+    summary_id: i64,
+    config: serde_json::Map<String, serde_json::Value>,
+    app: AppHandle,
+) -> Result<Vec<crate::delivery::jira::JiraIssueAction>, AppError> {
+    let row = sqlx::query(
+        r#"
+        SELECT summary_date, tickets_closed, tickets_in_progress, narrative
+        FROM daily_summaries
+        WHERE id = ?1
+        "#,
+    )
+    .bind(summary_id)
+    .fetch_optional(db.inner())
+    .await?;
+
+    let row =
+        row.ok_or_else(|| AppError::DatabaseError(format!("Summary {} not found", summary_id)))?;
+
+    let mut tickets_closed: Vec<crate::aggregation::Ticket> =
+        serde_json::from_str(&row.get::<String, _>("tickets_closed")).unwrap_or_default();
+    let mut tickets_in_progress: Vec<crate::aggregation::Ticket> =
+        serde_json::from_str(&row.get::<String, _>("tickets_in_progress")).unwrap_or_default();
+    let summary_date: String = row.get("summary_date");
+    let mut narrative: String = row.get("narrative");
+
+    // Preview what the target would send, with its redaction profile applied
+    if let Some(profile_id) = config.get("redactionProfileId").and_then(|v| v.as_i64()) {
+        let redactor = crate::redaction::load_redactor(db.inner(), profile_id).await?;
+        let mut report = Vec::new();
+        redactor.tickets(&mut tickets_closed, "ticketsClosed", &mut report);
+        redactor.tickets(&mut tickets_in_progress, "ticketsInProgress", &mut report);
+        narrative = redactor.free_text(&narrative, "narrative", &mut report);
+    }
+
+    let mut config_map = config;
+    inject_jira_credentials(db.inner(), &app, &summary_date, &mut config_map).await?;
+    let jira_config: crate::delivery::jira::JiraDeliveryConfig =
+        serde_json::from_value(serde_json::Value::Object(config_map))
+            .map_err(|e| AppError::NotConfigured(format!("Invalid jira delivery config: {}", e)))?;
+
+    let summary = crate::markdown::SummaryMarkdownInput {
+        date: &summary_date,
+        narrative: &narrative,
+        tickets_closed: &tickets_closed,
+        tickets_in_progress: &tickets_in_progress,
+        meetings: &[],
+        focus_hours: 0.0,
+        blockers: "",
+        tomorrow_priorities: "",
+        manual_notes: "",
+        log_entries: &[],
+        calendar_breakdown: None,
+    };
+
+    let time_entries =
+        crate::delivery::jira::load_time_entries(&summary_date, &jira_config).await?;
+
+    Ok(crate::delivery::jira::plan_actions(
+        summary,
+        &jira_config,
+        &time_entries,
+    ))
+}
+
+/// Summary row with its JSON columns parsed, for exports and per-target redaction
+#[derive(Clone)]
+struct StoredSummary {
+    date: String,
+    narrative: String,
+    tickets_closed: Vec<crate::aggregation::Ticket>,
+    tickets_in_progress: Vec<crate::aggregation::Ticket>,
+    meetings: Vec<crate::aggregation::Meeting>,
+    focus_hours: f32,
+    blockers: String,
+    tomorrow_priorities: String,
+    manual_notes: String,
+    log_entries: Vec<crate::aggregation::LogEntry>,
+    /// Sections of a structured narrative, if the day has one
+    narrative_structured: Option<StructuredNarrative>,
+    calendar_breakdown: crate::aggregation::meetings::CalendarBreakdown,
+}
+
+impl StoredSummary {
+    /// Apply redaction rules to every field a renderer shows. Meetings and
+    /// tickets go first so titles they hide are also masked in free text.
+    fn redact(
+        &mut self,
+        redactor: &crate::redaction::Redactor,
+    ) -> Vec<crate::redaction::Redaction> {
+        let mut report = Vec::new();
+        redactor.tickets(&mut self.tickets_closed, "ticketsClosed", &mut report);
+        redactor.tickets(
+            &mut self.tickets_in_progress,
+            "ticketsInProgress",
+            &mut report,
+        );
+        redactor.meetings(&mut self.meetings, &mut report);
+        redactor.log_entries(&mut self.log_entries, &mut report);
+        for (field, text) in [
+            ("narrative", &mut self.narrative),
+            ("blockers", &mut self.blockers),
+            ("tomorrowPriorities", &mut self.tomorrow_priorities),
+            ("manualNotes", &mut self.manual_notes),
+        ] {
+            *text = redactor.free_text(text, field, &mut report);
+        }
+        if let Some(structured) = &mut self.narrative_structured {
+            for (section, items) in [
+                ("accomplishments", &mut structured.accomplishments),
+                ("inProgress", &mut structured.in_progress),
+                ("collaboration", &mut structured.collaboration),
+                ("risks", &mut structured.risks),
+                ("tomorrow", &mut structured.tomorrow),
+            ] {
+                for (i, item) in items.iter_mut().enumerate() {
+                    let field = format!("narrativeStructured.{}[{}]", section, i);
+                    *item = redactor.free_text(item, &field, &mut report);
+                }
+            }
+        }
+        report
+    }
+
+    fn as_input(&self) -> crate::markdown::SummaryMarkdownInput<'_> {
+        crate::markdown::SummaryMarkdownInput {
+            date: &self.date,
+            narrative: &self.narrative,
+            tickets_closed: &self.tickets_closed,
+            tickets_in_progress: &self.tickets_in_progress,
+            meetings: &self.meetings,
+            focus_hours: self.focus_hours,
+            blockers: &self.blockers,
+            tomorrow_priorities: &self.tomorrow_priorities,
+            manual_notes: &self.manual_notes,
+            log_entries: &self.log_entries,
+            calendar_breakdown: Some(&self.calendar_breakdown),
+        }
+    }
+}
+
+async fn load_summaries_in_range(
+    db: &SqlitePool,
+    start_date: &str,
+    end_date: &str,
+) -> Result<Vec<StoredSummary>, AppError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT summary_date, tickets_closed, tickets_in_progress, meetings, focus_hours,
+               blockers, tomorrow_priorities, manual_notes, narrative, log_entries,
+               narrative_structured, calendar_breakdown
+        FROM daily_summaries
+        WHERE summary_date BETWEEN ?1 AND ?2
+        ORDER BY summary_date ASC
+        "#,
+    )
+    .bind(start_date)
+    .bind(end_date)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| StoredSummary {
+            date: row.get("summary_date"),
+            narrative: row.get("narrative"),
+            tickets_closed: serde_json::from_str(&row.get::<String, _>("tickets_closed"))
+                .unwrap_or_default(),
+            tickets_in_progress: serde_json::from_str(&row.get::<String, _>("tickets_in_progress"))
+                .unwrap_or_default(),
+            meetings: serde_json::from_str(&row.get::<String, _>("meetings")).unwrap_or_default(),
+            focus_hours: row.get("focus_hours"),
+            blockers: row.get("blockers"),
+            tomorrow_priorities: row.get("tomorrow_priorities"),
+            manual_notes: row.get("manual_notes"),
+            log_entries: serde_json::from_str(&row.get::<String, _>("log_entries"))
+                .unwrap_or_default(),
+            narrative_structured: row
+                .get::<Option<String>, _>("narrative_structured")
+                .and_then(|json| serde_json::from_str(&json).ok()),
+            calendar_breakdown: serde_json::from_str(&row.get::<String, _>("calendar_breakdown"))
+                .unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Export one day (or every saved day in a date range) as a PDF at `output_path`
+#[tauri::command]
+pub async fn export_summary_pdf(
+    db: State<'_, SqlitePool>,
+    start_date: String,
+    end_date: Option<String>,
+    output_path: String,
+) -> Result<String, AppError> {
+    let end_date = end_date.unwrap_or_else(|| start_date.clone());
+    for date in [&start_date, &end_date] {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| AppError::NotConfigured(format!("Invalid date: {}", date)))?;
+    }
+    if end_date < start_date {
+        return Err(AppError::NotConfigured(
+            "End date must not be before start date".to_string(),
+        ));
+    }
+
+    let stored = load_summaries_in_range(db.inner(), &start_date, &end_date).await?;
+    if stored.is_empty() {
+        return Err(AppError::NotConfigured(format!(
+            "No saved summaries between {} and {}",
+            start_date, end_date
+        )));
+    }
+
+    let inputs: Vec<_> = stored.iter().map(StoredSummary::as_input).collect();
+    let structured: Vec<_> = stored
+        .iter()
+        .map(|summary| summary.narrative_structured.clone())
+        .collect();
+    let bytes = crate::pdf::render_summaries_to_pdf(&inputs, &structured);
+
+    let path = std::path::Path::new(&output_path);
+    crate::delivery::file::write_atomic(path, bytes)?;
+
+    Ok(path.display().to_string())
+}
+
 #[tauri::command]
 pub async fn test_delivery(
     delivery_type: String,
     config: crate::delivery::DeliveryConfig,
 ) -> Result<String, AppError> {
-    let test_markdown = "# Test Summary\n\nThis is a test delivery from WorkdayDebrief.";
-    let test_date = "2026-02-14";
+    let test_summary = crate::markdown::SummaryMarkdownInput {
+        date: "2026-02-14",
+        narrative: "This is a test delivery from WorkdayDebrief.",
+        tickets_closed: &[],
+        tickets_in_progress: &[],
+        meetings: &[],
+        focus_hours: 0.0,
+        blockers: "",
+        tomorrow_priorities: "",
+        manual_notes: "",
+        log_entries: &[],
+        calendar_breakdown: None,
+    };
 
-    let confirmations = crate::delivery::send_summary(test_markdown, test_date, vec![config]).await;
+    let confirmations = crate::delivery::send_summary(test_summary, vec![config]).await;
 
     if let Some(confirmation) = confirmations.first() {
         if confirmation.success {
@@ -514,30 +1029,105 @@ pub async fn test_delivery(
 #[serde(rename_all = "camelCase")]
 pub struct Settings {
     pub scheduled_time: String, // "17:00"
-    pub default_tone: String,   // "professional", "casual", "detailed"
+    pub default_tone: String,   // built-in ("professional", ...) or custom tone name
     pub enable_llm: bool,
-    pub llm_model: String,       // "qwen3:14b"
-    pub llm_temperature: f32,    // 0.0-1.0
-    pub llm_timeout_secs: u64,   // 5-30
+    pub llm_model: String,     // "qwen3:14b"
+    pub llm_temperature: f32,  // 0.0-1.0
+    pub llm_timeout_secs: u64, // 5-30
+    #[serde(default)]
+    pub llm_output_format: crate::llm::structured::NarrativeFormat,
+    #[serde(default = "default_llm_num_ctx")]
+    pub llm_num_ctx: u32, // 2048-131072 tokens
+    /// Redaction profile applied to data sent to the LLM
+    #[serde(default)]
+    pub llm_redaction_profile_id: Option<i64>,
     pub calendar_source: String, // "google", "none"
     pub retention_days: i32,     // 7-365
     pub jira_base_url: Option<String>,
-    pub jira_project_key: Option<String>,
+    pub jira_project_key: Option<String>, // one or more keys, comma-separated
+    /// Custom JQL filter replacing `assignee = currentUser()`
+    #[serde(default)]
+    pub jira_jql: Option<String>,
+    #[serde(default)]
+    pub jira_auth_mode: crate::aggregation::jira::JiraAuthMode,
+    /// Extra CA certificate (PEM) for Jira servers behind a private CA
+    #[serde(default)]
+    pub jira_ca_cert_path: Option<String>,
     pub toggl_workspace_id: Option<String>,
+    /// Which tracked projects/clients/tags count as meetings or admin time
+    #[serde(default)]
+    pub time_categories: crate::aggregation::time::TimeCategories,
+    #[serde(default = "default_time_source")]
+    pub time_source: String, // "toggl", "clockify", "harvest", "native"
+    #[serde(default)]
+    pub clockify_workspace_id: Option<String>,
+    /// Overrides the public API, e.g. for a local stub
+    #[serde(default)]
+    pub clockify_base_url: Option<String>,
+    #[serde(default)]
+    pub harvest_account_id: Option<String>,
+    #[serde(default)]
+    pub harvest_base_url: Option<String>,
+    /// How calendar events are classified, and the working hours
+    #[serde(default)]
+    pub meeting_rules: crate::aggregation::meetings::MeetingRules,
+    /// IANA zone summary days are cut in; None means the computer's zone
+    #[serde(default)]
+    pub time_zone: Option<String>,
+    /// `HH:MM` a summary day starts, e.g. "04:00" to count late nights as the day before
+    #[serde(default = "default_day_start")]
+    pub day_start: String,
+}
+
+fn default_time_source() -> String {
+    "toggl".to_string()
+}
+
+fn default_llm_num_ctx() -> u32 {
+    4096
+}
+
+fn default_day_start() -> String {
+    "00:00".to_string()
+}
+
+/// Stored time categories; unreadable JSON falls back to "everything is focus"
+fn parse_time_categories(json: &str) -> crate::aggregation::time::TimeCategories {
+    serde_json::from_str(json).unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to parse time categories: {}", e);
+        Default::default()
+    })
+}
+
+/// Stored meeting rules; unreadable JSON falls back to the built-in classification
+fn parse_meeting_rules(json: &str) -> crate::aggregation::meetings::MeetingRules {
+    serde_json::from_str(json).unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to parse meeting rules: {}", e);
+        Default::default()
+    })
 }
 
 #[tauri::command]
 pub async fn get_settings(db: State<'_, SqlitePool>) -> Result<Settings, AppError> {
+    load_settings(db.inner()).await
+}
+
+/// Read the settings row; also used at startup to configure the scheduler
+pub async fn load_settings(db: &SqlitePool) -> Result<Settings, AppError> {
     let row = sqlx::query(
         r#"
         SELECT scheduled_time, default_tone, enable_llm, llm_model, llm_temperature,
-               llm_timeout_secs, calendar_source, retention_days, jira_base_url,
-               jira_project_key, toggl_workspace_id
+               llm_timeout_secs, llm_output_format, llm_num_ctx, llm_redaction_profile_id,
+               calendar_source, retention_days,
+               jira_base_url, jira_project_key, jira_jql, jira_auth_mode, jira_ca_cert_path,
+               toggl_workspace_id, time_categories, time_source, clockify_workspace_id,
+               clockify_base_url, harvest_account_id, harvest_base_url, meeting_rules,
+               time_zone, day_start
         FROM settings
         WHERE id = 1
         "#,
     )
-    .fetch_one(db.inner())
+    .fetch_one(db)
     .await?;
 
     Ok(Settings {
@@ -547,11 +1137,30 @@ pub async fn get_settings(db: State<'_, SqlitePool>) -> Result<Settings, AppErro
         llm_model: row.get("llm_model"),
         llm_temperature: row.get("llm_temperature"),
         llm_timeout_secs: row.get::<i32, _>("llm_timeout_secs") as u64,
+        llm_output_format: crate::llm::structured::NarrativeFormat::parse(
+            &row.get::<String, _>("llm_output_format"),
+        ),
+        llm_num_ctx: row.get::<i64, _>("llm_num_ctx") as u32,
+        llm_redaction_profile_id: row.get("llm_redaction_profile_id"),
         calendar_source: row.get("calendar_source"),
         retention_days: row.get("retention_days"),
         jira_base_url: row.get("jira_base_url"),
         jira_project_key: row.get("jira_project_key"),
+        jira_jql: row.get("jira_jql"),
+        jira_auth_mode: crate::aggregation::jira::JiraAuthMode::parse(
+            &row.get::<String, _>("jira_auth_mode"),
+        ),
+        jira_ca_cert_path: row.get("jira_ca_cert_path"),
         toggl_workspace_id: row.get("toggl_workspace_id"),
+        time_categories: parse_time_categories(&row.get::<String, _>("time_categories")),
+        time_source: row.get("time_source"),
+        clockify_workspace_id: row.get("clockify_workspace_id"),
+        clockify_base_url: row.get("clockify_base_url"),
+        harvest_account_id: row.get("harvest_account_id"),
+        harvest_base_url: row.get("harvest_base_url"),
+        meeting_rules: parse_meeting_rules(&row.get::<String, _>("meeting_rules")),
+        time_zone: row.get("time_zone"),
+        day_start: row.get("day_start"),
     })
 }
 
@@ -592,12 +1201,72 @@ pub async fn save_settings(
         ));
     }
 
+    if !(2048..=131072).contains(&settings.llm_num_ctx) {
+        return Err(AppError::NotConfigured(
+            "Context size must be 2048-131072 tokens".to_string(),
+        ));
+    }
+
+    if let Some(profile_id) = settings.llm_redaction_profile_id {
+        crate::redaction::load_redactor(db.inner(), profile_id).await?;
+    }
+
+    crate::aggregation::jira::JiraQuery::from_settings(
+        settings.jira_project_key.as_deref(),
+        settings.jira_jql.as_deref(),
+    )
+    .validate()?;
+
+    if let Some(path) = settings
+        .jira_ca_cert_path
+        .as_deref()
+        .filter(|p| !p.is_empty())
+    {
+        crate::aggregation::jira::load_ca_certificate(path)?;
+    }
+
+    if let Some(workspace_id) = &settings.toggl_workspace_id {
+        crate::aggregation::toggl::parse_workspace_id(workspace_id)?;
+    }
+
+    if !matches!(
+        settings.time_source.as_str(),
+        "toggl" | "clockify" | "harvest" | "native"
+    ) {
+        return Err(AppError::NotConfigured(format!(
+            "Unknown time source '{}'",
+            settings.time_source
+        )));
+    }
+    for url in [&settings.clockify_base_url, &settings.harvest_base_url]
+        .into_iter()
+        .flatten()
+        .filter(|url| !url.is_empty())
+    {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(AppError::NotConfigured(format!(
+                "Base URL must start with http:// or https://, got '{}'",
+                url
+            )));
+        }
+    }
+
+    settings.meeting_rules.validate()?;
+    crate::aggregation::day::DayBoundary::new(settings.time_zone.as_deref(), &settings.day_start)?;
+
     if settings.retention_days < 7 || settings.retention_days > 365 {
         return Err(AppError::NotConfigured(
             "Retention days must be 7-365".to_string(),
         ));
     }
 
+    if !crate::llm::tones::tone_exists(db.inner(), &settings.default_tone).await? {
+        return Err(AppError::NotConfigured(format!(
+            "Unknown tone '{}'",
+            settings.default_tone
+        )));
+    }
+
     // Update settings
     sqlx::query(
         r#"
@@ -613,6 +1282,21 @@ pub async fn save_settings(
             jira_base_url = ?9,
             jira_project_key = ?10,
             toggl_workspace_id = ?11,
+            llm_output_format = ?12,
+            llm_num_ctx = ?13,
+            llm_redaction_profile_id = ?14,
+            jira_jql = ?15,
+            jira_auth_mode = ?16,
+            jira_ca_cert_path = ?17,
+            time_categories = ?18,
+            time_source = ?19,
+            clockify_workspace_id = ?20,
+            clockify_base_url = ?21,
+            harvest_account_id = ?22,
+            harvest_base_url = ?23,
+            meeting_rules = ?24,
+            time_zone = ?25,
+            day_start = ?26,
             updated_at = datetime('now')
         WHERE id = 1
         "#,
@@ -628,6 +1312,35 @@ pub async fn save_settings(
     .bind(&settings.jira_base_url)
     .bind(&settings.jira_project_key)
     .bind(&settings.toggl_workspace_id)
+    .bind(settings.llm_output_format.as_str())
+    .bind(settings.llm_num_ctx as i64)
+    .bind(settings.llm_redaction_profile_id)
+    .bind(&settings.jira_jql)
+    .bind(settings.jira_auth_mode.as_str())
+    .bind(&settings.jira_ca_cert_path)
+    .bind(
+        serde_json::to_string(&settings.time_categories).map_err(|e| {
+            AppError::DatabaseError(format!("Cannot serialize time categories: {}", e))
+        })?,
+    )
+    .bind(&settings.time_source)
+    .bind(&settings.clockify_workspace_id)
+    .bind(&settings.clockify_base_url)
+    .bind(&settings.harvest_account_id)
+    .bind(&settings.harvest_base_url)
+    .bind(
+        serde_json::to_string(&settings.meeting_rules).map_err(|e| {
+            AppError::DatabaseError(format!("Cannot serialize meeting rules: {}", e))
+        })?,
+    )
+    .bind(
+        settings
+            .time_zone
+            .as_deref()
+            .map(str::trim)
+            .filter(|z| !z.is_empty()),
+    )
+    .bind(&settings.day_start)
     .execute(db.inner())
     .await?;
 
@@ -663,11 +1376,25 @@ pub async fn save_settings(
 
 // ── Delivery Config ──
 
+/// Config field that holds a delivery type's secret, and the vault key it is stored under
+fn delivery_secret_field(delivery_type: &str) -> Option<(&'static str, &'static str)> {
+    use crate::stronghold::keys;
+
+    match delivery_type {
+        "email" => Some(("password", keys::SMTP_PASSWORD)),
+        "slack" => Some(("webhookUrl", keys::SLACK_WEBHOOK_URL)),
+        "teams" => Some(("webhookUrl", keys::TEAMS_WEBHOOK_URL)),
+        "discord" => Some(("webhookUrl", keys::DISCORD_WEBHOOK_URL)),
+        "mattermost" => Some(("webhookUrl", keys::MATTERMOST_WEBHOOK_URL)),
+        _ => None,
+    }
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeliveryConfigRow {
     pub id: i64,
-    pub delivery_type: String,     // "email", "slack", "file"
+    pub delivery_type: String,     // see delivery::DELIVERY_TYPES
     pub config: serde_json::Value, // JSON blob with type-specific config
     pub is_enabled: bool,
 }
@@ -698,27 +1425,18 @@ pub async fn get_delivery_configs(
 
         // Add secrets from vault (masked for display)
         if let Some(obj) = config.as_object_mut() {
-            if delivery_type == "email" {
-                // Check if password exists in vault
-                if crate::stronghold::get_secret(&app, crate::stronghold::keys::SMTP_PASSWORD)?
-                    .is_some()
-                {
+            if let Some((field, key)) = delivery_secret_field(&delivery_type) {
+                if crate::stronghold::get_secret(&app, key)?.is_some() {
                     obj.insert(
-                        "password".to_string(),
+                        field.to_string(),
                         serde_json::Value::String("••••••".to_string()),
                     );
                 }
-            }
-
-            if delivery_type == "slack" {
-                // Check if webhook exists in vault
-                if crate::stronghold::get_secret(&app, crate::stronghold::keys::SLACK_WEBHOOK_URL)?
-                    .is_some()
+            } else if delivery_type == "webhook" {
+                if let Some(stored) =
+                    crate::stronghold::get_secret(&app, crate::stronghold::keys::WEBHOOK_SECRETS)?
                 {
-                    obj.insert(
-                        "webhookUrl".to_string(),
-                        serde_json::Value::String("••••••".to_string()),
-                    );
+                    crate::delivery::webhook::mask_secrets(obj, &stored);
                 }
             }
         }
@@ -749,44 +1467,39 @@ pub async fn save_delivery_config(
     app: AppHandle,
 ) -> Result<(), AppError> {
     // Validate delivery_type
-    if !["email", "slack", "file"].contains(&input.delivery_type.as_str()) {
+    if !crate::delivery::DELIVERY_TYPES.contains(&input.delivery_type.as_str()) {
         return Err(AppError::NotConfigured("Invalid delivery type".to_string()));
     }
 
+    if let Some(renderer) = input.config.get("renderer") {
+        let renderer: crate::render::RendererKind = serde_json::from_value(renderer.clone())
+            .map_err(|_| AppError::NotConfigured(format!("Unknown renderer: {}", renderer)))?;
+        crate::delivery::check_renderer(&input.delivery_type, renderer)?;
+    }
+
     // Extract secrets and store in encrypted vault
     let mut final_config = input.config.clone();
 
     if let Some(obj) = final_config.as_object_mut() {
-        // Extract and store SMTP password
-        if input.delivery_type == "email" {
-            if let Some(password) = obj.get("password").and_then(|v| v.as_str()) {
-                // If not masked, store in vault
-                if password != "••••••" {
-                    crate::stronghold::store_secret(
-                        &app,
-                        crate::stronghold::keys::SMTP_PASSWORD,
-                        password,
-                    )?;
-                }
-                // Remove from config JSON
-                obj.remove("password");
-            }
-        }
-
-        // Extract and store Slack webhook URL
-        if input.delivery_type == "slack" {
-            if let Some(webhook) = obj.get("webhookUrl").and_then(|v| v.as_str()) {
+        if let Some((field, key)) = delivery_secret_field(&input.delivery_type) {
+            if let Some(secret) = obj.get(field).and_then(|v| v.as_str()) {
                 // If not masked, store in vault
-                if webhook != "••••••" {
-                    crate::stronghold::store_secret(
-                        &app,
-                        crate::stronghold::keys::SLACK_WEBHOOK_URL,
-                        webhook,
-                    )?;
+                if secret != "••••••" {
+                    crate::stronghold::store_secret(&app, key, secret)?;
                 }
                 // Remove from config JSON
-                obj.remove("webhookUrl");
+                obj.remove(field);
             }
+        } else if input.delivery_type == "webhook" {
+            // Signing secret and secret header values
+            let stored =
+                crate::stronghold::get_secret(&app, crate::stronghold::keys::WEBHOOK_SECRETS)?;
+            let secrets = crate::delivery::webhook::extract_secrets(obj, stored.as_deref());
+            crate::stronghold::store_secret(
+                &app,
+                crate::stronghold::keys::WEBHOOK_SECRETS,
+                &secrets,
+            )?;
         }
     }
 
@@ -812,6 +1525,390 @@ pub async fn save_delivery_config(
     Ok(())
 }
 
+// ── Templates ──
+
+#[tauri::command]
+pub async fn list_summary_templates(
+    db: State<'_, SqlitePool>,
+) -> Result<Vec<crate::templates::SummaryTemplate>, AppError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT id, name, body, updated_at
+        FROM summary_templates
+        ORDER BY name
+        "#,
+    )
+    .fetch_all(db.inner())
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| crate::templates::SummaryTemplate {
+            id: row.get("id"),
+            name: row.get("name"),
+            body: row.get("body"),
+            updated_at: row.get("updated_at"),
+        })
+        .collect())
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveSummaryTemplateInput {
+    pub id: Option<i64>,
+    pub name: String,
+    pub body: String,
+}
+
+/// Create or update a template; the body is validated before it is stored
+#[tauri::command]
+pub async fn save_summary_template(
+    db: State<'_, SqlitePool>,
+    input: SaveSummaryTemplateInput,
+) -> Result<i64, AppError> {
+    let name = input.name.trim();
+    if name.is_empty() {
+        return Err(AppError::NotConfigured(
+            "Template name is required".to_string(),
+        ));
+    }
+    crate::templates::validate_template(&input.body)?;
+
+    let duplicate: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM summary_templates WHERE name = ?1 AND id IS NOT ?2")
+            .bind(name)
+            .bind(input.id)
+            .fetch_optional(db.inner())
+            .await?;
+    if duplicate.is_some() {
+        return Err(AppError::NotConfigured(format!(
+            "A template named '{}' already exists",
+            name
+        )));
+    }
+
+    match input.id {
+        Some(id) => {
+            let result =
+                sqlx::query("UPDATE summary_templates SET name = ?1, body = ?2 WHERE id = ?3")
+                    .bind(name)
+                    .bind(&input.body)
+                    .bind(id)
+                    .execute(db.inner())
+                    .await?;
+            if result.rows_affected() == 0 {
+                return Err(AppError::DatabaseError(format!(
+                    "Template {} not found",
+                    id
+                )));
+            }
+            Ok(id)
+        }
+        None => {
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO summary_templates (name, body) VALUES (?1, ?2) RETURNING id",
+            )
+            .bind(name)
+            .bind(&input.body)
+            .fetch_one(db.inner())
+            .await?;
+            Ok(id)
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn delete_summary_template(db: State<'_, SqlitePool>, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM summary_templates WHERE id = ?1")
+        .bind(id)
+        .execute(db.inner())
+        .await?;
+    Ok(())
+}
+
+/// Render an unsaved template body against the saved summary for `date`
+/// (today when omitted), reporting errors with their template line
+#[tauri::command]
+pub async fn preview_summary_template(
+    db: State<'_, SqlitePool>,
+    body: String,
+    date: Option<String>,
+) -> Result<String, AppError> {
+    crate::templates::validate_template(&body)?;
+
+    let date = match date {
+        Some(date) => date,
+        None => load_day_boundary(db.inner())
+            .await?
+            .today()?
+            .date
+            .to_string(),
+    };
+    let stored = load_summaries_in_range(db.inner(), &date, &date).await?;
+    let summary = stored.first().ok_or_else(|| {
+        AppError::NotConfigured(format!("No saved summary for {} to preview with", date))
+    })?;
+
+    crate::templates::render_template(&body, summary.as_input())
+}
+
+// ── Tones ──
+
+#[tauri::command]
+pub async fn list_tones(
+    db: State<'_, SqlitePool>,
+) -> Result<Vec<crate::llm::tones::ToneTemplate>, AppError> {
+    crate::llm::tones::list_tones(db.inner()).await
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveToneInput {
+    pub id: Option<i64>,
+    pub name: String,
+    pub template: String,
+}
+
+/// Create or update a custom tone. Renaming the default tone keeps it the default.
+#[tauri::command]
+pub async fn save_tone(db: State<'_, SqlitePool>, input: SaveToneInput) -> Result<i64, AppError> {
+    use crate::llm::tones;
+
+    let name = input.name.trim();
+    tones::validate_name(name)?;
+    tones::validate_template(&input.template)?;
+
+    let duplicate: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM custom_tones WHERE name = ?1 AND id IS NOT ?2")
+            .bind(name)
+            .bind(input.id)
+            .fetch_optional(db.inner())
+            .await?;
+    if duplicate.is_some() {
+        return Err(AppError::NotConfigured(format!(
+            "A tone named '{}' already exists",
+            name
+        )));
+    }
+
+    match input.id {
+        Some(id) => {
+            let old_name: Option<String> =
+                sqlx::query_scalar("SELECT name FROM custom_tones WHERE id = ?1")
+                    .bind(id)
+                    .fetch_optional(db.inner())
+                    .await?;
+            let old_name = old_name
+                .ok_or_else(|| AppError::DatabaseError(format!("Tone {} not found", id)))?;
+
+            sqlx::query("UPDATE custom_tones SET name = ?1, template = ?2 WHERE id = ?3")
+                .bind(name)
+                .bind(&input.template)
+                .bind(id)
+                .execute(db.inner())
+                .await?;
+
+            if old_name != name {
+                sqlx::query(
+                    "UPDATE settings SET default_tone = ?1 WHERE id = 1 AND default_tone = ?2",
+                )
+                .bind(name)
+                .bind(&old_name)
+                .execute(db.inner())
+                .await?;
+            }
+            Ok(id)
+        }
+        None => {
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO custom_tones (name, template) VALUES (?1, ?2) RETURNING id",
+            )
+            .bind(name)
+            .bind(&input.template)
+            .fetch_one(db.inner())
+            .await?;
+            Ok(id)
+        }
+    }
+}
+
+/// Copy a built-in or custom tone into a new editable custom tone
+#[tauri::command]
+pub async fn clone_tone(
+    db: State<'_, SqlitePool>,
+    source: String,
+    name: String,
+) -> Result<i64, AppError> {
+    let template = crate::llm::tones::resolve_template(db.inner(), &source).await?;
+    save_tone(
+        db,
+        SaveToneInput {
+            id: None,
+            name,
+            template,
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn delete_tone(db: State<'_, SqlitePool>, id: i64) -> Result<(), AppError> {
+    let in_use: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT t.name FROM custom_tones t
+        JOIN settings s ON s.id = 1 AND s.default_tone = t.name
+        WHERE t.id = ?1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(db.inner())
+    .await?;
+    if let Some(name) = in_use {
+        return Err(AppError::NotConfigured(format!(
+            "'{}' is the default tone. Choose another default before deleting it.",
+            name
+        )));
+    }
+
+    sqlx::query("DELETE FROM custom_tones WHERE id = ?1")
+        .bind(id)
+        .execute(db.inner())
+        .await?;
+    Ok(())
+}
+
+// ── Redaction ──
+
+#[tauri::command]
+pub async fn list_redaction_profiles(
+    db: State<'_, SqlitePool>,
+) -> Result<Vec<crate::redaction::RedactionProfile>, AppError> {
+    crate::redaction::list_profiles(db.inner()).await
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveRedactionProfileInput {
+    pub id: Option<i64>,
+    pub name: String,
+    pub rules: crate::redaction::RedactionRules,
+}
+
+/// Create or update a redaction profile; patterns are compiled to validate them
+#[tauri::command]
+pub async fn save_redaction_profile(
+    db: State<'_, SqlitePool>,
+    input: SaveRedactionProfileInput,
+) -> Result<i64, AppError> {
+    let name = input.name.trim();
+    if name.is_empty() || name.len() > 60 {
+        return Err(AppError::NotConfigured(
+            "Profile name must be 1-60 characters".to_string(),
+        ));
+    }
+    crate::redaction::Redactor::new(&input.rules)?;
+    let rules = serde_json::to_string(&input.rules)
+        .map_err(|e| AppError::DatabaseError(format!("Failed to serialize rules: {}", e)))?;
+
+    let duplicate: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM redaction_profiles WHERE name = ?1 AND id IS NOT ?2")
+            .bind(name)
+            .bind(input.id)
+            .fetch_optional(db.inner())
+            .await?;
+    if duplicate.is_some() {
+        return Err(AppError::NotConfigured(format!(
+            "A redaction profile named '{}' already exists",
+            name
+        )));
+    }
+
+    match input.id {
+        Some(id) => {
+            let result =
+                sqlx::query("UPDATE redaction_profiles SET name = ?1, rules = ?2 WHERE id = ?3")
+                    .bind(name)
+                    .bind(&rules)
+                    .bind(id)
+                    .execute(db.inner())
+                    .await?;
+            if result.rows_affected() == 0 {
+                return Err(AppError::DatabaseError(format!(
+                    "Redaction profile {} not found",
+                    id
+                )));
+            }
+            Ok(id)
+        }
+        None => {
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO redaction_profiles (name, rules) VALUES (?1, ?2) RETURNING id",
+            )
+            .bind(name)
+            .bind(&rules)
+            .fetch_one(db.inner())
+            .await?;
+            Ok(id)
+        }
+    }
+}
+
+/// Delete a profile that is not used by the LLM setting or any delivery target
+#[tauri::command]
+pub async fn delete_redaction_profile(db: State<'_, SqlitePool>, id: i64) -> Result<(), AppError> {
+    let used_by: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT 'LLM prompt' FROM settings WHERE id = 1 AND llm_redaction_profile_id = ?1
+        UNION ALL
+        SELECT delivery_type FROM delivery_configs
+        WHERE json_extract(config, '$.redactionProfileId') = ?1
+        "#,
+    )
+    .bind(id)
+    .fetch_all(db.inner())
+    .await?;
+    if !used_by.is_empty() {
+        return Err(AppError::NotConfigured(format!(
+            "Profile is used by {}. Pick another profile there first.",
+            used_by.join(", ")
+        )));
+    }
+
+    sqlx::query("DELETE FROM redaction_profiles WHERE id = ?1")
+        .bind(id)
+        .execute(db.inner())
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionPreview {
+    /// The summary as a target using the rules would render it (markdown)
+    pub markdown: String,
+    pub redactions: Vec<crate::redaction::Redaction>,
+}
+
+/// Show what a profile (or unsaved rules being edited) would mask in a summary
+#[tauri::command]
+pub async fn preview_redaction(
+    db: State<'_, SqlitePool>,
+    date: String,
+    rules: crate::redaction::RedactionRules,
+) -> Result<RedactionPreview, AppError> {
+    let redactor = crate::redaction::Redactor::new(&rules)?;
+    let mut summary = load_summaries_in_range(db.inner(), &date, &date)
+        .await?
+        .pop()
+        .ok_or_else(|| AppError::DatabaseError(format!("No summary for {}", date)))?;
+
+    let redactions = summary.redact(&redactor);
+    Ok(RedactionPreview {
+        markdown: crate::markdown::render_summary_to_markdown(summary.as_input()),
+        redactions,
+    })
+}
+
 // ── Stronghold (Secret Storage) ──
 
 fn validate_secret_key(key: &str) -> Result<(), AppError> {
@@ -819,10 +1916,16 @@ fn validate_secret_key(key: &str) -> Result<(), AppError> {
         key,
         crate::stronghold::keys::SMTP_PASSWORD
             | crate::stronghold::keys::SLACK_WEBHOOK_URL
+            | crate::stronghold::keys::TEAMS_WEBHOOK_URL
+            | crate::stronghold::keys::DISCORD_WEBHOOK_URL
+            | crate::stronghold::keys::MATTERMOST_WEBHOOK_URL
+            | crate::stronghold::keys::WEBHOOK_SECRETS
             | crate::stronghold::keys::JIRA_API_TOKEN
             | crate::stronghold::keys::JIRA_EMAIL
             | crate::stronghold::keys::GOOGLE_REFRESH_TOKEN
             | crate::stronghold::keys::TOGGL_API_TOKEN
+            | crate::stronghold::keys::CLOCKIFY_API_KEY
+            | crate::stronghold::keys::HARVEST_ACCESS_TOKEN
             | crate::stronghold::keys::OAUTH_CSRF_TOKEN
             | crate::stronghold::keys::OAUTH_PKCE_VERIFIER
     );
@@ -858,19 +1961,49 @@ pub fn delete_secret(app: AppHandle, key: String) -> Result<(), AppError> {
 // ── Connection Testing ──
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn test_jira_connection(
+    db: State<'_, SqlitePool>,
     _app: AppHandle,
     base_url: String,
     email: String,
     api_token: String,
     project_key: String,
+    jql: Option<String>,
+    auth_mode: Option<crate::aggregation::jira::JiraAuthMode>,
+    ca_cert_path: Option<String>,
 ) -> Result<String, AppError> {
+    use crate::aggregation::jira::{self, JiraAuthMode, JiraConnection, JiraQuery};
+
+    let connection = JiraConnection {
+        base_url,
+        auth_mode: auth_mode.unwrap_or_default(),
+        email,
+        api_token,
+        ca_cert_path,
+    };
+
+    // Detect the deployment first so a wrong auth mode gets a clear message
+    let server = jira::server_info(&connection).await?;
+    let expected = server.expected_auth_mode();
+    if expected != connection.auth_mode {
+        return Err(AppError::NotConfigured(format!(
+            "This is {}; switch the authentication mode to {}",
+            server.describe(),
+            match expected {
+                JiraAuthMode::Cloud => "Cloud (email + API token)",
+                JiraAuthMode::Datacenter => "Data Center (personal access token)",
+            }
+        )));
+    }
+
     // Test by attempting to fetch tickets
-    match crate::aggregation::jira::fetch_tickets_today(&base_url, &email, &api_token, &project_key)
-        .await
-    {
+    let query = JiraQuery::from_settings(Some(&project_key), jql.as_deref());
+    let day = load_day_boundary(db.inner()).await?.today()?;
+    match jira::fetch_tickets_today(&connection, &query, &day).await {
         Ok((closed, in_progress)) => Ok(format!(
-            "Connected successfully! Found {} closed and {} in-progress tickets today.",
+            "Connected to {}! Found {} closed and {} in-progress tickets today.",
+            server.describe(),
             closed.len(),
             in_progress.len()
         )),
@@ -880,20 +2013,248 @@ pub async fn test_jira_connection(
 
 #[tauri::command]
 pub async fn test_toggl_connection(
+    db: State<'_, SqlitePool>,
     _app: AppHandle,
     api_token: String,
     workspace_id: String,
 ) -> Result<String, AppError> {
-    // Test by attempting to fetch focus hours
-    match crate::aggregation::toggl::fetch_focus_hours_today(&api_token, &workspace_id).await {
-        Ok(hours) => Ok(format!(
-            "Connected successfully! Tracked {:.1} hours today.",
-            hours
+    test_time_source(
+        db.inner(),
+        crate::aggregation::time::TimeSource::Toggl {
+            api_token,
+            workspace_id,
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn test_clockify_connection(
+    db: State<'_, SqlitePool>,
+    _app: AppHandle,
+    api_key: String,
+    workspace_id: String,
+    base_url: Option<String>,
+) -> Result<String, AppError> {
+    test_time_source(
+        db.inner(),
+        crate::aggregation::time::TimeSource::Clockify {
+            base_url: base_url
+                .filter(|url| !url.is_empty())
+                .unwrap_or_else(|| crate::aggregation::clockify::DEFAULT_BASE_URL.to_string()),
+            api_key,
+            workspace_id,
+        },
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn test_harvest_connection(
+    db: State<'_, SqlitePool>,
+    _app: AppHandle,
+    access_token: String,
+    account_id: String,
+    base_url: Option<String>,
+) -> Result<String, AppError> {
+    test_time_source(
+        db.inner(),
+        crate::aggregation::time::TimeSource::Harvest {
+            base_url: base_url
+                .filter(|url| !url.is_empty())
+                .unwrap_or_else(|| crate::aggregation::harvest::DEFAULT_BASE_URL.to_string()),
+            access_token,
+            account_id,
+        },
+    )
+    .await
+}
+
+/// Test by attempting to fetch today's entries
+async fn test_time_source(
+    db: &SqlitePool,
+    source: crate::aggregation::time::TimeSource,
+) -> Result<String, AppError> {
+    let day = load_day_boundary(db).await?.today()?;
+    let entries = source.fetch_entries(&day).await?;
+    let seconds: i64 = entries.iter().map(|e| e.duration_seconds).sum();
+    Ok(format!(
+        "Connected to {}! Found {} entries ({:.1} hours) today.",
+        source.name(),
+        entries.len(),
+        seconds as f32 / 3600.0
+    ))
+}
+
+// ── Timer ──
+
+/// The running timer entry, if any
+#[tauri::command]
+pub async fn get_running_timer(
+    db: State<'_, SqlitePool>,
+) -> Result<Option<crate::timer::TimerEntry>, AppError> {
+    crate::timer::running(db.inner()).await
+}
+
+/// Start tracking `task`; a running timer is stopped first, so this also
+/// switches tasks
+#[tauri::command]
+pub async fn start_timer(
+    db: State<'_, SqlitePool>,
+    task: crate::timer::TimerTask,
+) -> Result<crate::timer::TimerEntry, AppError> {
+    crate::timer::start(db.inner(), task, chrono::Utc::now()).await
+}
+
+#[tauri::command]
+pub async fn stop_timer(
+    db: State<'_, SqlitePool>,
+) -> Result<Option<crate::timer::TimerEntry>, AppError> {
+    crate::timer::stop(db.inner(), chrono::Utc::now()).await
+}
+
+/// Called by idle detection once the user is back: drop the time since
+/// `idle_since` from the running entry and optionally keep tracking
+#[tauri::command]
+pub async fn discard_idle_time(
+    db: State<'_, SqlitePool>,
+    idle_since: String,
+    resume: bool,
+) -> Result<Option<crate::timer::TimerEntry>, AppError> {
+    let idle_since = crate::timer::parse_input_timestamp(&idle_since)?;
+    crate::timer::discard_idle(db.inner(), idle_since, resume).await
+}
+
+/// Timer entries overlapping a summary day (YYYY-MM-DD)
+#[tauri::command]
+pub async fn list_time_entries(
+    db: State<'_, SqlitePool>,
+    date: String,
+) -> Result<Vec<crate::timer::TimerEntry>, AppError> {
+    let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| AppError::NotConfigured(format!("Invalid date '{}': {}", date, e)))?;
+    let day = load_day_boundary(db.inner()).await?.day(date)?;
+    crate::timer::list_for_day(db.inner(), &day).await
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveTimeEntryInput {
+    pub id: Option<i64>,
+    #[serde(flatten)]
+    pub task: crate::timer::TimerTask,
+    pub started_at: String,
+    /// Required for new entries; None keeps a running entry running
+    pub stopped_at: Option<String>,
+}
+
+/// Add an entry by hand or edit an existing one
+#[tauri::command]
+pub async fn save_time_entry(
+    db: State<'_, SqlitePool>,
+    input: SaveTimeEntryInput,
+) -> Result<crate::timer::TimerEntry, AppError> {
+    let started_at = crate::timer::parse_input_timestamp(&input.started_at)?;
+    let stopped_at = input
+        .stopped_at
+        .as_deref()
+        .map(crate::timer::parse_input_timestamp)
+        .transpose()?;
+
+    match (input.id, stopped_at) {
+        (Some(id), stopped_at) => {
+            crate::timer::update(db.inner(), id, input.task, started_at, stopped_at).await
+        }
+        (None, Some(stopped_at)) => {
+            crate::timer::create(db.inner(), input.task, started_at, stopped_at).await
+        }
+        (None, None) => Err(AppError::NotConfigured(
+            "A manual entry needs a stop time; use the timer for running entries".to_string(),
         )),
-        Err(e) => Err(e),
     }
 }
 
+#[tauri::command]
+pub async fn delete_time_entry(db: State<'_, SqlitePool>, id: i64) -> Result<(), AppError> {
+    crate::timer::delete(db.inner(), id).await
+}
+
+// ── Activity Log ──
+
+/// Quick capture: store a timestamped note. The ticket key is taken from the
+/// text when not given; `#words` become tags.
+#[tauri::command]
+pub async fn capture_note(
+    db: State<'_, SqlitePool>,
+    text: String,
+    ticket_key: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<crate::aggregation::LogEntry, AppError> {
+    crate::capture::capture(
+        db.inner(),
+        &text,
+        ticket_key,
+        tags.unwrap_or_default(),
+        chrono::Utc::now(),
+    )
+    .await
+}
+
+/// Notes captured on a summary day (YYYY-MM-DD)
+#[tauri::command]
+pub async fn list_log_entries(
+    db: State<'_, SqlitePool>,
+    date: String,
+) -> Result<Vec<crate::aggregation::LogEntry>, AppError> {
+    let date = NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map_err(|e| AppError::NotConfigured(format!("Invalid date '{}': {}", date, e)))?;
+    let day = load_day_boundary(db.inner()).await?.day(date)?;
+    crate::capture::list_for_day(db.inner(), &day).await
+}
+
+#[tauri::command]
+pub async fn delete_log_entry(db: State<'_, SqlitePool>, id: i64) -> Result<(), AppError> {
+    crate::capture::delete(db.inner(), id).await
+}
+
+// ── Google Accounts ──
+
+#[tauri::command]
+pub async fn list_google_accounts(
+    db: State<'_, SqlitePool>,
+    app: AppHandle,
+) -> Result<Vec<crate::google_accounts::GoogleAccount>, AppError> {
+    crate::google_accounts::list(db.inner(), &app).await
+}
+
+/// Calendars the account can see, for choosing which count toward meetings
+#[tauri::command]
+pub async fn list_google_calendars(
+    app: AppHandle,
+    email: String,
+) -> Result<Vec<crate::aggregation::calendar::CalendarListEntry>, AppError> {
+    let access_token = crate::google_accounts::access_token(&app, &email).await?;
+    crate::aggregation::calendar::fetch_calendar_list(&access_token).await
+}
+
+#[tauri::command]
+pub async fn set_google_calendars(
+    db: State<'_, SqlitePool>,
+    email: String,
+    calendar_ids: Vec<String>,
+) -> Result<(), AppError> {
+    crate::google_accounts::set_calendars(db.inner(), &email, &calendar_ids).await
+}
+
+#[tauri::command]
+pub async fn disconnect_google_account(
+    db: State<'_, SqlitePool>,
+    app: AppHandle,
+    email: String,
+) -> Result<(), AppError> {
+    crate::google_accounts::remove(db.inner(), &app, &email).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -908,10 +2269,25 @@ mod tests {
             llm_model: "qwen3:14b".to_string(),
             llm_temperature: 0.7,
             llm_timeout_secs: 15,
+            llm_output_format: crate::llm::structured::NarrativeFormat::Prose,
+            llm_num_ctx: 4096,
+            llm_redaction_profile_id: None,
             calendar_source: "none".to_string(),
             retention_days: 90,
             jira_base_url: Some("https://example.atlassian.net".to_string()),
             jira_project_key: Some("PROJ".to_string()),
+            jira_jql: None,
+            jira_auth_mode: crate::aggregation::jira::JiraAuthMode::Cloud,
+            jira_ca_cert_path: None,
+            time_categories: Default::default(),
+            time_source: "toggl".to_string(),
+            clockify_workspace_id: None,
+            clockify_base_url: None,
+            harvest_account_id: None,
+            harvest_base_url: None,
+            meeting_rules: Default::default(),
+            time_zone: None,
+            day_start: "00:00".to_string(),
             toggl_workspace_id: Some("1234".to_string()),
         };
 
@@ -968,6 +2344,20 @@ mod tests {
         assert!(value.get("delivery_type").is_none());
     }
 
+    #[test]
+    fn delivery_secret_fields_map_to_allowed_keys() {
+        for delivery_type in crate::delivery::DELIVERY_TYPES {
+            if let Some((_, key)) = delivery_secret_field(delivery_type) {
+                assert!(validate_secret_key(key).is_ok(), "{} key rejected", key);
+            }
+        }
+        assert_eq!(
+            delivery_secret_field("teams"),
+            Some(("webhookUrl", crate::stronghold::keys::TEAMS_WEBHOOK_URL))
+        );
+        assert!(delivery_secret_field("file").is_none());
+    }
+
     #[test]
     fn secret_key_policy_allows_known_keys() {
         assert!(validate_secret_key(crate::stronghold::keys::JIRA_API_TOKEN).is_ok());
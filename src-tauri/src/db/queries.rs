@@ -26,6 +26,15 @@ pub async fn upsert_summary(
             tomorrow_priorities = COALESCE(?3, tomorrow_priorities),
             manual_notes = COALESCE(?4, manual_notes),
             narrative = COALESCE(?5, narrative),
+            -- A hand-edited narrative invalidates the fact-check warnings and sections
+            narrative_warnings = CASE
+                WHEN ?5 IS NOT NULL AND ?5 IS NOT narrative THEN '[]'
+                ELSE narrative_warnings
+            END,
+            narrative_structured = CASE
+                WHEN ?5 IS NOT NULL AND ?5 IS NOT narrative THEN NULL
+                ELSE narrative_structured
+            END,
             tone = COALESCE(?6, tone),
             updated_at = datetime('now')
         RETURNING id
@@ -58,10 +67,17 @@ pub async fn get_summary_by_date(
             tickets_in_progress,
             meetings,
             focus_hours,
+            time_breakdown,
+            log_entries,
+            calendar_breakdown,
+            time_zone,
+            day_start,
             blockers,
             tomorrow_priorities,
             manual_notes,
             narrative,
+            narrative_warnings,
+            narrative_structured,
             tone,
             delivered_to,
             sources_status,
@@ -83,10 +99,17 @@ pub async fn get_summary_by_date(
             let tickets_in_progress: String = r.get("tickets_in_progress");
             let meetings: String = r.get("meetings");
             let focus_hours: f64 = r.get("focus_hours");
+            let time_breakdown: String = r.get("time_breakdown");
+            let log_entries: String = r.get("log_entries");
+            let calendar_breakdown: String = r.get("calendar_breakdown");
+            let time_zone: String = r.get("time_zone");
+            let day_start: String = r.get("day_start");
             let blockers: String = r.get("blockers");
             let tomorrow_priorities: String = r.get("tomorrow_priorities");
             let manual_notes: String = r.get("manual_notes");
             let narrative: String = r.get("narrative");
+            let narrative_warnings: String = r.get("narrative_warnings");
+            let narrative_structured: Option<String> = r.get("narrative_structured");
             let tone: String = r.get("tone");
             let delivered_to: String = r.get("delivered_to");
             let sources_status: String = r.get("sources_status");
@@ -100,10 +123,17 @@ pub async fn get_summary_by_date(
                 "ticketsInProgress": serde_json::from_str::<serde_json::Value>(&tickets_in_progress).unwrap_or(serde_json::json!([])),
                 "meetings": serde_json::from_str::<serde_json::Value>(&meetings).unwrap_or(serde_json::json!([])),
                 "focusHours": focus_hours,
+                "timeBreakdown": serde_json::from_str::<serde_json::Value>(&time_breakdown).unwrap_or(serde_json::json!({})),
+                "logEntries": serde_json::from_str::<serde_json::Value>(&log_entries).unwrap_or(serde_json::json!([])),
+                "calendarBreakdown": serde_json::from_str::<serde_json::Value>(&calendar_breakdown).unwrap_or(serde_json::json!({})),
+                "timeZone": time_zone,
+                "dayStart": day_start,
                 "blockers": blockers,
                 "tomorrowPriorities": tomorrow_priorities,
                 "manualNotes": manual_notes,
                 "narrative": narrative,
+                "narrativeWarnings": serde_json::from_str::<serde_json::Value>(&narrative_warnings).unwrap_or(serde_json::json!([])),
+                "narrativeStructured": narrative_structured.and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok()),
                 "tone": tone,
                 "deliveredTo": serde_json::from_str::<serde_json::Value>(&delivered_to).unwrap_or(serde_json::json!([])),
                 "sourcesStatus": serde_json::from_str::<serde_json::Value>(&sources_status).unwrap_or(serde_json::json!({})),
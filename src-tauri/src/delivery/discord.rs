@@ -0,0 +1,249 @@
+use crate::error::AppError;
+use crate::render::RendererKind;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordConfig {
+    pub webhook_url: String,
+    pub username: Option<String>,
+    #[serde(default)]
+    pub renderer: RendererKind,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct DiscordMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    embeds: Vec<DiscordEmbed>,
+}
+
+#[derive(Debug, Serialize)]
+struct DiscordEmbed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    description: String,
+    color: u32,
+}
+
+/// Discord message limit; embed descriptions allow more but we keep every
+/// chunk readable as a plain message too
+const MAX_MESSAGE_LEN: usize = 2000;
+const EMBED_COLOR: u32 = 0x5865F2;
+
+/// A failed post, with the wait Discord asked for when it rate-limited us
+struct PostFailure {
+    error: AppError,
+    retry_after_secs: Option<u64>,
+}
+
+impl From<AppError> for PostFailure {
+    fn from(error: AppError) -> Self {
+        PostFailure {
+            error,
+            retry_after_secs: None,
+        }
+    }
+}
+
+/// Split text into chunks of at most `limit` chars, preferring line breaks
+pub fn split_message(text: &str, limit: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in text.lines() {
+        let needed = if current.is_empty() {
+            line.chars().count()
+        } else {
+            current.chars().count() + 1 + line.chars().count()
+        };
+
+        if needed <= limit {
+            if !current.is_empty() {
+                current.push('\n');
+            }
+            current.push_str(line);
+            continue;
+        }
+
+        if !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+        }
+
+        // Hard-wrap lines that are longer than the limit on their own
+        let chars: Vec<char> = line.chars().collect();
+        let mut pieces = chars.chunks(limit).peekable();
+        while let Some(piece) = pieces.next() {
+            let piece: String = piece.iter().collect();
+            if pieces.peek().is_some() {
+                chunks.push(piece);
+            } else {
+                current = piece;
+            }
+        }
+    }
+
+    if !current.trim().is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// Send summary to a Discord webhook, one embed per 2000-char chunk. Each
+/// chunk is retried on its own so a failure never reposts earlier chunks.
+pub async fn send_discord(summary_markdown: &str, config: &DiscordConfig) -> Result<(), AppError> {
+    // Lift the top-level heading into the first embed's title
+    let (title, body) = match summary_markdown.split_once('\n') {
+        Some((first, rest)) if first.starts_with("# ") => {
+            (Some(first.trim_start_matches("# ").to_string()), rest)
+        }
+        _ => (None, summary_markdown),
+    };
+
+    let chunks = split_message(body.trim(), MAX_MESSAGE_LEN);
+    if chunks.is_empty() {
+        return Err(AppError::DiscordWebhookInvalid(
+            "Nothing to send".to_string(),
+        ));
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| AppError::DiscordWebhookInvalid(format!("HTTP client error: {}", e)))?;
+
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        let payload = DiscordMessage {
+            username: config.username.clone().filter(|u| !u.is_empty()),
+            embeds: vec![DiscordEmbed {
+                title: if index == 0 { title.clone() } else { None },
+                description: chunk,
+                color: EMBED_COLOR,
+            }],
+        };
+
+        post_with_retry(&client, &config.webhook_url, &payload).await?;
+    }
+
+    Ok(())
+}
+
+/// Post one message, backing off on timeouts, 5xx responses and rate limits.
+/// A rate limit waits at least as long as Discord's `retry_after`.
+async fn post_with_retry(
+    client: &Client,
+    webhook_url: &str,
+    payload: &DiscordMessage,
+) -> Result<(), AppError> {
+    let backoff_delays = [1, 3, 9];
+    let mut last_error = None;
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        let failure = match post_message(client, webhook_url, payload).await {
+            Ok(()) => return Ok(()),
+            Err(failure) => failure,
+        };
+
+        let is_retryable = match &failure.error {
+            AppError::DiscordWebhookInvalid(msg) => super::is_retryable_webhook_error(msg),
+            _ => false,
+        };
+        let wait = failure.retry_after_secs.unwrap_or(0).max(*delay_secs);
+
+        last_error = Some(failure.error);
+        if !is_retryable || attempt == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(wait)).await;
+    }
+
+    Err(last_error
+        .unwrap_or_else(|| AppError::DiscordWebhookInvalid("Message not sent".to_string())))
+}
+
+async fn post_message(
+    client: &Client,
+    webhook_url: &str,
+    payload: &DiscordMessage,
+) -> Result<(), PostFailure> {
+    let response = client
+        .post(webhook_url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                AppError::DiscordWebhookInvalid("Request timed out".to_string())
+            } else {
+                AppError::DiscordWebhookInvalid(format!("Failed to send: {}", e))
+            }
+        })?;
+
+    let status = response.status();
+
+    if status == 401 || status == 403 || status == 404 {
+        return Err(AppError::DiscordWebhookInvalid(format!(
+            "Webhook deleted or invalid ({})",
+            status.as_u16()
+        ))
+        .into());
+    } else if status == 429 {
+        // Discord reports retry_after (seconds, fractional) in the JSON body
+        let retry_after = response
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|body| body.get("retry_after").and_then(|v| v.as_f64()))
+            .map(|secs| secs.ceil() as u64)
+            .unwrap_or(5);
+
+        return Err(PostFailure {
+            error: AppError::DiscordWebhookInvalid(format!(
+                "Rate limited - retry after {} seconds",
+                retry_after
+            )),
+            retry_after_secs: Some(retry_after),
+        });
+    } else if status.is_server_error() {
+        return Err(
+            AppError::DiscordWebhookInvalid(format!("Server error: HTTP {}", status)).into(),
+        );
+    } else if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(
+            AppError::DiscordWebhookInvalid(format!("HTTP {}: {}", status, error_text)).into(),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_message;
+
+    #[test]
+    fn split_message_keeps_lines_together() {
+        let text = "aaaa\nbbbb\ncccc";
+        assert_eq!(split_message(text, 9), vec!["aaaa\nbbbb", "cccc"]);
+        assert_eq!(split_message(text, 100), vec![text]);
+    }
+
+    #[test]
+    fn split_message_hard_wraps_long_lines() {
+        let long = "é".repeat(2500);
+        let chunks = split_message(&long, 2000);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.chars().count() <= 2000));
+        assert_eq!(chunks.concat(), long);
+    }
+}
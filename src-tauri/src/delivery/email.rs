@@ -1,4 +1,5 @@
 use crate::error::AppError;
+use crate::render::RendererKind;
 use lettre::message::header::ContentType;
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{Message, SmtpTransport, Transport};
@@ -14,10 +15,29 @@ pub struct SmtpConfig {
     pub username: String,
     pub password: String,
     pub use_tls: bool,
+    #[serde(default)]
+    pub renderer: RendererKind,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+/// Body type for the chosen renderer. Mail clients show `text/markdown` as an
+/// attachment or not at all, so Markdown goes out as plain text.
+fn body_content_type(renderer: RendererKind) -> Result<ContentType, AppError> {
+    let mime = match renderer {
+        RendererKind::Markdown => "text/plain",
+        other => other.content_type(),
+    };
+    ContentType::parse(&format!("{}; charset=utf-8", mime))
+        .map_err(|e| AppError::SmtpAuthFailed(format!("Invalid content type: {}", e)))
 }
 
 /// Send email via SMTP using lettre
-pub fn send_email(summary_markdown: &str, config: &SmtpConfig) -> Result<(), AppError> {
+pub fn send_email(summary_text: &str, config: &SmtpConfig) -> Result<(), AppError> {
+    super::check_renderer("email", config.renderer)?;
+    let content_type = body_content_type(config.renderer)?;
+
     // Build email message
     let email = Message::builder()
         .from(
@@ -31,8 +51,8 @@ pub fn send_email(summary_markdown: &str, config: &SmtpConfig) -> Result<(), App
             .parse()
             .map_err(|e| AppError::SmtpAuthFailed(format!("Invalid to address: {}", e)))?)
         .subject("Work Summary")
-        .header(ContentType::TEXT_PLAIN)
-        .body(summary_markdown.to_string())
+        .header(content_type)
+        .body(summary_text.to_string())
         .map_err(|e| AppError::SmtpAuthFailed(format!("Failed to build email: {}", e)))?;
 
     // Build SMTP transport
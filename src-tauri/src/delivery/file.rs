@@ -1,26 +1,226 @@
 use crate::error::AppError;
+use crate::markdown::{self, SummaryMarkdownInput};
+use crate::render::RendererKind;
 use serde::Deserialize;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Highest `-N` suffix tried before giving up on a free name
+const MAX_VERSION: u32 = 1000;
+
+/// Numbers temp files so concurrent writes to one path don't share one
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileConfig {
     #[serde(alias = "directory", alias = "directoryPath", alias = "directory_path")]
     pub directory_path: String,
+    /// Relative path below the directory, see `expand_path_template`
+    #[serde(default = "default_path_template")]
+    pub path_template: String,
+    #[serde(default)]
+    pub collision: CollisionPolicy,
+    #[serde(default)]
+    pub format: FileFormat,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+fn default_path_template() -> String {
+    "{date}.{ext}".to_string()
 }
 
-/// Write summary as markdown file
-pub fn write_markdown(summary: &str, config: &FileConfig, date: &str) -> Result<PathBuf, AppError> {
-    let dir_path = Path::new(&config.directory_path);
+/// What to do when the target file already exists
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollisionPolicy {
+    #[default]
+    Overwrite,
+    Append,
+    /// Write `name-2.ext`, `name-3.ext`, ... next to the existing file
+    VersionSuffix,
+    Skip,
+}
 
-    // Create directory if it doesn't exist
-    if !dir_path.exists() {
-        fs::create_dir_all(dir_path).map_err(|e| {
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FileFormat {
+    #[default]
+    Markdown,
+    Html,
+    Json,
+    Text,
+    Pdf,
+}
+
+impl FileFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            FileFormat::Markdown => "md",
+            FileFormat::Html => "html",
+            FileFormat::Json => "json",
+            FileFormat::Text => "txt",
+            FileFormat::Pdf => "pdf",
+        }
+    }
+
+    fn render(self, summary: SummaryMarkdownInput<'_>) -> Vec<u8> {
+        let text = match self {
+            FileFormat::Markdown => RendererKind::Markdown.render(summary),
+            FileFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Work Summary — {}</title>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+                markdown::escape_html(summary.date),
+                RendererKind::Html.render(summary)
+            ),
+            FileFormat::Json => RendererKind::Json.render(summary) + "\n",
+            FileFormat::Text => RendererKind::Text.render(summary),
+            FileFormat::Pdf => return crate::pdf::render_summary_to_pdf(summary),
+        };
+        text.into_bytes()
+    }
+
+    /// Separator placed between an existing file and an appended summary
+    fn append_separator(self) -> &'static str {
+        match self {
+            FileFormat::Markdown => "\n\n---\n\n",
+            FileFormat::Html => "\n<hr>\n",
+            FileFormat::Json | FileFormat::Text | FileFormat::Pdf => "\n\n",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FileWriteOutcome {
+    Written(PathBuf),
+    Appended(PathBuf),
+    Skipped(PathBuf),
+}
+
+/// Write the summary below the configured directory using the path template,
+/// collision policy and format of the target
+pub fn write_summary(
+    summary: SummaryMarkdownInput<'_>,
+    config: &FileConfig,
+) -> Result<FileWriteOutcome, AppError> {
+    let relative = expand_path_template(
+        &config.path_template,
+        summary.date,
+        config.format.extension(),
+    )?;
+    let path = Path::new(&config.directory_path).join(relative);
+    let content = match &config.template {
+        Some(_) if config.format == FileFormat::Pdf => {
+            return Err(AppError::NotConfigured(
+                "Templates cannot be used with PDF output".to_string(),
+            ))
+        }
+        Some(template) => crate::templates::render_template(template, summary)?.into_bytes(),
+        None => config.format.render(summary),
+    };
+
+    if !path.exists() {
+        write_atomic(&path, &content)?;
+        return Ok(FileWriteOutcome::Written(path));
+    }
+
+    match config.collision {
+        CollisionPolicy::Overwrite => {
+            write_atomic(&path, &content)?;
+            Ok(FileWriteOutcome::Written(path))
+        }
+        CollisionPolicy::Skip => Ok(FileWriteOutcome::Skipped(path)),
+        CollisionPolicy::Append => {
+            if matches!(config.format, FileFormat::Json | FileFormat::Pdf) {
+                return Err(AppError::NotConfigured(
+                    "Append is not supported for JSON or PDF files; use version suffix instead"
+                        .to_string(),
+                ));
+            }
+            let existing = fs::read_to_string(&path)
+                .map_err(|e| AppError::FileWriteError(format!("Failed to read file: {}", e)))?;
+            let combined = format!(
+                "{}{}{}",
+                existing.trim_end(),
+                config.format.append_separator(),
+                String::from_utf8_lossy(&content)
+            );
+            write_atomic(&path, &combined)?;
+            Ok(FileWriteOutcome::Appended(path))
+        }
+        CollisionPolicy::VersionSuffix => {
+            let versioned = next_free_version(&path)?;
+            write_atomic(&versioned, &content)?;
+            Ok(FileWriteOutcome::Written(versioned))
+        }
+    }
+}
+
+/// First `stem-N.ext` (2 <= N <= `MAX_VERSION`) that does not exist yet
+fn next_free_version(path: &Path) -> Result<PathBuf, AppError> {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    (2..=MAX_VERSION)
+        .map(|n| path.with_file_name(format!("{}-{}{}", stem, n, extension)))
+        .find(|candidate| !candidate.exists())
+        .ok_or_else(|| {
+            AppError::FileWriteError(format!(
+                "No free version of '{}' up to -{}",
+                path.display(),
+                MAX_VERSION
+            ))
+        })
+}
+
+/// Expand `{yyyy}`, `{mm}`, `{dd}`, `{date}` and `{ext}` in a relative path template.
+/// The result must stay below the directory it is joined to.
+pub fn expand_path_template(template: &str, date: &str, ext: &str) -> Result<PathBuf, AppError> {
+    let parts: Vec<&str> = date.split('-').collect();
+    if parts.len() != 3 {
+        return Err(AppError::NotConfigured(format!("Invalid date: {}", date)));
+    }
+
+    let expanded = template
+        .replace("{yyyy}", parts[0])
+        .replace("{mm}", parts[1])
+        .replace("{dd}", parts[2])
+        .replace("{date}", date)
+        .replace("{ext}", ext);
+    let relative = PathBuf::from(&expanded);
+
+    if expanded.is_empty()
+        || relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(AppError::NotConfigured(format!(
+            "Path template must be a relative path without '..': {}",
+            template
+        )));
+    }
+
+    Ok(relative)
+}
+
+/// Write through a temp file in the same directory, flush it to disk and
+/// rename it into place, so readers never see a half-written file
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), AppError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
             if e.kind() == std::io::ErrorKind::PermissionDenied {
                 AppError::FileWriteError(format!(
                     "Permission denied: Cannot create directory '{}'",
-                    config.directory_path
+                    parent.display()
                 ))
             } else {
                 AppError::FileWriteError(format!("Failed to create directory: {}", e))
@@ -28,16 +228,23 @@ pub fn write_markdown(summary: &str, config: &FileConfig, date: &str) -> Result<
         })?;
     }
 
-    // Build file path
-    let file_name = format!("{}.md", date);
-    let file_path = dir_path.join(&file_name);
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| AppError::FileWriteError(format!("Invalid path: {}", path.display())))?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
 
-    // Write file
-    fs::write(&file_path, summary).map_err(|e| {
+    let map_write_error = |e: std::io::Error| {
+        let _ = fs::remove_file(&temp_path);
         if e.kind() == std::io::ErrorKind::PermissionDenied {
             AppError::FileWriteError(format!(
                 "Permission denied: Cannot write to '{}'",
-                file_path.display()
+                path.display()
             ))
         } else if e.raw_os_error() == Some(28) {
             // ENOSPC - No space left on device
@@ -45,14 +252,25 @@ pub fn write_markdown(summary: &str, config: &FileConfig, date: &str) -> Result<
         } else {
             AppError::FileWriteError(format!("Failed to write file: {}", e))
         }
-    })?;
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp_path)
+        .map_err(map_write_error)?;
+    file.write_all(contents.as_ref())
+        .and_then(|_| file.sync_all())
+        .map_err(map_write_error)?;
+    drop(file);
+    fs::rename(&temp_path, path).map_err(map_write_error)?;
 
-    Ok(file_path)
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::FileConfig;
+    use super::*;
 
     #[test]
     fn file_config_accepts_directory_aliases() {
@@ -65,4 +283,67 @@ mod tests {
                 .expect("directoryPath alias should deserialize");
         assert_eq!(with_directory_path.directory_path, "/tmp/out2");
     }
+
+    #[test]
+    fn path_template_expands_date_tokens() {
+        assert_eq!(
+            expand_path_template("{yyyy}/{mm}/{date}-debrief.{ext}", "2026-02-14", "md")
+                .expect("template should expand"),
+            PathBuf::from("2026/02/2026-02-14-debrief.md")
+        );
+        assert!(expand_path_template("../{date}.md", "2026-02-14", "md").is_err());
+        assert!(expand_path_template("/etc/{date}", "2026-02-14", "md").is_err());
+    }
+
+    #[test]
+    fn collision_policies() {
+        let dir = std::env::temp_dir().join(format!(
+            "workday-debrief-file-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let summary = SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Shipped.",
+            tickets_closed: &[],
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 0.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        };
+        let config = |collision: &str, format: &str| -> FileConfig {
+            serde_json::from_value(serde_json::json!({
+                "directoryPath": dir.to_string_lossy(),
+                "pathTemplate": "{yyyy}/{date}.{ext}",
+                "collision": collision,
+                "format": format
+            }))
+            .expect("config should deserialize")
+        };
+        let target = dir.join("2026/2026-02-14.txt");
+
+        let first = write_summary(summary, &config("skip", "text")).expect("first write");
+        assert!(matches!(first, FileWriteOutcome::Written(ref p) if *p == target));
+
+        let skipped = write_summary(summary, &config("skip", "text")).expect("skip");
+        assert!(matches!(skipped, FileWriteOutcome::Skipped(_)));
+
+        let versioned = write_summary(summary, &config("versionSuffix", "text")).expect("version");
+        assert!(
+            matches!(versioned, FileWriteOutcome::Written(ref p) if *p == dir.join("2026/2026-02-14-2.txt"))
+        );
+
+        write_summary(summary, &config("append", "text")).expect("append");
+        let appended = fs::read_to_string(&target).expect("read back");
+        assert_eq!(appended.matches("Shipped.").count(), 2);
+
+        fs::write(dir.join("2026/2026-02-14.json"), "{}").expect("seed json");
+        assert!(write_summary(summary, &config("append", "json")).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
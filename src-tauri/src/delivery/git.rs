@@ -0,0 +1,394 @@
+use crate::error::AppError;
+use crate::markdown::SummaryMarkdownInput;
+use crate::render::RendererKind;
+use serde::Deserialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Trailer added to every generated commit; a file whose last commit lacks it
+/// was edited by hand
+const GENERATED_TRAILER: &str = "Generated-by: WorkdayDebrief";
+
+/// Longest a single git command may run; a push to an unreachable remote
+/// would otherwise hold up every delivery after it
+const GIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitConfig {
+    pub repo_path: String,
+    #[serde(default = "default_path_template")]
+    pub path_template: String,
+    /// Commit subject; `{date}` is replaced, empty uses a generated summary line
+    #[serde(default)]
+    pub commit_message: String,
+    pub author_name: Option<String>,
+    pub author_email: Option<String>,
+    #[serde(default)]
+    pub push: bool,
+    #[serde(default = "default_remote")]
+    pub remote: String,
+    /// Overwrite the file even if it was changed by hand since the last debrief
+    #[serde(default)]
+    pub overwrite_manual_edits: bool,
+    /// Format of the journal file; `{ext}` in the path follows it
+    #[serde(default)]
+    pub renderer: RendererKind,
+}
+
+fn default_path_template() -> String {
+    "{yyyy}/{mm}/{date}.{ext}".to_string()
+}
+
+fn default_remote() -> String {
+    "origin".to_string()
+}
+
+#[derive(Debug)]
+pub struct GitOutcome {
+    pub path: PathBuf,
+    /// Short hash of the new commit, None if the file was already up to date
+    pub commit: Option<String>,
+    pub pushed: bool,
+}
+
+/// Read a child's pipe to the end on its own thread, so a chatty command
+/// cannot fill the pipe and stall
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+/// Run git in the repository and return trimmed stdout
+fn git(repo: &Path, args: &[&str]) -> Result<String, AppError> {
+    let mut child = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        // Fail instead of waiting for credentials nobody is there to type
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env("GIT_ASKPASS", "")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                AppError::GitDeliveryFailed("git is not installed or not on PATH".to_string())
+            } else {
+                AppError::GitDeliveryFailed(format!("Cannot run git: {}", e))
+            }
+        })?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let command = args.first().copied().unwrap_or_default();
+    let deadline = Instant::now() + GIT_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(20)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(AppError::GitDeliveryFailed(format!(
+                    "git {} timed out after {}s",
+                    command,
+                    GIT_TIMEOUT.as_secs()
+                )));
+            }
+            Err(e) => {
+                return Err(AppError::GitDeliveryFailed(format!(
+                    "Cannot run git: {}",
+                    e
+                )))
+            }
+        }
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if status.success() {
+        Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+    } else {
+        Err(AppError::GitDeliveryFailed(format!(
+            "git {} failed: {}",
+            command,
+            String::from_utf8_lossy(&stderr).trim()
+        )))
+    }
+}
+
+fn commit_subject(summary: SummaryMarkdownInput<'_>, template: &str) -> String {
+    if template.trim().is_empty() {
+        format!(
+            "Work debrief {}: {} closed, {} in progress, {:.1}h focus",
+            summary.date,
+            summary.tickets_closed.len(),
+            summary.tickets_in_progress.len(),
+            summary.focus_hours
+        )
+    } else {
+        template.replace("{date}", summary.date)
+    }
+}
+
+/// Refuse to overwrite a journal entry that was changed outside WorkdayDebrief.
+/// `pending_is_ours` means the uncommitted file already holds the entry, left
+/// behind by a delivery that failed before committing.
+fn check_manual_edits(repo: &Path, relative: &str, pending_is_ours: bool) -> Result<(), AppError> {
+    let status = git(repo, &["status", "--porcelain", "--", relative])?;
+    if !status.is_empty() && !status.starts_with("??") && !pending_is_ours {
+        return Err(AppError::GitDeliveryFailed(format!(
+            "Conflict: {} has uncommitted changes",
+            relative
+        )));
+    }
+
+    // A fresh repository has no history to compare against
+    if git(repo, &["rev-parse", "--verify", "-q", "HEAD"]).is_err() {
+        return Ok(());
+    }
+
+    let last_message = git(repo, &["log", "-1", "--format=%B", "--", relative])?;
+    if !last_message.is_empty() && !last_message.contains(GENERATED_TRAILER) {
+        return Err(AppError::GitDeliveryFailed(format!(
+            "Conflict: {} was last committed by hand",
+            relative
+        )));
+    }
+
+    Ok(())
+}
+
+/// File extension for a journal entry in `renderer`'s format
+fn extension(renderer: RendererKind) -> &'static str {
+    match renderer {
+        RendererKind::Markdown => "md",
+        RendererKind::Html => "html",
+        RendererKind::Json => "json",
+        _ => "txt",
+    }
+}
+
+/// What gets committed for one summary
+struct JournalEntry {
+    date: String,
+    content: String,
+    subject: String,
+}
+
+impl JournalEntry {
+    fn new(summary: SummaryMarkdownInput<'_>, config: &GitConfig) -> JournalEntry {
+        JournalEntry {
+            date: summary.date.to_string(),
+            content: config.renderer.render(summary),
+            subject: commit_subject(summary, &config.commit_message),
+        }
+    }
+}
+
+/// Write the summary into the journal repository, commit it and optionally
+/// push. git runs on a blocking thread so it never stalls the async runtime.
+pub async fn commit_summary(
+    summary: SummaryMarkdownInput<'_>,
+    config: &GitConfig,
+) -> Result<GitOutcome, AppError> {
+    super::check_renderer("git", config.renderer)?;
+    let entry = JournalEntry::new(summary, config);
+    let config = config.clone();
+    tokio::task::spawn_blocking(move || commit_entry(&entry, &config))
+        .await
+        .map_err(|e| AppError::GitDeliveryFailed(format!("Git task failed: {}", e)))?
+}
+
+fn commit_entry(entry: &JournalEntry, config: &GitConfig) -> Result<GitOutcome, AppError> {
+    let repo = Path::new(&config.repo_path);
+    if git(repo, &["rev-parse", "--is-inside-work-tree"]).is_err() {
+        return Err(AppError::GitDeliveryFailed(format!(
+            "Not a git repository: {}",
+            config.repo_path
+        )));
+    }
+
+    let relative_path = super::file::expand_path_template(
+        &config.path_template,
+        &entry.date,
+        extension(config.renderer),
+    )?;
+    // git wants forward slashes in pathspecs on every platform
+    let relative = relative_path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let path = repo.join(&relative_path);
+
+    let on_disk = std::fs::read_to_string(&path)
+        .map(|existing| existing == entry.content)
+        .unwrap_or(false);
+    // Up to date only when that content is also what HEAD holds: an earlier
+    // delivery may have written and staged the file, then failed to commit
+    let unchanged = on_disk
+        && git(repo, &["status", "--porcelain", "--", &relative])?.is_empty()
+        && git(
+            repo,
+            &["rev-parse", "--verify", "-q", &format!("HEAD:{}", relative)],
+        )
+        .is_ok();
+
+    let mut commit = None;
+
+    if !unchanged {
+        if !config.overwrite_manual_edits {
+            check_manual_edits(repo, &relative, on_disk)?;
+        }
+
+        super::file::write_atomic(&path, &entry.content)?;
+        git(repo, &["add", "--", &relative])?;
+
+        let mut args: Vec<String> = Vec::new();
+
+        let author = match (&config.author_name, &config.author_email) {
+            (Some(name), Some(email)) if !name.is_empty() && !email.is_empty() => {
+                // Also use it as committer so commits work without a global git identity
+                args.extend([
+                    "-c".to_string(),
+                    format!("user.name={}", name),
+                    "-c".to_string(),
+                    format!("user.email={}", email),
+                ]);
+                Some(format!("{} <{}>", name, email))
+            }
+            _ => None,
+        };
+
+        args.extend([
+            "commit".to_string(),
+            "-m".to_string(),
+            entry.subject.clone(),
+            "-m".to_string(),
+            GENERATED_TRAILER.to_string(),
+        ]);
+        if let Some(author) = author {
+            args.push(format!("--author={}", author));
+        }
+        // Commit only the journal entry, never whatever else happens to be staged
+        args.extend(["--".to_string(), relative.clone()]);
+
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        git(repo, &args)?;
+        commit = Some(git(repo, &["rev-parse", "--short", "HEAD"])?);
+    }
+
+    let mut pushed = false;
+    if config.push {
+        git(repo, &["push", &config.remote, "HEAD"]).map_err(|e| {
+            AppError::GitDeliveryFailed(format!(
+                "Push failed (commit kept locally): {}",
+                e.to_string().trim_start_matches("Git delivery failed: ")
+            ))
+        })?;
+        pushed = true;
+    }
+
+    Ok(GitOutcome {
+        path,
+        commit,
+        pushed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> SummaryMarkdownInput<'static> {
+        SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Shipped.",
+            tickets_closed: &[],
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 2.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        }
+    }
+
+    #[test]
+    fn commit_subject_uses_template_or_generated_line() {
+        assert_eq!(
+            commit_subject(sample(), ""),
+            "Work debrief 2026-02-14: 0 closed, 0 in progress, 2.0h focus"
+        );
+        assert_eq!(
+            commit_subject(sample(), "Debrief {date}"),
+            "Debrief 2026-02-14"
+        );
+    }
+
+    #[test]
+    fn commit_summary_commits_and_detects_manual_edits() {
+        if Command::new("git").arg("--version").output().is_err() {
+            return; // git not available in this environment
+        }
+
+        let repo = std::env::temp_dir().join(format!(
+            "workday-debrief-git-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&repo).expect("temp repo dir");
+        git(&repo, &["init", "-q"]).expect("git init");
+
+        let config: GitConfig = serde_json::from_value(serde_json::json!({
+            "repoPath": repo.to_string_lossy(),
+            "authorName": "Debrief Bot",
+            "authorEmail": "bot@example.com"
+        }))
+        .expect("config should deserialize");
+
+        let commit = |summary| commit_entry(&JournalEntry::new(summary, &config), &config);
+        let first = commit(sample()).expect("first commit");
+        assert!(first.commit.is_some());
+        assert_eq!(first.path, repo.join("2026/02/2026-02-14.md"));
+
+        // Same content again is a no-op
+        let again = commit(sample()).expect("second run");
+        assert!(again.commit.is_none());
+
+        // Written and staged by a delivery whose commit failed: commit it now
+        let mut next_day = sample();
+        next_day.date = "2026-02-15";
+        let staged = JournalEntry::new(next_day, &config);
+        let staged_path = repo.join("2026/02/2026-02-15.md");
+        std::fs::create_dir_all(repo.join("2026/02")).expect("entry dir");
+        std::fs::write(&staged_path, &staged.content).expect("staged entry");
+        git(&repo, &["add", "--", "2026/02/2026-02-15.md"]).expect("git add");
+        let retried = commit(next_day).expect("retry after a failed commit");
+        assert!(retried.commit.is_some());
+        assert!(git(&repo, &["status", "--porcelain"])
+            .expect("status")
+            .is_empty());
+
+        // Hand edits block the next delivery
+        std::fs::write(&first.path, "edited by hand").expect("hand edit");
+        let mut changed = sample();
+        changed.narrative = "Changed.";
+        assert!(commit(changed).is_err());
+
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+}
@@ -0,0 +1,488 @@
+use crate::aggregation::jira::JiraConnection;
+use crate::aggregation::time::{mentions_key, TimeEntry};
+use crate::aggregation::Ticket;
+use crate::error::AppError;
+use crate::markdown::SummaryMarkdownInput;
+use reqwest::{Client, Method};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraDeliveryConfig {
+    // Connection details are injected from settings and the vault at send time
+    #[serde(default)]
+    pub base_url: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub api_token: String,
+    #[serde(default)]
+    pub auth_mode: crate::aggregation::jira::JiraAuthMode,
+    #[serde(default)]
+    pub ca_cert_path: Option<String>,
+    /// Time-tracking service whose entries become worklogs
+    #[serde(default)]
+    pub time_source: Option<crate::aggregation::time::TimeSource>,
+    /// Summary time zone and day start the entries are fetched for
+    #[serde(default)]
+    pub time_zone: Option<String>,
+    #[serde(default)]
+    pub day_start: Option<String>,
+
+    #[serde(default = "default_true")]
+    pub add_comments: bool,
+    #[serde(default)]
+    pub log_work: bool,
+    #[serde(default = "default_true")]
+    pub include_closed: bool,
+    #[serde(default = "default_true")]
+    pub include_in_progress: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// What the Jira target will do to a single issue
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssueAction {
+    pub issue_key: String,
+    pub issue_url: String,
+    pub comment: Option<String>,
+    pub worklog_seconds: Option<i64>,
+    pub worklog_started: Option<String>,
+}
+
+/// Jira rejects worklogs shorter than a minute
+const MIN_WORKLOG_SECONDS: i64 = 60;
+
+/// Start of every debrief comment. Finding it on an issue means the day was
+/// already delivered there, so the comment is edited instead of posted again.
+fn comment_marker(date: &str) -> String {
+    format!("Work Debrief {}:", date)
+}
+
+/// Worklog comment marking the time logged for a summary day
+fn worklog_marker(date: &str) -> String {
+    format!("Logged by WorkdayDebrief for {}", date)
+}
+
+/// Sentences of the narrative that mention the ticket key
+fn narrative_excerpt(narrative: &str, key: &str) -> Option<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+
+    for c in narrative.chars() {
+        if c == '\n' {
+            sentences.push(std::mem::take(&mut current));
+            continue;
+        }
+        current.push(c);
+        if matches!(c, '.' | '!' | '?') {
+            sentences.push(std::mem::take(&mut current));
+        }
+    }
+    sentences.push(current);
+
+    let relevant: Vec<&str> = sentences
+        .iter()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty() && mentions_key(s, key))
+        .collect();
+
+    if relevant.is_empty() {
+        None
+    } else {
+        Some(relevant.join(" "))
+    }
+}
+
+/// Decide which issues will be touched and how, without calling Jira
+pub fn plan_actions(
+    summary: SummaryMarkdownInput<'_>,
+    config: &JiraDeliveryConfig,
+    time_entries: &[TimeEntry],
+) -> Vec<JiraIssueAction> {
+    let mut tickets: Vec<&Ticket> = Vec::new();
+    if config.include_closed {
+        tickets.extend(summary.tickets_closed.iter());
+    }
+    if config.include_in_progress {
+        tickets.extend(summary.tickets_in_progress.iter());
+    }
+
+    tickets
+        .into_iter()
+        .filter_map(|ticket| {
+            let comment = if config.add_comments {
+                let body = narrative_excerpt(summary.narrative, &ticket.id).unwrap_or_else(|| {
+                    format!("Worked on this issue (status: {}).", ticket.status)
+                });
+                Some(format!("{}\n{}", comment_marker(summary.date), body))
+            } else {
+                None
+            };
+
+            let (worklog_seconds, worklog_started) = if config.log_work {
+                let linked: Vec<&TimeEntry> = time_entries
+                    .iter()
+                    .filter(|e| mentions_key(&e.description, &ticket.id))
+                    .collect();
+                let seconds: i64 = linked.iter().map(|e| e.duration_seconds).sum();
+                // Round to whole minutes, as Jira displays them
+                let seconds = (seconds + 30) / 60 * 60;
+
+                if seconds >= MIN_WORKLOG_SECONDS {
+                    let started = linked
+                        .iter()
+                        .map(|e| e.start.as_str())
+                        .min()
+                        .map(jira_timestamp);
+                    (Some(seconds), started)
+                } else {
+                    (None, None)
+                }
+            } else {
+                (None, None)
+            };
+
+            if comment.is_none() && worklog_seconds.is_none() {
+                return None;
+            }
+
+            Some(JiraIssueAction {
+                issue_key: ticket.id.clone(),
+                issue_url: ticket.url.clone(),
+                comment,
+                worklog_seconds,
+                worklog_started,
+            })
+        })
+        .collect()
+}
+
+/// Jira wants `2026-02-14T09:30:00.000+0000` for worklog start times
+fn jira_timestamp(rfc3339: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(rfc3339)
+        .map(|dt| dt.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string())
+        .unwrap_or_else(|_| rfc3339.to_string())
+}
+
+/// Fetch tracked time for the summary date when work logging is enabled
+pub async fn load_time_entries(
+    summary_date: &str,
+    config: &JiraDeliveryConfig,
+) -> Result<Vec<TimeEntry>, AppError> {
+    if !config.log_work {
+        return Ok(Vec::new());
+    }
+
+    let source = config.time_source.as_ref().ok_or_else(|| {
+        AppError::JiraDeliveryFailed(
+            "Logging work needs a time-tracking service in Settings".to_string(),
+        )
+    })?;
+    let date = chrono::NaiveDate::parse_from_str(summary_date, "%Y-%m-%d")
+        .map_err(|e| AppError::JiraDeliveryFailed(format!("Invalid summary date: {}", e)))?;
+    let day = crate::aggregation::day::DayBoundary::new(
+        config.time_zone.as_deref(),
+        config.day_start.as_deref().unwrap_or("00:00"),
+    )?
+    .day(date)?;
+
+    source.fetch_entries(&day).await
+}
+
+/// Post comments and worklogs to every planned issue.
+/// Returns (comments added, worklogs added).
+pub async fn send_jira(
+    summary: SummaryMarkdownInput<'_>,
+    config: &JiraDeliveryConfig,
+) -> Result<(usize, usize), AppError> {
+    let connection = JiraConnection {
+        base_url: config.base_url.clone(),
+        auth_mode: config.auth_mode,
+        email: config.email.clone(),
+        api_token: config.api_token.clone(),
+        ca_cert_path: config.ca_cert_path.clone(),
+    };
+    if !connection.is_configured() {
+        return Err(AppError::JiraDeliveryFailed(
+            "Jira is not configured. Add your Jira URL and credentials in Settings.".to_string(),
+        ));
+    }
+
+    let time_entries = load_time_entries(summary.date, config).await?;
+    let actions = plan_actions(summary, config, &time_entries);
+    if actions.is_empty() {
+        return Ok((0, 0));
+    }
+
+    let client = connection.http_client()?;
+    let auth_header = connection.auth_header();
+    let base_url = connection.base_url();
+
+    let mut comments = 0;
+    let mut worklogs = 0;
+    let mut failures = Vec::new();
+
+    // Each request is retried on its own so a failure never re-posts earlier comments,
+    // and an earlier delivery of the same day is edited rather than duplicated
+    for action in &actions {
+        let issue_url = format!("{}/rest/api/2/issue/{}", base_url, action.issue_key);
+
+        if let Some(comment) = &action.comment {
+            let marked = Marked {
+                collection_url: format!("{}/comment", issue_url),
+                list_query: "?orderBy=-created&maxResults=100",
+                list_field: "comments",
+                text_field: "body",
+                marker: comment_marker(summary.date),
+            };
+            match upsert_marked(&client, &auth_header, &marked, json!({ "body": comment })).await {
+                Ok(()) => comments += 1,
+                Err(e) => failures.push(format!("{} comment: {}", action.issue_key, e)),
+            }
+        }
+
+        if let Some(seconds) = action.worklog_seconds {
+            let marked = Marked {
+                collection_url: format!("{}/worklog", issue_url),
+                list_query: "",
+                list_field: "worklogs",
+                text_field: "comment",
+                marker: worklog_marker(summary.date),
+            };
+            let mut body = json!({
+                "timeSpentSeconds": seconds,
+                "comment": marked.marker,
+            });
+            if let Some(started) = &action.worklog_started {
+                body["started"] = json!(started);
+            }
+            match upsert_marked(&client, &auth_header, &marked, body).await {
+                Ok(()) => worklogs += 1,
+                Err(e) => failures.push(format!("{} worklog: {}", action.issue_key, e)),
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(AppError::JiraDeliveryFailed(format!(
+            "{} comment(s) and {} worklog(s) saved, but: {}",
+            comments,
+            worklogs,
+            failures.join("; ")
+        )));
+    }
+
+    Ok((comments, worklogs))
+}
+
+/// Comments or worklogs of one issue, and how a debrief entry is recognised
+struct Marked {
+    collection_url: String,
+    list_query: &'static str,
+    list_field: &'static str,
+    text_field: &'static str,
+    marker: String,
+}
+
+/// Id of the first entry in a Jira comment or worklog listing whose text
+/// starts with `marker`
+fn marked_id(listing: &serde_json::Value, marked: &Marked) -> Option<String> {
+    listing[marked.list_field]
+        .as_array()?
+        .iter()
+        .find(|entry| {
+            entry[marked.text_field]
+                .as_str()
+                .is_some_and(|text| text.starts_with(&marked.marker))
+        })
+        .and_then(|entry| entry["id"].as_str().map(str::to_string))
+}
+
+/// Edit the entry left by an earlier delivery of the day, or add a new one
+async fn upsert_marked(
+    client: &Client,
+    auth_header: &str,
+    marked: &Marked,
+    body: serde_json::Value,
+) -> Result<(), String> {
+    let response = client
+        .get(format!("{}{}", marked.collection_url, marked.list_query))
+        .header("Authorization", auth_header)
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "HTTP {} while checking for an earlier debrief",
+            response.status()
+        ));
+    }
+    let listing: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+
+    match marked_id(&listing, marked) {
+        Some(id) => {
+            let url = format!("{}/{}", marked.collection_url, id);
+            send_with_retry(client, Method::PUT, &url, auth_header, body).await
+        }
+        None => {
+            send_with_retry(
+                client,
+                Method::POST,
+                &marked.collection_url,
+                auth_header,
+                body,
+            )
+            .await
+        }
+    }
+}
+
+async fn send_with_retry(
+    client: &Client,
+    method: Method,
+    url: &str,
+    auth_header: &str,
+    body: serde_json::Value,
+) -> Result<(), String> {
+    let backoff_delays = [1, 3, 9];
+    let mut last_error = String::new();
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        let result = client
+            .request(method.clone(), url)
+            .header("Authorization", auth_header)
+            .header("Accept", "application/json")
+            .json(&body)
+            .send()
+            .await;
+
+        let is_retryable = match result {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => {
+                let status = response.status();
+                last_error = match status.as_u16() {
+                    401 => "Authentication failed - check API token".to_string(),
+                    403 => "No permission to update this issue".to_string(),
+                    404 => "Issue not found".to_string(),
+                    _ => format!("HTTP {}", status),
+                };
+                status == 429 || status.is_server_error()
+            }
+            Err(e) => {
+                last_error = if e.is_timeout() {
+                    "Request timed out".to_string()
+                } else {
+                    e.to_string()
+                };
+                e.is_timeout() || e.is_connect()
+            }
+        };
+
+        if !is_retryable || attempt == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+    }
+
+    Err(last_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(id: &str, status: &str) -> Ticket {
+        Ticket {
+            id: id.to_string(),
+            title: "Title".to_string(),
+            status: status.to_string(),
+            url: format!("https://jira/browse/{}", id),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }
+    }
+
+    fn config(add_comments: bool, log_work: bool) -> JiraDeliveryConfig {
+        serde_json::from_value(json!({ "addComments": add_comments, "logWork": log_work }))
+            .expect("config should deserialize")
+    }
+
+    #[test]
+    fn plan_uses_narrative_excerpts_and_linked_time() {
+        let closed = vec![ticket("PROJ-1", "Done")];
+        let in_progress = vec![ticket("PROJ-2", "In Progress")];
+        let summary = SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Closed PROJ-1 after fixing the token refresh. Also met the team.",
+            tickets_closed: &closed,
+            tickets_in_progress: &in_progress,
+            meetings: &[],
+            focus_hours: 0.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        };
+        let entries = vec![
+            TimeEntry {
+                description: "PROJ-2 refactor".to_string(),
+                start: "2026-02-14T13:00:00Z".to_string(),
+                duration_seconds: 1790,
+                ..Default::default()
+            },
+            TimeEntry {
+                description: "PROJ-2 review".to_string(),
+                start: "2026-02-14T09:00:00Z".to_string(),
+                duration_seconds: 1800,
+                ..Default::default()
+            },
+        ];
+
+        let actions = plan_actions(summary, &config(true, true), &entries);
+        assert_eq!(actions.len(), 2);
+        assert_eq!(
+            actions[0].comment.as_deref(),
+            Some("Work Debrief 2026-02-14:\nClosed PROJ-1 after fixing the token refresh.")
+        );
+        assert_eq!(actions[0].worklog_seconds, None);
+        assert_eq!(actions[1].worklog_seconds, Some(3600));
+        assert_eq!(
+            actions[1].worklog_started.as_deref(),
+            Some("2026-02-14T09:00:00.000+0000")
+        );
+
+        // Nothing to do for issues without comments or time
+        let actions = plan_actions(summary, &config(false, true), &[]);
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn finds_the_comment_from_an_earlier_delivery() {
+        let marked = Marked {
+            collection_url: "https://jira/rest/api/2/issue/PROJ-1/comment".to_string(),
+            list_query: "",
+            list_field: "comments",
+            text_field: "body",
+            marker: comment_marker("2026-02-14"),
+        };
+        let listing = json!({
+            "comments": [
+                { "id": "100", "body": "Work Debrief 2026-02-13:\nYesterday." },
+                { "id": "101", "body": "Looks good to me" },
+                { "id": "102", "body": "Work Debrief 2026-02-14:\nClosed PROJ-1." },
+            ]
+        });
+        assert_eq!(marked_id(&listing, &marked).as_deref(), Some("102"));
+        assert_eq!(marked_id(&json!({ "comments": [] }), &marked), None);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::error::AppError;
+use crate::render::RendererKind;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MattermostConfig {
+    pub webhook_url: String,
+    pub channel: Option<String>,
+    pub username: Option<String>,
+    #[serde(default)]
+    pub renderer: RendererKind,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct MattermostMessage {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+}
+
+/// Mattermost's default post size limit
+const MAX_POST_LEN: usize = 16383;
+
+/// Webhook payload; over-long summaries are cut and blank overrides dropped
+fn build_payload(summary_markdown: &str, config: &MattermostConfig) -> MattermostMessage {
+    let mut text = summary_markdown.to_string();
+    if text.len() > MAX_POST_LEN {
+        let mut cut = MAX_POST_LEN - 32;
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        text.truncate(cut);
+        text.push_str("\n\n_Summary truncated_");
+    }
+
+    MattermostMessage {
+        text,
+        channel: config.channel.clone().filter(|c| !c.is_empty()),
+        username: config.username.clone().filter(|u| !u.is_empty()),
+    }
+}
+
+/// Send summary to a Mattermost incoming webhook (full markdown is supported)
+pub async fn send_mattermost(
+    summary_markdown: &str,
+    config: &MattermostConfig,
+) -> Result<(), AppError> {
+    let payload = build_payload(summary_markdown, config);
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| AppError::MattermostWebhookInvalid(format!("HTTP client error: {}", e)))?;
+
+    let response = client
+        .post(&config.webhook_url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                AppError::MattermostWebhookInvalid("Request timed out".to_string())
+            } else {
+                AppError::MattermostWebhookInvalid(format!("Failed to send: {}", e))
+            }
+        })?;
+
+    let status = response.status();
+
+    if status == 401 || status == 403 || status == 404 {
+        return Err(AppError::MattermostWebhookInvalid(format!(
+            "Webhook disabled or invalid ({})",
+            status.as_u16()
+        )));
+    } else if status == 429 {
+        let retry_after = response
+            .headers()
+            .get("x-ratelimit-reset")
+            .or_else(|| response.headers().get("retry-after"))
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(5);
+
+        return Err(AppError::MattermostWebhookInvalid(format!(
+            "Rate limited - retry after {} seconds",
+            retry_after
+        )));
+    } else if status.is_server_error() {
+        return Err(AppError::MattermostWebhookInvalid(format!(
+            "Server error: HTTP {}",
+            status
+        )));
+    } else if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(AppError::MattermostWebhookInvalid(format!(
+            "HTTP {}: {}",
+            status, error_text
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_drops_blank_overrides_and_truncates() {
+        let config: MattermostConfig = serde_json::from_str(
+            r#"{"webhookUrl": "https://chat/hooks/x", "channel": "", "username": ""}"#,
+        )
+        .expect("config should parse");
+        let payload = serde_json::to_value(build_payload("# Summary", &config))
+            .expect("payload should serialize");
+        assert_eq!(payload, serde_json::json!({ "text": "# Summary" }));
+
+        let long = "é".repeat(MAX_POST_LEN);
+        let payload = build_payload(&long, &config);
+        assert!(payload.text.len() <= MAX_POST_LEN);
+        assert!(payload.text.ends_with("_Summary truncated_"));
+    }
+}
@@ -1,8 +1,17 @@
+pub mod discord;
 pub mod email;
 pub mod file;
+pub mod git;
+pub mod jira;
+pub mod mattermost;
+pub mod notes;
 pub mod slack;
+pub mod teams;
+pub mod webhook;
 
 use crate::error::AppError;
+use crate::markdown::SummaryMarkdownInput;
+use crate::render::RendererKind;
 use chrono::Local;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -25,12 +34,39 @@ pub enum DeliveryConfig {
     Slack(slack::SlackConfig),
     #[serde(rename = "file")]
     File(file::FileConfig),
+    #[serde(rename = "teams")]
+    Teams(teams::TeamsConfig),
+    #[serde(rename = "discord")]
+    Discord(discord::DiscordConfig),
+    #[serde(rename = "mattermost")]
+    Mattermost(mattermost::MattermostConfig),
+    #[serde(rename = "webhook")]
+    Webhook(webhook::WebhookConfig),
+    #[serde(rename = "jira")]
+    Jira(jira::JiraDeliveryConfig),
+    #[serde(rename = "notes")]
+    Notes(notes::NotesVaultConfig),
+    #[serde(rename = "git")]
+    Git(git::GitConfig),
 }
 
+/// Delivery type identifiers accepted by `DeliveryConfig`
+pub const DELIVERY_TYPES: &[&str] = &[
+    "email",
+    "slack",
+    "file",
+    "teams",
+    "discord",
+    "mattermost",
+    "webhook",
+    "jira",
+    "notes",
+    "git",
+];
+
 /// Send summary to multiple delivery targets with retry logic
 pub async fn send_summary(
-    summary_markdown: &str,
-    date: &str,
+    summary: SummaryMarkdownInput<'_>,
     configs: Vec<DeliveryConfig>,
 ) -> Vec<DeliveryConfirmation> {
     let mut confirmations = Vec::new();
@@ -38,14 +74,67 @@ pub async fn send_summary(
     for config in configs {
         let confirmation = match config {
             DeliveryConfig::Email(email_config) => {
-                send_email_with_retry(summary_markdown, &email_config).await
+                match render_text(
+                    summary,
+                    "email",
+                    email_config.renderer,
+                    &email_config.template,
+                ) {
+                    Ok(body) => send_email_with_retry(&body, &email_config).await,
+                    Err(e) => render_failed("email", e),
+                }
             }
             DeliveryConfig::Slack(slack_config) => {
-                send_slack_with_retry(summary_markdown, &slack_config).await
+                match render_text(
+                    summary,
+                    "slack",
+                    slack_config.renderer,
+                    &slack_config.template,
+                ) {
+                    Ok(body) => send_slack_with_retry(&body, &slack_config).await,
+                    Err(e) => render_failed("slack", e),
+                }
             }
-            DeliveryConfig::File(file_config) => {
-                send_file_with_retry(summary_markdown, date, &file_config).await
+            DeliveryConfig::File(file_config) => send_file_with_retry(summary, &file_config).await,
+            DeliveryConfig::Teams(teams_config) => {
+                match render_text(
+                    summary,
+                    "teams",
+                    teams_config.renderer,
+                    &teams_config.template,
+                ) {
+                    Ok(body) => send_teams_with_retry(&body, &teams_config).await,
+                    Err(e) => render_failed("teams", e),
+                }
+            }
+            DeliveryConfig::Discord(discord_config) => {
+                match render_text(
+                    summary,
+                    "discord",
+                    discord_config.renderer,
+                    &discord_config.template,
+                ) {
+                    Ok(body) => send_discord_message(&body, &discord_config).await,
+                    Err(e) => render_failed("discord", e),
+                }
             }
+            DeliveryConfig::Mattermost(mattermost_config) => match render_text(
+                summary,
+                "mattermost",
+                mattermost_config.renderer,
+                &mattermost_config.template,
+            ) {
+                Ok(body) => send_mattermost_with_retry(&body, &mattermost_config).await,
+                Err(e) => render_failed("mattermost", e),
+            },
+            DeliveryConfig::Webhook(webhook_config) => {
+                send_webhook_with_retry(summary, &webhook_config).await
+            }
+            DeliveryConfig::Jira(jira_config) => send_jira_updates(summary, &jira_config).await,
+            DeliveryConfig::Notes(notes_config) => {
+                send_notes_with_retry(summary, &notes_config).await
+            }
+            DeliveryConfig::Git(git_config) => send_git_with_retry(summary, &git_config).await,
         };
         confirmations.push(confirmation);
     }
@@ -53,6 +142,70 @@ pub async fn send_summary(
     confirmations
 }
 
+/// Renderers a message-style target shows as intended; the others would arrive
+/// as raw HTML tags or another app's markup. None for targets without a choice.
+fn accepted_renderers(delivery_type: &str) -> Option<&'static [RendererKind]> {
+    match delivery_type {
+        "email" => Some(&[
+            RendererKind::Markdown,
+            RendererKind::Html,
+            RendererKind::Text,
+        ]),
+        "slack" => Some(&[RendererKind::SlackMrkdwn, RendererKind::Text]),
+        "teams" | "discord" | "mattermost" => Some(&[RendererKind::Markdown, RendererKind::Text]),
+        "git" => Some(&[
+            RendererKind::Markdown,
+            RendererKind::Html,
+            RendererKind::Text,
+            RendererKind::Json,
+        ]),
+        _ => None,
+    }
+}
+
+/// Reject a renderer the target cannot display
+pub fn check_renderer(delivery_type: &str, renderer: RendererKind) -> Result<(), AppError> {
+    match accepted_renderers(delivery_type) {
+        Some(accepted) if !accepted.contains(&renderer) => Err(AppError::NotConfigured(format!(
+            "{} cannot show {} output; choose {}",
+            delivery_type,
+            renderer.name(),
+            accepted
+                .iter()
+                .map(|kind| kind.name())
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Text for a message-style target: the user template if one is attached,
+/// otherwise the configured renderer
+fn render_text(
+    summary: SummaryMarkdownInput<'_>,
+    delivery_type: &str,
+    renderer: RendererKind,
+    template: &Option<String>,
+) -> Result<String, AppError> {
+    match template {
+        Some(template) => crate::templates::render_template(template, summary),
+        None => {
+            check_renderer(delivery_type, renderer)?;
+            Ok(renderer.render(summary))
+        }
+    }
+}
+
+fn render_failed(delivery_type: &str, error: AppError) -> DeliveryConfirmation {
+    DeliveryConfirmation {
+        delivery_type: delivery_type.to_string(),
+        success: false,
+        message: error.to_string(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
 /// Email delivery with retry logic
 async fn send_email_with_retry(
     summary_markdown: &str,
@@ -141,20 +294,30 @@ async fn send_slack_with_retry(
 
 /// File delivery with retry logic
 async fn send_file_with_retry(
-    summary: &str,
-    date: &str,
+    summary: SummaryMarkdownInput<'_>,
     config: &file::FileConfig,
 ) -> DeliveryConfirmation {
     let mut last_error = None;
     let backoff_delays = [1, 3, 9];
 
     for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
-        match file::write_markdown(summary, config, date) {
-            Ok(path) => {
+        match file::write_summary(summary, config) {
+            Ok(outcome) => {
+                let message = match outcome {
+                    file::FileWriteOutcome::Written(path) => {
+                        format!("Written to {}", path.display())
+                    }
+                    file::FileWriteOutcome::Appended(path) => {
+                        format!("Appended to {}", path.display())
+                    }
+                    file::FileWriteOutcome::Skipped(path) => {
+                        format!("Skipped, {} already exists", path.display())
+                    }
+                };
                 return DeliveryConfirmation {
                     delivery_type: "file".to_string(),
                     success: true,
-                    message: format!("Written to {}", path.display()),
+                    message,
                     timestamp: Local::now().to_rfc3339(),
                 };
             }
@@ -182,3 +345,271 @@ async fn send_file_with_retry(
         timestamp: Local::now().to_rfc3339(),
     }
 }
+
+/// Teams delivery with retry logic
+async fn send_teams_with_retry(
+    summary_markdown: &str,
+    config: &teams::TeamsConfig,
+) -> DeliveryConfirmation {
+    let mut last_error = None;
+    let backoff_delays = [1, 3, 9];
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        match teams::send_teams(summary_markdown, config).await {
+            Ok(()) => {
+                return DeliveryConfirmation {
+                    delivery_type: "teams".to_string(),
+                    success: true,
+                    message: "Posted to Teams".to_string(),
+                    timestamp: Local::now().to_rfc3339(),
+                };
+            }
+            Err(e) => {
+                let is_retryable = match &e {
+                    AppError::TeamsWebhookInvalid(msg) => is_retryable_webhook_error(msg),
+                    _ => false,
+                };
+
+                last_error = Some(e);
+                if !is_retryable || attempt == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+            }
+        }
+    }
+
+    DeliveryConfirmation {
+        delivery_type: "teams".to_string(),
+        success: false,
+        message: last_error.map(|e| e.to_string()).unwrap_or_default(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
+/// Discord delivery; each chunk is retried individually inside `discord`
+async fn send_discord_message(
+    summary_markdown: &str,
+    config: &discord::DiscordConfig,
+) -> DeliveryConfirmation {
+    match discord::send_discord(summary_markdown, config).await {
+        Ok(()) => DeliveryConfirmation {
+            delivery_type: "discord".to_string(),
+            success: true,
+            message: "Posted to Discord".to_string(),
+            timestamp: Local::now().to_rfc3339(),
+        },
+        Err(e) => DeliveryConfirmation {
+            delivery_type: "discord".to_string(),
+            success: false,
+            message: e.to_string(),
+            timestamp: Local::now().to_rfc3339(),
+        },
+    }
+}
+
+/// Mattermost delivery with retry logic
+async fn send_mattermost_with_retry(
+    summary_markdown: &str,
+    config: &mattermost::MattermostConfig,
+) -> DeliveryConfirmation {
+    let mut last_error = None;
+    let backoff_delays = [1, 3, 9];
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        match mattermost::send_mattermost(summary_markdown, config).await {
+            Ok(()) => {
+                return DeliveryConfirmation {
+                    delivery_type: "mattermost".to_string(),
+                    success: true,
+                    message: "Posted to Mattermost".to_string(),
+                    timestamp: Local::now().to_rfc3339(),
+                };
+            }
+            Err(e) => {
+                let is_retryable = match &e {
+                    AppError::MattermostWebhookInvalid(msg) => is_retryable_webhook_error(msg),
+                    _ => false,
+                };
+
+                last_error = Some(e);
+                if !is_retryable || attempt == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+            }
+        }
+    }
+
+    DeliveryConfirmation {
+        delivery_type: "mattermost".to_string(),
+        success: false,
+        message: last_error.map(|e| e.to_string()).unwrap_or_default(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
+/// Generic webhook delivery with retry logic
+async fn send_webhook_with_retry(
+    summary: SummaryMarkdownInput<'_>,
+    config: &webhook::WebhookConfig,
+) -> DeliveryConfirmation {
+    let mut last_error = None;
+    let backoff_delays = [1, 3, 9];
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        match webhook::send_webhook(summary, config).await {
+            Ok(status) => {
+                return DeliveryConfirmation {
+                    delivery_type: "webhook".to_string(),
+                    success: true,
+                    message: format!("Webhook responded with HTTP {}", status),
+                    timestamp: Local::now().to_rfc3339(),
+                };
+            }
+            Err(e) => {
+                let is_retryable = match &e {
+                    AppError::WebhookDeliveryFailed(msg) => {
+                        msg.contains("timed out") || msg.starts_with("Retryable response")
+                    }
+                    _ => false,
+                };
+
+                last_error = Some(e);
+                if !is_retryable || attempt == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+            }
+        }
+    }
+
+    DeliveryConfirmation {
+        delivery_type: "webhook".to_string(),
+        success: false,
+        message: last_error.map(|e| e.to_string()).unwrap_or_default(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
+/// Notes vault delivery with retry logic
+async fn send_notes_with_retry(
+    summary: SummaryMarkdownInput<'_>,
+    config: &notes::NotesVaultConfig,
+) -> DeliveryConfirmation {
+    let mut last_error = None;
+    let backoff_delays = [1, 3, 9];
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        match notes::write_daily_note(summary, config) {
+            Ok(path) => {
+                return DeliveryConfirmation {
+                    delivery_type: "notes".to_string(),
+                    success: true,
+                    message: format!("Updated daily note {}", path.display()),
+                    timestamp: Local::now().to_rfc3339(),
+                };
+            }
+            Err(e) => {
+                let is_retryable = match &e {
+                    AppError::FileWriteError(msg) => {
+                        !msg.contains("Permission denied")
+                            && !msg.contains("Disk full")
+                            && !msg.contains("damaged")
+                            && !msg.contains("inside the vault")
+                    }
+                    _ => false,
+                };
+
+                last_error = Some(e);
+                if !is_retryable || attempt == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+            }
+        }
+    }
+
+    DeliveryConfirmation {
+        delivery_type: "notes".to_string(),
+        success: false,
+        message: last_error.map(|e| e.to_string()).unwrap_or_default(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
+/// Git journal delivery with retry logic; only pushes are retried
+async fn send_git_with_retry(
+    summary: SummaryMarkdownInput<'_>,
+    config: &git::GitConfig,
+) -> DeliveryConfirmation {
+    let mut last_error = None;
+    let backoff_delays = [1, 3, 9];
+
+    for (attempt, delay_secs) in backoff_delays.iter().enumerate() {
+        match git::commit_summary(summary, config).await {
+            Ok(outcome) => {
+                let mut message = match &outcome.commit {
+                    Some(commit) => format!("Committed {} as {}", outcome.path.display(), commit),
+                    None => format!("{} already up to date", outcome.path.display()),
+                };
+                if outcome.pushed {
+                    message.push_str(&format!(" and pushed to {}", config.remote));
+                }
+                return DeliveryConfirmation {
+                    delivery_type: "git".to_string(),
+                    success: true,
+                    message,
+                    timestamp: Local::now().to_rfc3339(),
+                };
+            }
+            Err(e) => {
+                // A retry finds the entry already committed and only pushes again
+                let is_retryable = match &e {
+                    AppError::GitDeliveryFailed(msg) => msg.starts_with("Push failed"),
+                    _ => false,
+                };
+
+                last_error = Some(e);
+                if !is_retryable || attempt == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(*delay_secs)).await;
+            }
+        }
+    }
+
+    DeliveryConfirmation {
+        delivery_type: "git".to_string(),
+        success: false,
+        message: last_error.map(|e| e.to_string()).unwrap_or_default(),
+        timestamp: Local::now().to_rfc3339(),
+    }
+}
+
+/// Jira comment/worklog delivery; requests are retried individually inside `jira`
+async fn send_jira_updates(
+    summary: SummaryMarkdownInput<'_>,
+    config: &jira::JiraDeliveryConfig,
+) -> DeliveryConfirmation {
+    match jira::send_jira(summary, config).await {
+        Ok((comments, worklogs)) => DeliveryConfirmation {
+            delivery_type: "jira".to_string(),
+            success: true,
+            message: format!("Saved {} comment(s) and {} worklog(s)", comments, worklogs),
+            timestamp: Local::now().to_rfc3339(),
+        },
+        Err(e) => DeliveryConfirmation {
+            delivery_type: "jira".to_string(),
+            success: false,
+            message: e.to_string(),
+            timestamp: Local::now().to_rfc3339(),
+        },
+    }
+}
+
+/// Chat webhooks are worth retrying on timeouts, rate limits and 5xx responses;
+/// invalid or deleted webhooks fail immediately
+fn is_retryable_webhook_error(msg: &str) -> bool {
+    msg.contains("timed out") || msg.contains("Rate limited") || msg.contains("Server error")
+}
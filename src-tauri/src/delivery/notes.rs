@@ -0,0 +1,417 @@
+use crate::error::AppError;
+use crate::markdown::{self, SummaryMarkdownInput};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const BLOCK_START: &str = "<!-- work-debrief:start -->";
+const BLOCK_END: &str = "<!-- work-debrief:end -->";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotesVaultConfig {
+    pub vault_path: String,
+    /// Relative note path with `{yyyy}`, `{mm}`, `{dd}` and `{date}` placeholders
+    #[serde(default = "default_filename_pattern")]
+    pub filename_pattern: String,
+    #[serde(default)]
+    pub mode: NotesMode,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default = "default_true")]
+    pub wiki_links: bool,
+    #[serde(default = "default_true")]
+    pub front_matter: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotesMode {
+    /// Replace the managed block on every delivery
+    #[default]
+    Replace,
+    /// Keep earlier deliveries and add the new one at the end of the block
+    Append,
+}
+
+fn default_filename_pattern() -> String {
+    "{date}.md".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Resolve the daily note path for a date, refusing to leave the vault
+pub fn note_path(config: &NotesVaultConfig, date: &str) -> Result<PathBuf, AppError> {
+    let relative = super::file::expand_path_template(&config.filename_pattern, date, "md")?;
+    Ok(Path::new(&config.vault_path).join(relative))
+}
+
+/// Turn ticket ids into `[[ID]]` wiki-links, including `[ID](url)` markdown links
+pub fn wiki_link_tickets(text: &str, ticket_ids: &[(&str, &str)]) -> String {
+    let mut out = text.to_string();
+
+    for (id, url) in ticket_ids {
+        out = out.replace(&format!("[{}]({})", id, url), &format!("[[{}]]", id));
+
+        let mut linked = String::with_capacity(out.len());
+        let mut last = 0;
+        for (index, _) in out.match_indices(id) {
+            let before = out[..index].chars().next_back();
+            let after = out[index + id.len()..].chars().next();
+            let is_bare = !before.is_some_and(|c| c.is_ascii_alphanumeric() || "-[/".contains(c))
+                && !after.is_some_and(|c| c.is_ascii_alphanumeric() || c == ']');
+            if is_bare {
+                linked.push_str(&out[last..index]);
+                linked.push_str(&format!("[[{}]]", id));
+                last = index + id.len();
+            }
+        }
+        linked.push_str(&out[last..]);
+        out = linked;
+    }
+
+    out
+}
+
+/// Render the block body: the summary without its top-level title, headings demoted
+fn render_section(summary: SummaryMarkdownInput<'_>, config: &NotesVaultConfig) -> String {
+    let rendered = markdown::render_summary_to_markdown(summary);
+    let body = rendered
+        .lines()
+        .filter(|line| !line.starts_with("# "))
+        .map(|line| {
+            if line.starts_with("## ") {
+                format!("#{}", line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let body = if config.wiki_links {
+        let ids: Vec<(&str, &str)> = summary
+            .tickets_closed
+            .iter()
+            .chain(summary.tickets_in_progress.iter())
+            .map(|t| (t.id.as_str(), t.url.as_str()))
+            .collect();
+        wiki_link_tickets(&body, &ids)
+    } else {
+        body
+    };
+
+    body.trim().to_string()
+}
+
+/// Insert or update the managed block, leaving everything else untouched
+pub fn upsert_block(existing: &str, section: &str, mode: NotesMode) -> Result<String, AppError> {
+    let start = existing.find(BLOCK_START);
+    let end = existing.find(BLOCK_END);
+
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => {
+            let inner_start = start + BLOCK_START.len();
+            let inner = match mode {
+                NotesMode::Replace => format!("\n## Work Debrief\n\n{}\n", section),
+                NotesMode::Append => {
+                    format!("{}\n{}\n", existing[inner_start..end].trim_end(), section)
+                }
+            };
+            Ok(format!(
+                "{}{}{}{}",
+                &existing[..inner_start],
+                inner,
+                BLOCK_END,
+                &existing[end + BLOCK_END.len()..]
+            ))
+        }
+        (None, None) => {
+            let mut note = existing.trim_end().to_string();
+            if !note.is_empty() {
+                note.push_str("\n\n");
+            }
+            note.push_str(&format!(
+                "{}\n## Work Debrief\n\n{}\n{}\n",
+                BLOCK_START, section, BLOCK_END
+            ));
+            Ok(note)
+        }
+        _ => Err(AppError::FileWriteError(
+            "Daily note has a damaged Work Debrief block (unmatched markers); fix it by hand"
+                .to_string(),
+        )),
+    }
+}
+
+/// Front matter lines and the text after the closing `---`, for notes with
+/// `\n` or `\r\n` line endings; None when the note has no front matter.
+/// An opening `---` that is never closed is an error rather than a second block.
+fn split_front_matter(note: &str) -> Result<Option<(Vec<String>, &str)>, AppError> {
+    let Some(mut rest) = note
+        .strip_prefix("---\n")
+        .or_else(|| note.strip_prefix("---\r\n"))
+    else {
+        return Ok(None);
+    };
+    let mut lines = Vec::new();
+    while !rest.is_empty() {
+        let (line, remainder) = rest.split_once('\n').unwrap_or((rest, ""));
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line == "---" {
+            return Ok(Some((lines, remainder)));
+        }
+        lines.push(line.to_string());
+        rest = remainder;
+    }
+    Err(AppError::FileWriteError(
+        "Daily note has unclosed front matter (no closing ---); fix it by hand".to_string(),
+    ))
+}
+
+/// Set our front matter keys and merge tags, keeping every other key, the
+/// note's line endings and the tag list's inline or block style as-is
+pub fn upsert_front_matter(
+    note: &str,
+    fields: &[(&str, String)],
+    tags: &[String],
+) -> Result<String, AppError> {
+    let newline = if note.contains("\r\n") { "\r\n" } else { "\n" };
+    let (mut lines, body) = split_front_matter(note)?.unwrap_or((Vec::new(), note));
+
+    for (key, value) in fields {
+        set_key(&mut lines, key, format!("{}: {}", key, value));
+    }
+
+    if !tags.is_empty() {
+        let (existing, range) = read_list(&lines, "tags");
+        let mut merged = existing;
+        for tag in tags {
+            if !merged.contains(tag) {
+                merged.push(tag.clone());
+            }
+        }
+
+        // Indentation of the first `- item` when the list is in block style
+        let block_indent = range.and_then(|(from, to)| {
+            lines[from + 1..to].iter().find_map(|l| {
+                let item = l.trim_start();
+                item.starts_with('-')
+                    .then(|| l[..l.len() - item.len()].to_string())
+            })
+        });
+        let list: Vec<String> = match block_indent {
+            Some(indent) => std::iter::once("tags:".to_string())
+                .chain(merged.iter().map(|tag| format!("{}- {}", indent, tag)))
+                .collect(),
+            None => vec![format!("tags: [{}]", merged.join(", "))],
+        };
+        match range {
+            Some((from, to)) => {
+                lines.splice(from..to, list);
+            }
+            None => lines.extend(list),
+        }
+    }
+
+    let mut updated = format!("---{}", newline);
+    for line in &lines {
+        updated.push_str(line);
+        updated.push_str(newline);
+    }
+    updated.push_str("---");
+    updated.push_str(newline);
+    updated.push_str(body);
+    Ok(updated)
+}
+
+/// Index range of a top-level key plus its indented continuation lines
+fn key_range(lines: &[String], key: &str) -> Option<(usize, usize)> {
+    let prefix = format!("{}:", key);
+    let from = lines.iter().position(|l| l.starts_with(&prefix))?;
+    let to = lines[from + 1..]
+        .iter()
+        .position(|l| !(l.starts_with(' ') || l.starts_with('\t') || l.starts_with('-')))
+        .map(|offset| from + 1 + offset)
+        .unwrap_or(lines.len());
+    Some((from, to))
+}
+
+fn set_key(lines: &mut Vec<String>, key: &str, line: String) {
+    match key_range(lines, key) {
+        Some((from, to)) => {
+            lines.splice(from..to, [line]);
+        }
+        None => lines.push(line),
+    }
+}
+
+/// Read an inline (`[a, b]`, `a`) or block (`- a`) YAML list
+fn read_list(lines: &[String], key: &str) -> (Vec<String>, Option<(usize, usize)>) {
+    let Some((from, to)) = key_range(lines, key) else {
+        return (Vec::new(), None);
+    };
+
+    let clean = |s: &str| s.trim().trim_matches(|c| c == '"' || c == '\'').to_string();
+    let inline = lines[from][key.len() + 1..].trim();
+    let mut items: Vec<String> = inline
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(clean)
+        .filter(|s| !s.is_empty())
+        .collect();
+    items.extend(
+        lines[from + 1..to]
+            .iter()
+            .filter_map(|l| l.trim().strip_prefix('-'))
+            .map(clean)
+            .filter(|s| !s.is_empty()),
+    );
+
+    (items, Some((from, to)))
+}
+
+/// Write the summary into the daily note inside the managed block
+pub fn write_daily_note(
+    summary: SummaryMarkdownInput<'_>,
+    config: &NotesVaultConfig,
+) -> Result<PathBuf, AppError> {
+    let path = note_path(config, summary.date)?;
+
+    let existing = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return Err(AppError::FileWriteError(format!(
+                "Cannot read '{}': {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+
+    let section = render_section(summary, config);
+    let mut note = upsert_block(&existing, &section, config.mode)?;
+
+    if config.front_matter {
+        let ticket_ids: Vec<&str> = summary
+            .tickets_closed
+            .iter()
+            .chain(summary.tickets_in_progress.iter())
+            .map(|t| t.id.as_str())
+            .collect();
+        let tags: Vec<String> = config
+            .tags
+            .iter()
+            .map(|t| t.trim().trim_start_matches('#').replace(' ', "-"))
+            .filter(|t| !t.is_empty())
+            .collect();
+        note = upsert_front_matter(
+            &note,
+            &[
+                ("debrief_tickets", format!("[{}]", ticket_ids.join(", "))),
+                ("debrief_focus_hours", format!("{:.1}", summary.focus_hours)),
+            ],
+            &tags,
+        )?;
+    }
+
+    super::file::write_atomic(&path, &note)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn note_path_expands_tokens_and_stays_in_vault() {
+        let mut config: NotesVaultConfig = serde_json::from_str(
+            r#"{"vaultPath":"/vault","filenamePattern":"{yyyy}/{mm}/{date}.md"}"#,
+        )
+        .expect("config should deserialize");
+        assert_eq!(
+            note_path(&config, "2026-02-14").expect("path should resolve"),
+            PathBuf::from("/vault/2026/02/2026-02-14.md")
+        );
+
+        // Letters that spell a token stay as they are
+        config.filename_pattern = "COMMON/DD-{dd}.md".to_string();
+        assert_eq!(
+            note_path(&config, "2026-02-14").expect("path should resolve"),
+            PathBuf::from("/vault/COMMON/DD-14.md")
+        );
+
+        config.filename_pattern = "../{date}.md".to_string();
+        assert!(note_path(&config, "2026-02-14").is_err());
+    }
+
+    #[test]
+    fn wiki_links_replace_links_and_bare_ids() {
+        let text = "- [PROJ-1](https://j/browse/PROJ-1) - Fix\nClosed PROJ-1, not PROJ-12.";
+        assert_eq!(
+            wiki_link_tickets(text, &[("PROJ-1", "https://j/browse/PROJ-1")]),
+            "- [[PROJ-1]] - Fix\nClosed [[PROJ-1]], not PROJ-12."
+        );
+    }
+
+    #[test]
+    fn upsert_block_preserves_surrounding_content() {
+        let note = "# Friday\n\nMy own notes.\n";
+        let first = upsert_block(note, "v1", NotesMode::Replace).expect("block should insert");
+        assert!(first.starts_with("# Friday\n\nMy own notes.\n\n<!-- work-debrief:start -->"));
+
+        let edited = format!("{}\nWritten after the block.\n", first);
+        let second = upsert_block(&edited, "v2", NotesMode::Replace).expect("block should update");
+        assert!(second.contains("v2") && !second.contains("v1"));
+        assert!(second.starts_with("# Friday\n\nMy own notes.\n\n"));
+        assert!(second.ends_with("<!-- work-debrief:end -->\n\nWritten after the block.\n"));
+
+        let appended = upsert_block(&second, "v3", NotesMode::Append).expect("block should append");
+        assert!(appended.contains("v2\nv3\n<!-- work-debrief:end -->"));
+
+        assert!(upsert_block("<!-- work-debrief:start -->", "x", NotesMode::Replace).is_err());
+    }
+
+    #[test]
+    fn front_matter_merges_tags_and_keeps_other_keys() {
+        let note = "---\ntitle: Friday\ntags:\n  - daily\ndebrief_focus_hours: 1.0\n---\nBody\n";
+        let updated = upsert_front_matter(
+            note,
+            &[("debrief_focus_hours", "3.5".to_string())],
+            &["work".to_string(), "daily".to_string()],
+        )
+        .expect("front matter should update");
+        assert_eq!(
+            updated,
+            "---\ntitle: Friday\ntags:\n  - daily\n  - work\ndebrief_focus_hours: 3.5\n---\nBody\n"
+        );
+
+        let inline = upsert_front_matter("---\ntags: [daily]\n---\n", &[], &["work".to_string()])
+            .expect("inline tags should update");
+        assert_eq!(inline, "---\ntags: [daily, work]\n---\n");
+
+        let windows = upsert_front_matter(
+            "---\r\n---\r\nBody\r\n",
+            &[("debrief_focus_hours", "2.0".to_string())],
+            &[],
+        )
+        .expect("CRLF front matter should update");
+        assert_eq!(
+            windows,
+            "---\r\ndebrief_focus_hours: 2.0\r\n---\r\nBody\r\n"
+        );
+
+        let fresh = upsert_front_matter("Body\n", &[("debrief_tickets", "[A-1]".to_string())], &[])
+            .expect("front matter should be added");
+        assert_eq!(fresh, "---\ndebrief_tickets: [A-1]\n---\nBody\n");
+
+        let unclosed =
+            upsert_front_matter("---\ntitle: Friday\nBody\n", &[], &["work".to_string()]);
+        assert!(matches!(unclosed, Err(AppError::FileWriteError(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::AppError;
+use crate::render::RendererKind;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -7,6 +8,15 @@ use std::time::Duration;
 #[serde(rename_all = "camelCase")]
 pub struct SlackConfig {
     pub webhook_url: String,
+    #[serde(default = "default_renderer")]
+    pub renderer: RendererKind,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+fn default_renderer() -> RendererKind {
+    RendererKind::SlackMrkdwn
 }
 
 #[derive(Debug, Serialize)]
@@ -19,7 +29,11 @@ pub async fn send_slack(summary_text: &str, config: &SlackConfig) -> Result<(),
     // Truncate if too long (Slack limit is ~4000 chars, we use 3000 to be safe)
     let mut text = summary_text.to_string();
     if text.len() > 3000 {
-        text.truncate(3000);
+        let mut cut = 3000;
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        text.truncate(cut);
         text.push_str("\n\n_Full summary sent via email_");
     }
 
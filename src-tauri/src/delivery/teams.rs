@@ -0,0 +1,179 @@
+use crate::error::AppError;
+use crate::render::RendererKind;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamsConfig {
+    pub webhook_url: String,
+    #[serde(default)]
+    pub renderer: RendererKind,
+    /// Body of the user template chosen by `templateId`, filled in at send time
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+/// Teams rejects payloads above ~28KB; leave room for the card envelope
+const MAX_CARD_TEXT_LEN: usize = 20000;
+
+/// Build an Adaptive Card message from rendered markdown.
+///
+/// Headings become bold TextBlocks, everything else is grouped into wrapped
+/// TextBlocks (Adaptive Cards render a subset of markdown, including links).
+pub fn build_adaptive_card(summary_markdown: &str) -> serde_json::Value {
+    let mut text = summary_markdown.to_string();
+    if text.len() > MAX_CARD_TEXT_LEN {
+        let mut cut = MAX_CARD_TEXT_LEN;
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        text.truncate(cut);
+        text.push_str("\n\n_Summary truncated_");
+    }
+
+    let mut body = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    let flush = |paragraph: &mut Vec<&str>, body: &mut Vec<serde_json::Value>| {
+        if !paragraph.is_empty() {
+            // Teams needs a blank line between lines to keep them apart
+            body.push(json!({
+                "type": "TextBlock",
+                "text": paragraph.join("\n\n"),
+                "wrap": true
+            }));
+            paragraph.clear();
+        }
+    };
+
+    for line in text.lines() {
+        if let Some(title) = line.strip_prefix("# ") {
+            flush(&mut paragraph, &mut body);
+            body.push(json!({
+                "type": "TextBlock",
+                "text": title,
+                "size": "Large",
+                "weight": "Bolder",
+                "wrap": true
+            }));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            flush(&mut paragraph, &mut body);
+            body.push(json!({
+                "type": "TextBlock",
+                "text": heading,
+                "size": "Medium",
+                "weight": "Bolder",
+                "spacing": "Medium",
+                "wrap": true
+            }));
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut body);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    flush(&mut paragraph, &mut body);
+
+    json!({
+        "type": "message",
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "contentUrl": null,
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": "1.4",
+                "msteams": { "width": "Full" },
+                "body": body
+            }
+        }]
+    })
+}
+
+/// Send summary to a Microsoft Teams incoming webhook as an Adaptive Card
+pub async fn send_teams(summary_markdown: &str, config: &TeamsConfig) -> Result<(), AppError> {
+    let payload = build_adaptive_card(summary_markdown);
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| AppError::TeamsWebhookInvalid(format!("HTTP client error: {}", e)))?;
+
+    let response = client
+        .post(&config.webhook_url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                AppError::TeamsWebhookInvalid("Request timed out".to_string())
+            } else {
+                AppError::TeamsWebhookInvalid(format!("Failed to send: {}", e))
+            }
+        })?;
+
+    let status = response.status();
+
+    if status == 401 || status == 403 || status == 404 {
+        return Err(AppError::TeamsWebhookInvalid(format!(
+            "Webhook expired or invalid ({})",
+            status.as_u16()
+        )));
+    } else if status == 429 {
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(5);
+
+        return Err(AppError::TeamsWebhookInvalid(format!(
+            "Rate limited - retry after {} seconds",
+            retry_after
+        )));
+    } else if status.is_server_error() {
+        return Err(AppError::TeamsWebhookInvalid(format!(
+            "Server error: HTTP {}",
+            status
+        )));
+    } else if !status.is_success() {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(AppError::TeamsWebhookInvalid(format!(
+            "HTTP {}: {}",
+            status, error_text
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_adaptive_card;
+
+    #[test]
+    fn adaptive_card_maps_headings_and_paragraphs() {
+        let card = build_adaptive_card(
+            "# Work Summary — 2026-02-14\n\n## Narrative\nShipped it.\n\n## Tickets Closed (1)\n- [PROJ-1](https://x/browse/PROJ-1) - Fix",
+        );
+
+        let body = card["attachments"][0]["content"]["body"]
+            .as_array()
+            .expect("card body should be an array");
+        assert_eq!(body.len(), 5);
+        assert_eq!(body[0]["text"], "Work Summary — 2026-02-14");
+        assert_eq!(body[0]["size"], "Large");
+        assert_eq!(body[1]["text"], "Narrative");
+        assert_eq!(body[2]["text"], "Shipped it.");
+        assert_eq!(
+            card["attachments"][0]["contentType"],
+            "application/vnd.microsoft.card.adaptive"
+        );
+    }
+}
@@ -0,0 +1,487 @@
+use crate::error::AppError;
+use crate::markdown::{self, SummaryMarkdownInput};
+use crate::render::RendererKind;
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const MASKED: &str = "••••••";
+const DEFAULT_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookConfig {
+    #[serde(default = "default_method")]
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<WebhookHeader>,
+    /// Body with `{{field}}` placeholders; empty sends the full summary JSON
+    #[serde(default)]
+    pub body_template: String,
+    #[serde(default = "default_content_type")]
+    pub content_type: String,
+    pub signing_secret: Option<String>,
+    pub signature_header: Option<String>,
+    /// Response statuses worth retrying; timeouts are always retried
+    #[serde(default = "default_retry_statuses")]
+    pub retry_on_status: Vec<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookHeader {
+    pub name: String,
+    pub value: String,
+    /// Secret header values live in the vault, never in the config JSON
+    #[serde(default)]
+    pub secret: bool,
+}
+
+/// Vault representation of a webhook's secret values
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WebhookSecrets {
+    signing_secret: Option<String>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+}
+
+fn default_method() -> String {
+    "POST".to_string()
+}
+
+fn default_content_type() -> String {
+    "application/json".to_string()
+}
+
+fn default_retry_statuses() -> Vec<u16> {
+    vec![408, 429, 500, 502, 503, 504]
+}
+
+/// Move secret values out of a webhook config, returning the vault JSON to store.
+/// Masked values keep whatever the vault already held.
+pub fn extract_secrets(config: &mut Map<String, Value>, stored: Option<&str>) -> String {
+    let previous: WebhookSecrets = stored
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    let mut secrets = WebhookSecrets::default();
+
+    if let Some(secret) = config.remove("signingSecret") {
+        match secret.as_str() {
+            Some(MASKED) => secrets.signing_secret = previous.signing_secret.clone(),
+            Some(value) if !value.is_empty() => secrets.signing_secret = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    if let Some(headers) = config.get_mut("headers").and_then(|h| h.as_array_mut()) {
+        for header in headers.iter_mut().filter_map(|h| h.as_object_mut()) {
+            if header.get("secret").and_then(|s| s.as_bool()) != Some(true) {
+                continue;
+            }
+            let name = header
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let value = header
+                .get("value")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+
+            let secret_value = if value == MASKED {
+                previous.headers.get(&name).cloned()
+            } else {
+                Some(value.to_string())
+            };
+            if let Some(secret_value) = secret_value {
+                secrets.headers.insert(name, secret_value);
+            }
+            header.insert("value".to_string(), Value::String(String::new()));
+        }
+    }
+
+    serde_json::to_string(&secrets).unwrap_or_default()
+}
+
+/// Put vault secrets back into a webhook config before sending
+pub fn inject_secrets(config: &mut Map<String, Value>, stored: &str) {
+    fill_secrets(config, stored, false);
+}
+
+/// Replace secret values with a mask for display
+pub fn mask_secrets(config: &mut Map<String, Value>, stored: &str) {
+    fill_secrets(config, stored, true);
+}
+
+fn fill_secrets(config: &mut Map<String, Value>, stored: &str, masked: bool) {
+    let secrets: WebhookSecrets = serde_json::from_str(stored).unwrap_or_default();
+    let shown = |value: &str| {
+        Value::String(if masked {
+            MASKED.to_string()
+        } else {
+            value.to_string()
+        })
+    };
+
+    if let Some(signing_secret) = &secrets.signing_secret {
+        config.insert("signingSecret".to_string(), shown(signing_secret));
+    }
+
+    if let Some(headers) = config.get_mut("headers").and_then(|h| h.as_array_mut()) {
+        for header in headers.iter_mut().filter_map(|h| h.as_object_mut()) {
+            if header.get("secret").and_then(|s| s.as_bool()) != Some(true) {
+                continue;
+            }
+            let name = header
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or_default();
+            if let Some(value) = secrets.headers.get(name) {
+                header.insert("value".to_string(), shown(value));
+            }
+        }
+    }
+}
+
+/// Summary fields available to body templates, as JSON values
+fn template_fields(summary: SummaryMarkdownInput<'_>) -> Map<String, Value> {
+    let markdown = markdown::render_summary_to_markdown(summary);
+    let html = markdown::render_summary_to_html(summary);
+    let tickets: Vec<_> = summary
+        .tickets_closed
+        .iter()
+        .chain(summary.tickets_in_progress.iter())
+        .collect();
+
+    let mut fields = Map::new();
+    fields.insert("date".into(), json!(summary.date));
+    fields.insert("narrative".into(), json!(summary.narrative));
+    fields.insert("markdown".into(), json!(markdown));
+    fields.insert("html".into(), json!(html));
+    fields.insert("text".into(), json!(RendererKind::Text.render(summary)));
+    fields.insert(
+        "mrkdwn".into(),
+        json!(RendererKind::SlackMrkdwn.render(summary)),
+    );
+    fields.insert(
+        "jira_wiki".into(),
+        json!(RendererKind::JiraWiki.render(summary)),
+    );
+    fields.insert("tickets_closed".into(), json!(summary.tickets_closed));
+    fields.insert(
+        "tickets_in_progress".into(),
+        json!(summary.tickets_in_progress),
+    );
+    fields.insert("tickets".into(), json!(tickets));
+    fields.insert("meetings".into(), json!(summary.meetings));
+    fields.insert(
+        "focus_hours".into(),
+        json!((summary.focus_hours as f64 * 10.0).round() / 10.0),
+    );
+    fields.insert("blockers".into(), json!(summary.blockers));
+    fields.insert(
+        "tomorrow_priorities".into(),
+        json!(summary.tomorrow_priorities),
+    );
+    fields.insert("notes".into(), json!(summary.manual_notes));
+    fields.insert("log".into(), json!(summary.log_entries));
+    fields
+}
+
+/// Full summary document sent when no body template is configured: the
+/// summary JSON export plus the rendered Markdown and HTML
+fn summary_json(summary: SummaryMarkdownInput<'_>, fields: &Map<String, Value>) -> Value {
+    let mut document = markdown::render_summary_to_json(summary);
+    if let Some(object) = document.as_object_mut() {
+        object.insert("markdown".into(), fields["markdown"].clone());
+        object.insert("html".into(), fields["html"].clone());
+    }
+    document
+}
+
+/// Render the request body from the template.
+///
+/// In JSON mode string values are JSON-escaped (write `"{{narrative}}"`) and
+/// lists are inserted as JSON arrays (write `{{tickets}}`). In text mode lists
+/// become comma-separated `ID: title` / `title (Nm)` / note text items. `{{summary}}`
+/// inserts the full summary document.
+pub fn render_body(
+    template: &str,
+    summary: SummaryMarkdownInput<'_>,
+    json_mode: bool,
+) -> Result<String, AppError> {
+    let fields = template_fields(summary);
+
+    if template.trim().is_empty() {
+        return Ok(if json_mode {
+            summary_json(summary, &fields).to_string()
+        } else {
+            fields["markdown"].as_str().unwrap_or_default().to_string()
+        });
+    }
+
+    let mut body = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        body.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            AppError::WebhookDeliveryFailed("Unclosed {{ in body template".to_string())
+        })?;
+        let name = after[..end].trim();
+
+        let value = if name == "summary" {
+            summary_json(summary, &fields)
+        } else {
+            fields.get(name).cloned().ok_or_else(|| {
+                AppError::WebhookDeliveryFailed(format!(
+                    "Unknown template field '{{{{{}}}}}'",
+                    name
+                ))
+            })?
+        };
+
+        body.push_str(&format_value(&value, json_mode));
+        rest = &after[end + 2..];
+    }
+    body.push_str(rest);
+
+    Ok(body)
+}
+
+fn format_value(value: &Value, json_mode: bool) -> String {
+    match value {
+        Value::String(s) if json_mode => {
+            // JSON-escape without the surrounding quotes
+            let quoted = Value::String(s.clone()).to_string();
+            quoted[1..quoted.len() - 1].to_string()
+        }
+        Value::String(s) => s.clone(),
+        Value::Array(_) | Value::Object(_) if json_mode => value.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| match (item.get("id"), item.get("title")) {
+                (Some(id), Some(title)) => format!(
+                    "{}: {}",
+                    id.as_str().unwrap_or_default(),
+                    title.as_str().unwrap_or_default()
+                ),
+                (None, Some(title)) => format!(
+                    "{} ({}m)",
+                    title.as_str().unwrap_or_default(),
+                    item.get("durationMinutes")
+                        .and_then(|d| d.as_i64())
+                        .unwrap_or(0)
+                ),
+                (None, None) if item.get("text").is_some() => {
+                    item["text"].as_str().unwrap_or_default().to_string()
+                }
+                _ => item.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// HMAC-SHA256 over `{timestamp}.{body}`, hex encoded
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> Result<String, AppError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|e| AppError::WebhookDeliveryFailed(format!("Invalid signing secret: {}", e)))?;
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Send summary to a generic HTTP webhook.
+/// Returns the response status code on success.
+pub async fn send_webhook(
+    summary: SummaryMarkdownInput<'_>,
+    config: &WebhookConfig,
+) -> Result<u16, AppError> {
+    let method = Method::from_bytes(config.method.to_uppercase().as_bytes()).map_err(|_| {
+        AppError::WebhookDeliveryFailed(format!("Invalid HTTP method: {}", config.method))
+    })?;
+
+    let json_mode = config.content_type.contains("json");
+    let body = render_body(&config.body_template, summary, json_mode)?;
+
+    if json_mode {
+        serde_json::from_str::<Value>(&body).map_err(|e| {
+            AppError::WebhookDeliveryFailed(format!("Body template is not valid JSON: {}", e))
+        })?;
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| AppError::WebhookDeliveryFailed(format!("HTTP client error: {}", e)))?;
+
+    let mut request = client
+        .request(method, &config.url)
+        .header("Content-Type", &config.content_type);
+
+    for header in &config.headers {
+        if !header.name.is_empty() {
+            request = request.header(header.name.as_str(), header.value.as_str());
+        }
+    }
+
+    if let Some(secret) = config.signing_secret.as_deref().filter(|s| !s.is_empty()) {
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign_payload(secret, timestamp, &body)?;
+        let signature_header = config
+            .signature_header
+            .as_deref()
+            .filter(|h| !h.is_empty())
+            .unwrap_or(DEFAULT_SIGNATURE_HEADER);
+        request = request
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(signature_header, format!("sha256={}", signature));
+    }
+
+    let response = request.body(body).send().await.map_err(|e| {
+        if e.is_timeout() {
+            AppError::WebhookDeliveryFailed("Request timed out".to_string())
+        } else {
+            AppError::WebhookDeliveryFailed(format!("Failed to send: {}", e))
+        }
+    })?;
+
+    let status = response.status();
+
+    if config.retry_on_status.contains(&status.as_u16()) {
+        return Err(AppError::WebhookDeliveryFailed(format!(
+            "Retryable response: HTTP {}",
+            status
+        )));
+    } else if !status.is_success() {
+        let error_text: String = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string())
+            .chars()
+            .take(200)
+            .collect();
+        return Err(AppError::WebhookDeliveryFailed(format!(
+            "HTTP {}: {}",
+            status, error_text
+        )));
+    }
+
+    Ok(status.as_u16())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::Ticket;
+
+    fn sample<'a>(tickets: &'a [Ticket]) -> SummaryMarkdownInput<'a> {
+        SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Fixed the \"login\" bug.\nShipped.",
+            tickets_closed: tickets,
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 3.25,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        }
+    }
+
+    #[test]
+    fn render_body_escapes_json_and_inserts_arrays() {
+        let tickets = vec![Ticket {
+            id: "PROJ-1".to_string(),
+            title: "Login".to_string(),
+            status: "Done".to_string(),
+            url: "https://jira/browse/PROJ-1".to_string(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }];
+        let body = render_body(
+            r#"{"day":"{{date}}","text":"{{ narrative }}","tickets":{{tickets}},"hours":{{focus_hours}}}"#,
+            sample(&tickets),
+            true,
+        )
+        .expect("template should render");
+
+        let parsed: Value = serde_json::from_str(&body).expect("body should be valid JSON");
+        assert_eq!(parsed["day"], "2026-02-14");
+        assert_eq!(parsed["text"], "Fixed the \"login\" bug.\nShipped.");
+        assert_eq!(parsed["tickets"][0]["id"], "PROJ-1");
+        assert_eq!(parsed["hours"], 3.3);
+
+        let text = render_body("{{date}}: {{tickets}}", sample(&tickets), false)
+            .expect("text template should render");
+        assert_eq!(text, "2026-02-14: PROJ-1: Login");
+
+        // No template sends the JSON export plus the rendered documents
+        let body = render_body("", sample(&tickets), true).expect("default body should render");
+        let mut parsed: Value = serde_json::from_str(&body).expect("body should be valid JSON");
+        let object = parsed.as_object_mut().expect("body should be an object");
+        assert!(object.remove("markdown").is_some_and(|m| m.is_string()));
+        assert!(object.remove("html").is_some_and(|h| h.is_string()));
+        assert_eq!(parsed, markdown::render_summary_to_json(sample(&tickets)));
+    }
+
+    #[test]
+    fn render_body_rejects_unknown_fields() {
+        assert!(render_body("{{nope}}", sample(&[]), true).is_err());
+        assert!(render_body("{{date", sample(&[]), true).is_err());
+    }
+
+    #[test]
+    fn sign_payload_matches_known_vector() {
+        // echo -n '1700000000.{}' | openssl dgst -sha256 -hmac secret
+        assert_eq!(
+            sign_payload("secret", 1_700_000_000, "{}").expect("signing should work"),
+            "b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+
+    #[test]
+    fn secrets_round_trip_through_vault_json() {
+        let mut config = json!({
+            "url": "https://example.com/hook",
+            "signingSecret": "s3cret",
+            "headers": [
+                { "name": "Authorization", "value": "Bearer abc", "secret": true },
+                { "name": "X-Team", "value": "core" }
+            ]
+        })
+        .as_object()
+        .cloned()
+        .expect("config is an object");
+
+        let stored = extract_secrets(&mut config, None);
+        assert!(config.get("signingSecret").is_none());
+        assert_eq!(config["headers"][0]["value"], "");
+        assert_eq!(config["headers"][1]["value"], "core");
+
+        let mut masked = config.clone();
+        mask_secrets(&mut masked, &stored);
+        assert_eq!(masked["signingSecret"], MASKED);
+        assert_eq!(masked["headers"][0]["value"], MASKED);
+
+        // Saving the masked form again keeps the stored secrets
+        let restored = extract_secrets(&mut masked, Some(&stored));
+        inject_secrets(&mut config, &restored);
+        assert_eq!(config["signingSecret"], "s3cret");
+        assert_eq!(config["headers"][0]["value"], "Bearer abc");
+    }
+}
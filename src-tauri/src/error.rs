@@ -16,18 +16,48 @@ pub enum AppError {
     #[error("Toggl Track error: {0}")]
     TogglError(String),
 
+    #[error("Clockify error: {0}")]
+    ClockifyError(String),
+
+    #[error("Harvest error: {0}")]
+    HarvestError(String),
+
     #[error("LLM generation timed out after {0}s. Try increasing timeout in Settings or using a faster model.")]
     LlmTimeout(u64),
 
     #[error("Ollama is not running. Start Ollama and try again, or disable LLM in Settings to use bullet-point summaries.")]
     LlmUnavailable(String),
 
+    #[error("LLM returned unusable output: {0}")]
+    LlmInvalidOutput(String),
+
     #[error("Email delivery failed: {0}")]
     SmtpAuthFailed(String),
 
     #[error("Slack delivery failed: {0}")]
     SlackWebhookInvalid(String),
 
+    #[error("Teams delivery failed: {0}")]
+    TeamsWebhookInvalid(String),
+
+    #[error("Discord delivery failed: {0}")]
+    DiscordWebhookInvalid(String),
+
+    #[error("Mattermost delivery failed: {0}")]
+    MattermostWebhookInvalid(String),
+
+    #[error("Jira delivery failed: {0}")]
+    JiraDeliveryFailed(String),
+
+    #[error("Git delivery failed: {0}")]
+    GitDeliveryFailed(String),
+
+    #[error("Webhook delivery failed: {0}")]
+    WebhookDeliveryFailed(String),
+
+    #[error("Template error: {0}")]
+    TemplateError(String),
+
     #[error("Database error: {0}")]
     DatabaseError(String),
 
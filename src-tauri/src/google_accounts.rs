@@ -0,0 +1,184 @@
+//! Connected Google accounts. Refresh tokens are kept in the vault as one JSON
+//! object keyed by account email; which calendars count toward meetings is
+//! stored per account in the `google_accounts` table.
+
+use crate::aggregation::calendar::{self, CalendarAccount, CalendarAccounts};
+use crate::error::AppError;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::BTreeMap;
+use tauri::AppHandle;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleAccount {
+    pub email: String,
+    /// Empty means the primary calendar only
+    pub calendar_ids: Vec<String>,
+}
+
+fn account_from_row(row: &sqlx::sqlite::SqliteRow) -> GoogleAccount {
+    GoogleAccount {
+        email: row.get("email"),
+        calendar_ids: serde_json::from_str(&row.get::<String, _>("calendar_ids"))
+            .unwrap_or_default(),
+    }
+}
+
+fn refresh_tokens(app: &AppHandle) -> Result<BTreeMap<String, String>, AppError> {
+    match crate::stronghold::get_secret(app, crate::stronghold::keys::GOOGLE_REFRESH_TOKENS)? {
+        Some(json) => serde_json::from_str(&json).map_err(|e| {
+            AppError::NotConfigured(format!("Stored Google tokens are unreadable: {}", e))
+        }),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+fn store_refresh_tokens(
+    app: &AppHandle,
+    tokens: &BTreeMap<String, String>,
+) -> Result<(), AppError> {
+    let json = serde_json::to_string(tokens)
+        .map_err(|e| AppError::NotConfigured(format!("Cannot serialize Google tokens: {}", e)))?;
+    crate::stronghold::store_secret(app, crate::stronghold::keys::GOOGLE_REFRESH_TOKENS, &json)
+}
+
+/// Exchange a refresh token for an access token using the app's OAuth client
+async fn refresh_access_token(refresh_token: String) -> Result<String, AppError> {
+    let client_id = std::env::var("GOOGLE_CLIENT_ID")
+        .unwrap_or_else(|_| "YOUR_CLIENT_ID.apps.googleusercontent.com".to_string());
+    let client_secret =
+        std::env::var("GOOGLE_CLIENT_SECRET").unwrap_or_else(|_| "YOUR_CLIENT_SECRET".to_string());
+    crate::oauth::GoogleOAuthClient::new(client_id, client_secret)?
+        .refresh_access_token(refresh_token)
+        .await
+}
+
+/// The email address of the account an access token belongs to
+pub async fn account_email(access_token: &str) -> Result<String, AppError> {
+    calendar::fetch_calendar_list(access_token)
+        .await?
+        .into_iter()
+        .find(|c| c.primary)
+        .map(|c| c.id)
+        .ok_or_else(|| {
+            AppError::CalendarError("Google account has no primary calendar".to_string())
+        })
+}
+
+/// Store a newly authorized account. Reconnecting an account replaces its
+/// token and keeps its calendar selection.
+pub async fn add(
+    db: &SqlitePool,
+    app: &AppHandle,
+    email: &str,
+    refresh_token: &str,
+) -> Result<(), AppError> {
+    let mut tokens = refresh_tokens(app)?;
+    tokens.insert(email.to_string(), refresh_token.to_string());
+    store_refresh_tokens(app, &tokens)?;
+
+    sqlx::query("INSERT INTO google_accounts (email) VALUES (?1) ON CONFLICT(email) DO NOTHING")
+        .bind(email)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Move a token saved before multiple accounts were supported into the
+/// per-account store. Left in place if the account can't be reached.
+async fn migrate_legacy_token(db: &SqlitePool, app: &AppHandle) -> Result<(), AppError> {
+    let Some(refresh_token) =
+        crate::stronghold::get_secret(app, crate::stronghold::keys::GOOGLE_REFRESH_TOKEN)?
+    else {
+        return Ok(());
+    };
+
+    let email = match refresh_access_token(refresh_token.clone()).await {
+        Ok(access_token) => account_email(&access_token).await,
+        Err(e) => Err(e),
+    };
+    match email {
+        Ok(email) => {
+            add(db, app, &email, &refresh_token).await?;
+            crate::stronghold::delete_secret(app, crate::stronghold::keys::GOOGLE_REFRESH_TOKEN)
+        }
+        Err(e) => {
+            eprintln!("[Google] Cannot migrate the stored account yet: {}", e);
+            Ok(())
+        }
+    }
+}
+
+pub async fn list(db: &SqlitePool, app: &AppHandle) -> Result<Vec<GoogleAccount>, AppError> {
+    migrate_legacy_token(db, app).await?;
+    let rows = sqlx::query("SELECT email, calendar_ids FROM google_accounts ORDER BY connected_at")
+        .fetch_all(db)
+        .await?;
+    Ok(rows.iter().map(account_from_row).collect())
+}
+
+/// A fresh access token for a connected account
+pub async fn access_token(app: &AppHandle, email: &str) -> Result<String, AppError> {
+    let refresh_token = refresh_tokens(app)?.remove(email).ok_or_else(|| {
+        AppError::NotConfigured(format!("Google account {} is not connected", email))
+    })?;
+    refresh_access_token(refresh_token).await
+}
+
+/// Every connected account with a fresh access token. Accounts whose token
+/// can't be refreshed are reported as skipped so the others still count.
+pub async fn calendar_accounts(
+    db: &SqlitePool,
+    app: &AppHandle,
+) -> Result<CalendarAccounts, AppError> {
+    let mut accounts = CalendarAccounts::default();
+    for account in list(db, app).await? {
+        match access_token(app, &account.email).await {
+            Ok(access_token) => accounts.usable.push(CalendarAccount {
+                email: account.email,
+                access_token,
+                calendar_ids: account.calendar_ids,
+            }),
+            Err(e) => {
+                let failure = format!("{}: {}", account.email, e);
+                eprintln!("[Google] Skipping {}", failure);
+                accounts.skipped.push(failure);
+            }
+        }
+    }
+    Ok(accounts)
+}
+
+pub async fn set_calendars(
+    db: &SqlitePool,
+    email: &str,
+    calendar_ids: &[String],
+) -> Result<(), AppError> {
+    let json = serde_json::to_string(calendar_ids)
+        .map_err(|e| AppError::DatabaseError(format!("Cannot serialize calendar IDs: {}", e)))?;
+    let result = sqlx::query("UPDATE google_accounts SET calendar_ids = ?1 WHERE email = ?2")
+        .bind(&json)
+        .bind(email)
+        .execute(db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotConfigured(format!(
+            "Google account {} is not connected",
+            email
+        )));
+    }
+    Ok(())
+}
+
+pub async fn remove(db: &SqlitePool, app: &AppHandle, email: &str) -> Result<(), AppError> {
+    let mut tokens = refresh_tokens(app)?;
+    if tokens.remove(email).is_some() {
+        store_refresh_tokens(app, &tokens)?;
+    }
+    sqlx::query("DELETE FROM google_accounts WHERE email = ?1")
+        .bind(email)
+        .execute(db)
+        .await?;
+    Ok(())
+}
@@ -1,20 +1,30 @@
 mod aggregation;
+mod capture;
 mod commands;
 mod db;
 mod delivery;
 mod error;
+mod google_accounts;
 mod llm;
 mod markdown;
 mod oauth;
+mod pdf;
+mod redaction;
+mod render;
 mod scheduler;
 mod stronghold;
+mod templates;
+mod timer;
 
 use chrono::Timelike;
-use sqlx::Row;
 use std::sync::Arc;
 use tauri::{Emitter, Manager};
 use tokio::sync::Mutex;
 
+/// Opens the quick-capture box from anywhere
+#[cfg(desktop)]
+const QUICK_CAPTURE_SHORTCUT: &str = "CommandOrControl+Shift+L";
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -52,6 +62,9 @@ pub fn run() {
                 }
             });
 
+            #[cfg(desktop)]
+            setup_quick_capture(app)?;
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -63,6 +76,20 @@ pub fn run() {
             commands::regenerate_narrative,
             commands::send_summary,
             commands::test_delivery,
+            commands::preview_jira_delivery,
+            commands::export_summary_pdf,
+            commands::list_summary_templates,
+            commands::save_summary_template,
+            commands::delete_summary_template,
+            commands::preview_summary_template,
+            commands::list_tones,
+            commands::save_tone,
+            commands::clone_tone,
+            commands::delete_tone,
+            commands::list_redaction_profiles,
+            commands::save_redaction_profile,
+            commands::delete_redaction_profile,
+            commands::preview_redaction,
             commands::get_settings,
             commands::save_settings,
             commands::get_delivery_configs,
@@ -72,44 +99,108 @@ pub fn run() {
             commands::delete_secret,
             commands::test_jira_connection,
             commands::test_toggl_connection,
+            commands::test_clockify_connection,
+            commands::test_harvest_connection,
+            commands::get_running_timer,
+            commands::start_timer,
+            commands::stop_timer,
+            commands::discard_idle_time,
+            commands::list_time_entries,
+            commands::save_time_entry,
+            commands::delete_time_entry,
+            commands::capture_note,
+            commands::list_log_entries,
+            commands::delete_log_entry,
+            commands::list_google_accounts,
+            commands::list_google_calendars,
+            commands::set_google_calendars,
+            commands::disconnect_google_account,
             oauth::start_google_oauth,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
 
+/// Tray menu and global shortcut that open the quick-capture box
+#[cfg(desktop)]
+fn setup_quick_capture(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
+    use tauri::menu::{Menu, MenuItem};
+    use tauri::tray::TrayIconBuilder;
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+    let capture = MenuItem::with_id(app, "capture_note", "Capture Note…", true, None::<&str>)?;
+    let show = MenuItem::with_id(app, "show", "Open WorkdayDebrief", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&capture, &show, &quit])?;
+
+    let mut tray = TrayIconBuilder::new()
+        .tooltip("WorkdayDebrief")
+        .menu(&menu)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "capture_note" => open_quick_capture(app),
+            "show" => show_main_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        });
+    if let Some(icon) = app.default_window_icon() {
+        tray = tray.icon(icon.clone());
+    }
+    tray.build(app)?;
+
+    // The shortcut may already be taken by another app; the tray entry still
+    // works then, so a failure is logged rather than stopping startup
+    if let Err(e) = app
+        .handle()
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+    {
+        eprintln!(
+            "[Capture] Failed to install the global shortcut plugin: {}",
+            e
+        );
+        return Ok(());
+    }
+    if let Err(e) =
+        app.global_shortcut()
+            .on_shortcut(QUICK_CAPTURE_SHORTCUT, |app, _shortcut, event| {
+                if event.state == ShortcutState::Pressed {
+                    open_quick_capture(app);
+                }
+            })
+    {
+        eprintln!(
+            "[Capture] Failed to register {}: {}",
+            QUICK_CAPTURE_SHORTCUT, e
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(desktop)]
+fn show_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Bring the window forward and ask the frontend to show the capture box
+#[cfg(desktop)]
+fn open_quick_capture(app: &tauri::AppHandle) {
+    show_main_window(app);
+    if let Err(e) = app.emit("quick-capture", ()) {
+        eprintln!("[Capture] Failed to emit quick-capture event: {}", e);
+    }
+}
+
 /// Load settings from database and start scheduler if configured
 async fn load_and_start_scheduler(
     app: &tauri::AppHandle,
     pool: &sqlx::SqlitePool,
     scheduler_state: Arc<Mutex<scheduler::SchedulerState>>,
 ) -> Result<commands::Settings, Box<dyn std::error::Error>> {
-    // Load settings from database
-    let row = sqlx::query(
-        r#"
-        SELECT scheduled_time, default_tone, enable_llm, llm_model, llm_temperature,
-               llm_timeout_secs, calendar_source, retention_days, jira_base_url,
-               jira_project_key, toggl_workspace_id
-        FROM settings
-        WHERE id = 1
-        "#,
-    )
-    .fetch_one(pool)
-    .await?;
-
-    let settings = commands::Settings {
-        scheduled_time: row.get("scheduled_time"),
-        default_tone: row.get("default_tone"),
-        enable_llm: row.get::<i32, _>("enable_llm") != 0,
-        llm_model: row.get("llm_model"),
-        llm_temperature: row.get("llm_temperature"),
-        llm_timeout_secs: row.get::<i32, _>("llm_timeout_secs") as u64,
-        calendar_source: row.get("calendar_source"),
-        retention_days: row.get("retention_days"),
-        jira_base_url: row.get("jira_base_url"),
-        jira_project_key: row.get("jira_project_key"),
-        toggl_workspace_id: row.get("toggl_workspace_id"),
-    };
+    let settings = commands::load_settings(pool).await?;
 
     // Start scheduler if time is configured (not default "17:00" or user has set it)
     if !settings.scheduled_time.is_empty() {
@@ -137,7 +228,19 @@ async fn check_missed_summary(
     use chrono::Local;
 
     let now = Local::now();
-    let today = now.format("%Y-%m-%d").to_string();
+    // The summary date follows the configured time zone and day start
+    let today = match aggregation::day::DayBoundary::new(
+        settings.time_zone.as_deref(),
+        &settings.day_start,
+    )
+    .and_then(|boundary| boundary.today())
+    {
+        Ok(day) => day.date.to_string(),
+        Err(e) => {
+            eprintln!("[Startup] Cannot work out today's summary date: {}", e);
+            return;
+        }
+    };
 
     // Check if summary already exists for today
     let existing = sqlx::query(
@@ -0,0 +1,468 @@
+//! Checks a generated narrative against the data it was generated from.
+//!
+//! Three kinds of claims are extracted: ticket keys (`PROJ-123`), counted
+//! quantities (`3 tickets`, `2 meetings`, `4.5 hours`, `45 minutes`) and
+//! quoted names (`the "Sprint Planning" meeting`). Anything not backed by
+//! `AggregatedData` or the user's own notes becomes a warning.
+
+use super::prompts::{estimate_tokens, CORRECTION_TOKENS};
+use super::sanitize::truncate_at_sentence;
+use crate::aggregation::AggregatedData;
+use crate::commands::SummaryInput;
+
+/// Uppercase tokens that look like ticket keys but are not (`UTF-8`, `SHA-256`)
+const NOT_TICKET_PREFIXES: &[&str] = &["UTF", "ISO", "SHA", "RFC", "CVE", "MD", "HTTP", "TLS"];
+
+/// Words between a number and its unit that make the claim about part of a list
+const PARTIAL_WORDS: &[&str] = &["more", "other", "additional", "remaining"];
+
+const NUMBER_WORDS: &[&str] = &[
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Tickets,
+    Meetings,
+    Hours,
+    Minutes,
+}
+
+impl Unit {
+    fn parse(word: &str) -> Option<Unit> {
+        match word {
+            "ticket" | "tickets" | "issue" | "issues" => Some(Unit::Tickets),
+            "meeting" | "meetings" | "call" | "calls" => Some(Unit::Meetings),
+            "hour" | "hours" | "hr" | "hrs" | "h" => Some(Unit::Hours),
+            "minute" | "minutes" | "min" | "mins" | "m" => Some(Unit::Minutes),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Unit::Tickets => "tickets",
+            Unit::Meetings => "meetings",
+            Unit::Hours => "hours",
+            Unit::Minutes => "minutes",
+        }
+    }
+}
+
+/// A `<number> <unit>` claim; `precise` is set when the number had decimals,
+/// `partial` for "14 more tickets" style claims about part of a list
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Quantity {
+    value: f32,
+    unit: Unit,
+    precise: bool,
+    partial: bool,
+}
+
+/// Ticket keys like `PROJ-123`, in order of appearance without duplicates
+pub fn ticket_keys(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut keys: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let starts_word = i == 0 || !chars[i - 1].is_alphanumeric();
+        if !(starts_word && chars[i].is_ascii_uppercase()) {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i;
+        while j < chars.len() && (chars[j].is_ascii_uppercase() || chars[j].is_ascii_digit()) {
+            j += 1;
+        }
+        let prefix_end = j;
+        if j < chars.len() && chars[j] == '-' {
+            j += 1;
+            let digits_start = j;
+            while j < chars.len() && chars[j].is_ascii_digit() {
+                j += 1;
+            }
+            let ends_word = j == chars.len() || !chars[j].is_alphanumeric();
+            let prefix: String = chars[i..prefix_end].iter().collect();
+            if j > digits_start
+                && prefix_end - i >= 2
+                && ends_word
+                && !NOT_TICKET_PREFIXES.contains(&prefix.as_str())
+            {
+                let key: String = chars[i..j].iter().collect();
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        i = j.max(i + 1);
+    }
+
+    keys
+}
+
+fn parse_number(word: &str) -> Option<(f32, bool)> {
+    if let Some(n) = NUMBER_WORDS.iter().position(|w| *w == word) {
+        return Some((n as f32, false));
+    }
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return None;
+    }
+    word.parse().ok().map(|n| (n, word.contains('.')))
+}
+
+/// `<number> <unit>` pairs, including compact forms like `3h` and `45-minute`
+fn quantities(text: &str) -> Vec<Quantity> {
+    let words: Vec<String> = text
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '/')
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric() && c != '.')
+                .trim_end_matches('.')
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    let mut found = Vec::new();
+    for (i, word) in words.iter().enumerate() {
+        if let Some((value, precise)) = parse_number(word) {
+            // Budgeted prompts summarise overflow as "and 14 more tickets"
+            let partial = words
+                .get(i + 1)
+                .is_some_and(|next| PARTIAL_WORDS.contains(&next.as_str()));
+            let unit_at = if partial { i + 2 } else { i + 1 };
+            if let Some(unit) = words.get(unit_at).and_then(|next| Unit::parse(next)) {
+                found.push(Quantity {
+                    value,
+                    unit,
+                    precise,
+                    partial,
+                });
+            }
+            continue;
+        }
+
+        // Compact "3h" / "1.5h" / "45m"
+        let split = word
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(word.len());
+        let (number, suffix) = word.split_at(split);
+        if let (Some((value, precise)), Some(unit)) = (parse_number(number), Unit::parse(suffix)) {
+            if matches!(unit, Unit::Hours | Unit::Minutes) {
+                found.push(Quantity {
+                    value,
+                    unit,
+                    precise,
+                    partial: false,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// Phrases in straight or curly double quotes
+fn quoted_phrases(text: &str) -> Vec<String> {
+    let mut phrases = Vec::new();
+    let mut current: Option<String> = None;
+
+    for c in text.chars() {
+        match (c, current.as_mut()) {
+            ('"' | '“' | '”', Some(phrase)) => {
+                let phrase = phrase.trim().to_string();
+                if !phrase.is_empty() {
+                    phrases.push(phrase);
+                }
+                current = None;
+            }
+            ('"' | '“', None) => current = Some(String::new()),
+            (_, Some(phrase)) => phrase.push(c),
+            _ => {}
+        }
+    }
+    phrases
+}
+
+/// Everything the user wrote: end-of-day fields and notes captured during the day
+fn user_text(user_fields: &SummaryInput, data: &AggregatedData) -> String {
+    [
+        &user_fields.blockers,
+        &user_fields.tomorrow_priorities,
+        &user_fields.manual_notes,
+    ]
+    .iter()
+    .filter_map(|field| field.as_deref())
+    .chain(data.log_entries.iter().map(|e| e.text.as_str()))
+    .chain(
+        data.log_entries
+            .iter()
+            .filter_map(|e| e.ticket_key.as_deref()),
+    )
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn quantity_matches(claim: Quantity, data: &AggregatedData) -> bool {
+    let tolerance = if claim.precise { 0.1 } else { 0.5 };
+    let close = |actual: f32| (claim.value - actual).abs() <= tolerance;
+    let meeting_minutes: Vec<f32> = data
+        .meetings
+        .iter()
+        .map(|m| m.duration_minutes as f32)
+        .collect();
+    let total_minutes: f32 = meeting_minutes.iter().sum();
+    let logged_minutes: Vec<f32> = data
+        .tickets_closed
+        .iter()
+        .chain(&data.tickets_in_progress)
+        .flat_map(|t| {
+            [
+                t.activity.time_logged_minutes,
+                t.activity.time_tracked_minutes,
+            ]
+        })
+        .filter(|m| *m > 0)
+        .map(|m| m as f32)
+        .collect();
+    let calendar = &data.calendar_breakdown;
+    let calendar_minutes: Vec<f32> = [
+        calendar.focus_block_minutes,
+        calendar.out_of_office_minutes,
+        calendar.longest_gap_minutes,
+    ]
+    .into_iter()
+    .filter(|m| *m > 0)
+    .map(|m| m as f32)
+    .collect();
+
+    match claim.unit {
+        Unit::Tickets if claim.partial => {
+            claim.value <= (data.tickets_closed.len() + data.tickets_in_progress.len()) as f32
+        }
+        Unit::Meetings if claim.partial => claim.value <= data.meetings.len() as f32,
+        Unit::Tickets => {
+            let closed = data.tickets_closed.len() as f32;
+            let in_progress = data.tickets_in_progress.len() as f32;
+            [closed, in_progress, closed + in_progress]
+                .into_iter()
+                .any(close)
+        }
+        Unit::Meetings => close(data.meetings.len() as f32),
+        Unit::Hours => std::iter::once(data.focus_hours)
+            .chain(std::iter::once(total_minutes / 60.0))
+            .chain(meeting_minutes.iter().map(|m| m / 60.0))
+            .chain(logged_minutes.iter().map(|m| m / 60.0))
+            .chain(calendar_minutes.iter().map(|m| m / 60.0))
+            .any(close),
+        Unit::Minutes => {
+            std::iter::once(total_minutes)
+                .chain(meeting_minutes.iter().copied())
+                .chain(logged_minutes.iter().copied())
+                .chain(calendar_minutes.iter().copied())
+                .any(|actual| (claim.value - actual).abs() <= 5.0)
+                || (claim.value - data.focus_hours * 60.0).abs() <= 5.0
+        }
+    }
+}
+
+/// Problems found in `narrative`; empty when every checked claim is backed by
+/// the aggregated data or the user's own notes
+pub fn verify_narrative(
+    narrative: &str,
+    data: &AggregatedData,
+    user_fields: &SummaryInput,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let notes = user_text(user_fields, data);
+
+    let known_keys: Vec<&str> = data
+        .tickets_closed
+        .iter()
+        .chain(data.tickets_in_progress.iter())
+        .map(|t| t.id.as_str())
+        .collect();
+    let noted_keys = ticket_keys(&notes);
+    for key in ticket_keys(narrative) {
+        if !known_keys.contains(&key.as_str()) && !noted_keys.contains(&key) {
+            warnings.push(format!(
+                "Mentions ticket {} which is not in today's data",
+                key
+            ));
+        }
+    }
+
+    let noted_quantities = quantities(&notes);
+    for claim in quantities(narrative) {
+        if !quantity_matches(claim, data) && !noted_quantities.contains(&claim) {
+            warnings.push(format!(
+                "Says {} {} which does not match today's data",
+                claim.value,
+                claim.unit.label()
+            ));
+        }
+    }
+
+    let known_names: Vec<String> = data
+        .meetings
+        .iter()
+        .map(|m| m.title.to_lowercase())
+        .chain(
+            data.tickets_closed
+                .iter()
+                .chain(data.tickets_in_progress.iter())
+                .map(|t| t.title.to_lowercase()),
+        )
+        .chain(std::iter::once(notes.to_lowercase()))
+        .collect();
+    for phrase in quoted_phrases(narrative) {
+        let lower = phrase.to_lowercase();
+        if !known_names
+            .iter()
+            .any(|name| name.contains(&lower) || (!name.is_empty() && lower.contains(name)))
+        {
+            warnings.push(format!(
+                "Mentions \"{}\" which is not a meeting or ticket from today",
+                phrase
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Instructions appended to the prompt when retrying after a failed check.
+/// They stay within `CORRECTION_TOKENS`: the error list gets up to half and
+/// the previous attempt is shortened to fit the rest.
+pub fn corrective_prompt(prompt: &str, previous: &str, warnings: &[String]) -> String {
+    let mut errors: Vec<String> = Vec::new();
+    for warning in warnings {
+        let line = format!("- {}", warning);
+        let used: usize = errors.iter().map(|e| estimate_tokens(e) + 1).sum();
+        if used + estimate_tokens(&line) > CORRECTION_TOKENS / 2 {
+            break;
+        }
+        errors.push(line);
+    }
+
+    let correction = |previous: &str| {
+        format!(
+            "\n\nA previous attempt produced this summary:\n{}\n\nIt contained these errors:\n{}\n\n\
+             Rewrite the summary. Only mention ticket IDs, counts, durations and meeting names \
+             that appear in the input data above.",
+            previous,
+            errors.join("\n")
+        )
+    };
+    let room = CORRECTION_TOKENS.saturating_sub(estimate_tokens(&correction("")));
+    let previous = truncate_at_sentence(previous, room * 4);
+
+    format!("{}{}", prompt, correction(&previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::{DataSourcesStatus, Meeting, SourceStatusDetail, Ticket};
+
+    fn ticket(id: &str, title: &str) -> Ticket {
+        Ticket {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: "Done".to_string(),
+            url: String::new(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }
+    }
+
+    fn sample_data() -> AggregatedData {
+        AggregatedData {
+            tickets_closed: vec![ticket("PROJ-12", "Fix login"), ticket("PROJ-15", "Add SSO")],
+            tickets_in_progress: vec![ticket("PROJ-20", "Billing export")],
+            meetings: vec![Meeting {
+                title: "Sprint Planning".to_string(),
+                start: "2026-02-14T09:00:00Z".to_string(),
+                end: "2026-02-14T09:45:00Z".to_string(),
+                duration_minutes: 45,
+                visibility: None,
+                calendar_id: None,
+                ..Default::default()
+            }],
+            focus_hours: 4.5,
+            time_breakdown: Default::default(),
+            log_entries: Vec::new(),
+            calendar_breakdown: Default::default(),
+            data_sources_status: DataSourcesStatus {
+                jira: SourceStatusDetail::NotConfigured,
+                calendar: SourceStatusDetail::NotConfigured,
+                toggl: SourceStatusDetail::NotConfigured,
+            },
+        }
+    }
+
+    fn no_notes() -> SummaryInput {
+        SummaryInput {
+            blockers: None,
+            tomorrow_priorities: None,
+            manual_notes: None,
+            narrative: None,
+            tone: None,
+        }
+    }
+
+    #[test]
+    fn accepts_a_faithful_narrative() {
+        let narrative = "Closed two tickets (PROJ-12, PROJ-15) and kept PROJ-20 moving. \
+            The 45-minute \"Sprint Planning\" meeting was the only call, leaving 4.5 hours \
+            of focus time. Output is UTF-8 encoded.";
+        assert!(verify_narrative(narrative, &sample_data(), &no_notes()).is_empty());
+    }
+
+    #[test]
+    fn flags_invented_keys_counts_and_meetings() {
+        let narrative = "Closed 5 tickets including PROJ-99, attended 3 meetings \
+            such as \"Architecture Review\" and logged 7h of focus.";
+        let warnings = verify_narrative(narrative, &sample_data(), &no_notes());
+        assert_eq!(warnings.len(), 5, "{:?}", warnings);
+        assert!(warnings[0].contains("PROJ-99"));
+        assert!(warnings.iter().any(|w| w.contains("Architecture Review")));
+    }
+
+    #[test]
+    fn user_notes_count_as_evidence() {
+        let notes = SummaryInput {
+            blockers: Some("Waiting on OPS-7 for 2 hours".to_string()),
+            ..no_notes()
+        };
+        let narrative = "Blocked on OPS-7 for 2 hours.";
+        assert!(verify_narrative(narrative, &sample_data(), &notes).is_empty());
+
+        // Overflow summaries from a budgeted prompt are partial counts
+        let narrative = "Closed PROJ-12 and two other tickets.";
+        assert!(verify_narrative(narrative, &sample_data(), &no_notes()).is_empty());
+        let narrative = "Closed PROJ-12 and 9 more tickets.";
+        assert_eq!(
+            verify_narrative(narrative, &sample_data(), &no_notes()).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn corrective_prompt_fits_the_suffix_reserve() {
+        let previous = "Closed PROJ-99 after a long day. ".repeat(200);
+        let warnings: Vec<String> = (0..100)
+            .map(|i| format!("PROJ-{} is not one of today's tickets", i))
+            .collect();
+
+        let prompt = corrective_prompt("PROMPT", &previous, &warnings);
+        assert!(prompt.starts_with("PROMPT\n\nA previous attempt"));
+        assert!(prompt.contains("- PROJ-0 is not"));
+        assert!(estimate_tokens(&prompt["PROMPT".len()..]) <= CORRECTION_TOKENS);
+
+        let structured = crate::llm::structured::structured_prompt("");
+        assert!(estimate_tokens(&structured) <= crate::llm::prompts::STRUCTURED_SUFFIX_TOKENS);
+    }
+}
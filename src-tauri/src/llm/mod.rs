@@ -1,49 +1,192 @@
+pub mod factcheck;
 pub mod prompts;
+pub mod sanitize;
+pub mod structured;
+pub mod tones;
 
 use crate::aggregation::AggregatedData;
 use crate::commands::SummaryInput;
 use crate::error::AppError;
 use ollama_rs::generation::completion::request::GenerationRequest;
+use ollama_rs::generation::parameters::{FormatType, JsonStructure};
 use ollama_rs::models::ModelOptions;
 use ollama_rs::Ollama;
 use std::time::Duration;
+use structured::{NarrativeFormat, StructuredNarrative};
 
-/// Generate narrative summary using Ollama LLM
-pub async fn generate_narrative(
+/// Model settings for one narrative generation
+#[derive(Debug, Clone)]
+pub struct LlmParams {
+    pub model: String,
+    pub temperature: f32,
+    pub timeout_secs: u64,
+    /// Context window in tokens; the prompt is budgeted to fit
+    pub num_ctx: u32,
+    pub format: NarrativeFormat,
+}
+
+/// Generate the structured sections. Output that is not valid JSON for the
+/// schema gets one retry before the error is returned.
+pub async fn generate_structured_narrative(
+    prompt: &str,
+    params: &LlmParams,
+) -> Result<StructuredNarrative, AppError> {
+    let prompt = structured::structured_prompt(prompt);
+    let mut last_error = None;
+
+    for attempt in 1..=2 {
+        let format =
+            FormatType::StructuredJson(Box::new(JsonStructure::new::<StructuredNarrative>()));
+        let raw = request(prompt.clone(), Some(format), params).await?;
+        match structured::parse_structured(&raw) {
+            Ok(narrative) => return Ok(narrative),
+            Err(e) => {
+                eprintln!("Structured narrative attempt {} unusable: {}", attempt, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| AppError::LlmInvalidOutput("no answer".to_string())))
+}
+
+/// A narrative plus the fact-check problems that could not be corrected
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckedNarrative {
+    pub narrative: String,
+    /// Parsed sections when the narrative was generated in structured mode
+    pub structured: Option<StructuredNarrative>,
+    pub warnings: Vec<String>,
+}
+
+/// One generation in either format; structured output is rendered to markdown
+async fn draft(
+    prompt: &str,
+    params: &LlmParams,
+) -> Result<(String, Option<StructuredNarrative>), AppError> {
+    match params.format {
+        NarrativeFormat::Prose => complete(prompt.to_string(), params)
+            .await
+            .map(|narrative| (narrative, None)),
+        NarrativeFormat::Structured => generate_structured_narrative(prompt, params)
+            .await
+            .map(|sections| (sections.to_markdown(), Some(sections))),
+    }
+}
+
+/// Generate a narrative and verify it against the aggregated data. A narrative
+/// with invented tickets, counts or meetings gets one corrective retry; if that
+/// still fails the bullet fallback is used and the problems are returned as
+/// warnings. LLM errors are returned as-is so callers can fall back themselves.
+pub async fn generate_checked_narrative(
     data: &AggregatedData,
     user_fields: &SummaryInput,
-    tone: &str,
-    model: &str,
-    temperature: f32,
-    timeout_secs: u64,
-) -> Result<String, AppError> {
-    // Build the prompt
-    let prompt = prompts::build_prompt(data, user_fields, tone);
+    prompt_template: &str,
+    params: &LlmParams,
+) -> Result<CheckedNarrative, AppError> {
+    let budget = prompts::prompt_budget(params.num_ctx);
+    let prompt = prompts::build_prompt(data, user_fields, prompt_template, budget);
+    let (narrative, structured) = draft(&prompt, params).await?;
 
+    let warnings = factcheck::verify_narrative(&narrative, data, user_fields);
+    if warnings.is_empty() {
+        return Ok(CheckedNarrative {
+            narrative,
+            structured,
+            warnings,
+        });
+    }
+
+    eprintln!(
+        "Narrative failed fact-check ({}), retrying with corrections",
+        warnings.join("; ")
+    );
+    let retry_prompt = factcheck::corrective_prompt(&prompt, &narrative, &warnings);
+    let warnings = match draft(&retry_prompt, params).await {
+        Ok((retried, structured)) => {
+            let warnings = factcheck::verify_narrative(&retried, data, user_fields);
+            if warnings.is_empty() {
+                return Ok(CheckedNarrative {
+                    narrative: retried,
+                    structured,
+                    warnings,
+                });
+            }
+            warnings
+        }
+        Err(e) => {
+            eprintln!("Corrective retry failed: {}", e);
+            warnings
+        }
+    };
+
+    let mut notes = vec!["LLM narrative failed fact-checking; using bullet summary".to_string()];
+    notes.extend(warnings);
+    Ok(CheckedNarrative {
+        narrative: generate_bullet_fallback(data, user_fields),
+        structured: None,
+        warnings: notes,
+    })
+}
+
+/// Run a prompt through Ollama and clean up the answer
+async fn complete(prompt: String, params: &LlmParams) -> Result<String, AppError> {
+    let raw = request(prompt, None, params).await?;
+    let narrative = sanitize::clean_narrative(&raw, sanitize::MAX_NARRATIVE_CHARS);
+    if narrative.is_empty() {
+        return Err(AppError::LlmInvalidOutput(
+            "nothing left after removing reasoning and boilerplate".to_string(),
+        ));
+    }
+    Ok(narrative)
+}
+
+/// Raw Ollama call, optionally constrained to JSON or a JSON schema
+async fn request(
+    prompt: String,
+    format: Option<FormatType>,
+    params: &LlmParams,
+) -> Result<String, AppError> {
     // Create Ollama client (localhost:11434)
     let ollama = Ollama::default();
 
     // Build generation options
     let options = ModelOptions::default()
-        .temperature(temperature)
-        .num_ctx(4096); // Context window
+        .temperature(params.temperature)
+        .num_ctx(params.num_ctx as u64);
 
-    // Build generation request
-    let request = GenerationRequest::new(model.to_string(), prompt).options(options);
+    let build = |disable_thinking: bool| {
+        let mut request =
+            GenerationRequest::new(params.model.clone(), prompt.clone()).options(options.clone());
+        if let Some(format) = format.clone() {
+            request = request.format(format);
+        }
+        if disable_thinking {
+            request.think(false)
+        } else {
+            request
+        }
+    };
 
-    // Execute with timeout
-    let result = tokio::time::timeout(Duration::from_secs(timeout_secs), async {
-        ollama
-            .generate(request)
-            .await
-            .map_err(|e| AppError::LlmUnavailable(e.to_string()))
+    // Reasoning models are asked not to think so the answer isn't buried in
+    // (or replaced by) a chain of thought.
+    let result = tokio::time::timeout(Duration::from_secs(params.timeout_secs), async {
+        match ollama.generate(build(true)).await {
+            // Models without thinking support reject the flag; ask again without it
+            Err(e) if e.to_string().contains("does not support thinking") => {
+                ollama.generate(build(false)).await
+            }
+            other => other,
+        }
+        .map_err(|e| AppError::LlmUnavailable(e.to_string()))
     })
     .await;
 
     match result {
-        Ok(Ok(response)) => Ok(response.response.trim().to_string()),
+        Ok(Ok(response)) => Ok(response.response),
         Ok(Err(e)) => Err(e),
-        Err(_) => Err(AppError::LlmTimeout(timeout_secs)),
+        Err(_) => Err(AppError::LlmTimeout(params.timeout_secs)),
     }
 }
 
@@ -79,14 +222,19 @@ pub fn generate_bullet_fallback(data: &AggregatedData, user_fields: &SummaryInpu
 
     // Meetings
     if !data.meetings.is_empty() {
-        let total_meeting_minutes: i32 = data.meetings.iter().map(|m| m.duration_minutes).sum();
+        // overlapping meetings count once when the calendar was read
+        let total_meeting_minutes = if data.calendar_breakdown.collaboration_minutes > 0 {
+            data.calendar_breakdown.collaboration_minutes as i32
+        } else {
+            data.meetings.iter().map(|m| m.duration_minutes).sum()
+        };
         lines.push(format!(
             "**Meetings ({}, {}m total):** {}",
             data.meetings.len(),
             total_meeting_minutes,
             data.meetings
                 .iter()
-                .map(|m| format!("{} ({}m)", m.title, m.duration_minutes))
+                .map(|m| format!("{} ({})", m.title, m.length_label()))
                 .collect::<Vec<_>>()
                 .join(", ")
         ));
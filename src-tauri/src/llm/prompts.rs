@@ -1,62 +1,318 @@
 use crate::aggregation::AggregatedData;
 use crate::commands::SummaryInput;
 
-/// Build a prompt for the LLM based on aggregated data and tone
-pub fn build_prompt(data: &AggregatedData, user_fields: &SummaryInput, tone: &str) -> String {
-    let template = get_template(tone);
-
-    // Build context from aggregated data
-    let tickets_closed_count = data.tickets_closed.len();
-    let tickets_closed_list = data
-        .tickets_closed
-        .iter()
-        .map(|t| format!("{}: {}", t.id, t.title))
-        .collect::<Vec<_>>()
-        .join(", ");
+/// Placeholders a tone template may use
+pub const PLACEHOLDERS: &[&str] = &[
+    "tickets_closed_count",
+    "tickets_closed_list",
+    "tickets_in_progress_count",
+    "tickets_in_progress_list",
+    "meetings_count",
+    "meetings_list",
+    "focus_hours",
+    "calendar_breakdown",
+    "blockers",
+    "tomorrow_priorities",
+    "activity_log",
+];
+
+/// Tones shipped with the app; they cannot be edited or deleted
+pub const BUILTIN_TONES: &[&str] = &["professional", "casual", "detailed"];
+
+/// Tokens kept free for the model's answer
+pub const RESPONSE_RESERVE_TOKENS: usize = 1024;
+
+/// Tokens kept free for instructions appended after the tone prompt
+/// (structured mode, the fact-check corrective retry)
+pub const SUFFIX_RESERVE_TOKENS: usize = 768;
+
+/// Part of the suffix reserve taken by the structured-mode instructions
+pub const STRUCTURED_SUFFIX_TOKENS: usize = 96;
+
+/// Room for the fact-check correction, which can be combined with the
+/// structured-mode instructions on a retry
+pub const CORRECTION_TOKENS: usize = SUFFIX_RESERVE_TOKENS - STRUCTURED_SUFFIX_TOKENS;
+
+/// Smallest prompt budget, whatever the context size
+const MIN_PROMPT_TOKENS: usize = 256;
+
+/// Room kept for the "and 14 more tickets in project X" lines
+const OVERFLOW_RESERVE_TOKENS: usize = 32;
+
+/// Rough token count: about four characters per token for English text.
+/// Deliberately conservative so the estimate errs on the side of fitting.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Tokens the tone prompt may use for a model context of `num_ctx` tokens
+pub fn prompt_budget(num_ctx: u32) -> usize {
+    (num_ctx as usize)
+        .saturating_sub(RESPONSE_RESERVE_TOKENS + SUFFIX_RESERVE_TOKENS)
+        .max(MIN_PROMPT_TOKENS)
+}
+
+/// Which list placeholder an item belongs to
+#[derive(Clone, Copy)]
+enum List {
+    Closed,
+    InProgress,
+    Meetings,
+}
+
+/// Project part of a ticket key, `PROJ` for `PROJ-123`
+fn project_of(ticket_id: &str) -> &str {
+    match ticket_id.rsplit_once('-') {
+        Some((project, number)) if number.chars().all(|c| c.is_ascii_digit()) => project,
+        _ => "other",
+    }
+}
+
+/// "and 14 more tickets in project PROJ" for the tickets that did not fit
+fn ticket_overflow(ids: &[&str], listed_any: bool) -> String {
+    let mut groups: Vec<(&str, usize)> = Vec::new();
+    for id in ids {
+        let project = project_of(id);
+        match groups.iter_mut().find(|(p, _)| *p == project) {
+            Some((_, count)) => *count += 1,
+            None => groups.push((project, 1)),
+        }
+    }
+    groups.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let more = if listed_any { "more " } else { "" };
+    let noun = if ids.len() == 1 { "ticket" } else { "tickets" };
+    let lead = if listed_any { "and " } else { "" };
+    match groups.as_slice() {
+        [(project, count)] => format!("{}{} {}{} in project {}", lead, count, more, noun, project),
+        _ => {
+            let mut parts: Vec<String> = groups
+                .iter()
+                .take(3)
+                .map(|(project, count)| format!("{} in project {}", count, project))
+                .collect();
+            let rest: usize = groups.iter().skip(3).map(|(_, count)| count).sum();
+            if rest > 0 {
+                parts.push(format!("{} in other projects", rest));
+            }
+            format!(
+                "{}{} {}{} ({})",
+                lead,
+                ids.len(),
+                more,
+                noun,
+                parts.join(", ")
+            )
+        }
+    }
+}
+
+/// Choose which tickets and meetings are listed by name. Closed tickets come
+/// first, then meetings from longest to shortest, then in-progress tickets;
+/// an item is listed if it still fits in `budget` tokens.
+fn select_items(
+    data: &AggregatedData,
+    template: &str,
+    budget: usize,
+) -> (Vec<bool>, Vec<bool>, Vec<bool>) {
+    let mut selected = (
+        vec![false; data.tickets_closed.len()],
+        vec![false; data.tickets_in_progress.len()],
+        vec![false; data.meetings.len()],
+    );
+
+    // A placeholder used twice costs twice; one that is never used costs nothing
+    let uses = |placeholder: &str| template.matches(placeholder).count();
+    let closed_uses = uses("{{tickets_closed_list}}");
+    let in_progress_uses = uses("{{tickets_in_progress_list}}");
+    let meeting_uses = uses("{{meetings_list}}");
+
+    let mut by_length: Vec<usize> = (0..data.meetings.len()).collect();
+    by_length.sort_by(|a, b| {
+        data.meetings[*b]
+            .duration_minutes
+            .cmp(&data.meetings[*a].duration_minutes)
+    });
+
+    let candidates = (0..data.tickets_closed.len())
+        .map(|i| (List::Closed, i))
+        .chain(by_length.into_iter().map(|i| (List::Meetings, i)))
+        .chain((0..data.tickets_in_progress.len()).map(|i| (List::InProgress, i)));
+
+    let mut remaining = budget;
+    for (list, i) in candidates {
+        let (text, uses) = match list {
+            List::Closed => (ticket_entry(&data.tickets_closed[i]), closed_uses),
+            List::InProgress => (ticket_entry(&data.tickets_in_progress[i]), in_progress_uses),
+            List::Meetings => (meeting_entry(&data.meetings[i]), meeting_uses),
+        };
+        // +1 for the ", " separator
+        let cost = (estimate_tokens(&text) + 1) * uses;
+        if cost <= remaining {
+            remaining -= cost;
+            match list {
+                List::Closed => selected.0[i] = true,
+                List::InProgress => selected.1[i] = true,
+                List::Meetings => selected.2[i] = true,
+            }
+        }
+    }
+
+    selected
+}
+
+fn ticket_entry(ticket: &crate::aggregation::Ticket) -> String {
+    let activity = ticket.activity.describe();
+    if activity.is_empty() {
+        format!("{}: {}", ticket.id, ticket.title)
+    } else {
+        format!("{}: {} [{}]", ticket.id, ticket.title, activity)
+    }
+}
+
+fn meeting_entry(meeting: &crate::aggregation::Meeting) -> String {
+    format!("{} ({})", meeting.title, meeting.length_label())
+}
 
-    let tickets_in_progress_count = data.tickets_in_progress.len();
-    let tickets_in_progress_list = data
-        .tickets_in_progress
+fn ticket_list(tickets: &[crate::aggregation::Ticket], selected: &[bool]) -> String {
+    let mut parts: Vec<String> = tickets
         .iter()
-        .map(|t| format!("{}: {}", t.id, t.title))
-        .collect::<Vec<_>>()
-        .join(", ");
+        .zip(selected)
+        .filter(|(_, keep)| **keep)
+        .map(|(t, _)| ticket_entry(t))
+        .collect();
+    let overflow: Vec<&str> = tickets
+        .iter()
+        .zip(selected)
+        .filter(|(_, keep)| !**keep)
+        .map(|(t, _)| t.id.as_str())
+        .collect();
+    if !overflow.is_empty() {
+        parts.push(ticket_overflow(&overflow, !parts.is_empty()));
+    }
+    parts.join(", ")
+}
 
-    let meetings_count = data.meetings.len();
-    let meetings_list = data
-        .meetings
+fn meeting_list(meetings: &[crate::aggregation::Meeting], selected: &[bool]) -> String {
+    let mut parts: Vec<String> = meetings
         .iter()
-        .map(|m| format!("{} ({}m)", m.title, m.duration_minutes))
-        .collect::<Vec<_>>()
-        .join(", ");
-
-    let focus_hours = data.focus_hours;
-
-    let blockers = user_fields.blockers.clone().unwrap_or_default();
-    let priorities = user_fields.tomorrow_priorities.clone().unwrap_or_default();
-
-    // Replace placeholders in template
-    template
-        .replace(
-            "{{tickets_closed_count}}",
-            &tickets_closed_count.to_string(),
-        )
-        .replace("{{tickets_closed_list}}", &tickets_closed_list)
-        .replace(
-            "{{tickets_in_progress_count}}",
-            &tickets_in_progress_count.to_string(),
-        )
-        .replace("{{tickets_in_progress_list}}", &tickets_in_progress_list)
-        .replace("{{meetings_count}}", &meetings_count.to_string())
-        .replace("{{meetings_list}}", &meetings_list)
-        .replace("{{focus_hours}}", &format!("{:.1}", focus_hours))
-        .replace("{{blockers}}", &blockers)
-        .replace("{{tomorrow_priorities}}", &priorities)
+        .zip(selected)
+        .filter(|(_, keep)| **keep)
+        .map(|(m, _)| meeting_entry(m))
+        .collect();
+    let overflow: Vec<i32> = meetings
+        .iter()
+        .zip(selected)
+        .filter(|(_, keep)| !**keep)
+        .map(|(m, _)| m.duration_minutes)
+        .collect();
+    if !overflow.is_empty() {
+        let more = if parts.is_empty() { "" } else { "and " };
+        let noun = if overflow.len() == 1 {
+            "meeting"
+        } else {
+            "meetings"
+        };
+        parts.push(format!(
+            "{}{} more {} ({}m total)",
+            more,
+            overflow.len(),
+            noun,
+            overflow.iter().sum::<i32>()
+        ));
+    }
+    parts.join(", ")
+}
+
+/// The day's captured notes, oldest first, as many as fit in `max_chars`
+fn activity_log(entries: &[crate::aggregation::LogEntry], max_chars: usize) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let mut used = 0;
+    for entry in entries {
+        let text = entry.describe();
+        let cost = text.chars().count() + 2;
+        if used + cost > max_chars {
+            break;
+        }
+        used += cost;
+        parts.push(text);
+    }
+    let skipped = entries.len() - parts.len();
+    if skipped > 0 {
+        let noun = if skipped == 1 { "note" } else { "notes" };
+        parts.push(format!("and {} more {}", skipped, noun));
+    }
+    parts.join("; ")
 }
 
-/// Get prompt template by tone
-pub fn get_template(tone: &str) -> String {
-    match tone {
+/// Build a prompt for the LLM from aggregated data and a tone template, kept
+/// within `max_tokens` (see `prompt_budget`). Counts are always exact; on busy
+/// days the lists name as many items as fit and summarise the rest.
+pub fn build_prompt(
+    data: &AggregatedData,
+    user_fields: &SummaryInput,
+    template: &str,
+    max_tokens: usize,
+) -> String {
+    // User-written fields get at most an eighth of the budget each
+    let clip = |text: Option<&String>| {
+        let text = text.cloned().unwrap_or_default();
+        super::sanitize::truncate_at_sentence(&text, max_tokens / 8 * 4)
+    };
+    let blockers = clip(user_fields.blockers.as_ref());
+    let priorities = clip(user_fields.tomorrow_priorities.as_ref());
+    let log = activity_log(&data.log_entries, max_tokens / 8 * 4);
+
+    let fill = |closed: &str, in_progress: &str, meetings: &str| {
+        template
+            .replace(
+                "{{tickets_closed_count}}",
+                &data.tickets_closed.len().to_string(),
+            )
+            .replace("{{tickets_closed_list}}", closed)
+            .replace(
+                "{{tickets_in_progress_count}}",
+                &data.tickets_in_progress.len().to_string(),
+            )
+            .replace("{{tickets_in_progress_list}}", in_progress)
+            .replace("{{meetings_count}}", &data.meetings.len().to_string())
+            .replace("{{meetings_list}}", meetings)
+            .replace("{{focus_hours}}", &format!("{:.1}", data.focus_hours))
+            .replace(
+                "{{calendar_breakdown}}",
+                &data.calendar_breakdown.describe(),
+            )
+            .replace("{{blockers}}", &blockers)
+            .replace("{{tomorrow_priorities}}", &priorities)
+            .replace("{{activity_log}}", &log)
+    };
+
+    let fixed_tokens = estimate_tokens(&fill("", "", ""));
+    let list_budget = max_tokens.saturating_sub(fixed_tokens + OVERFLOW_RESERVE_TOKENS * 3);
+    let (closed, in_progress, meetings) = select_items(data, template, list_budget);
+
+    let prompt = fill(
+        &ticket_list(&data.tickets_closed, &closed),
+        &ticket_list(&data.tickets_in_progress, &in_progress),
+        &meeting_list(&data.meetings, &meetings),
+    );
+    if estimate_tokens(&prompt) <= max_tokens {
+        return prompt;
+    }
+
+    // Only an oversized template gets here; cut it rather than let the model
+    // truncate silently somewhere we can't see
+    eprintln!(
+        "Prompt of ~{} tokens exceeds the {} token budget; truncating",
+        estimate_tokens(&prompt),
+        max_tokens
+    );
+    prompt.chars().take(max_tokens * 4).collect()
+}
+
+/// Get the built-in prompt template for a tone, None for custom tones
+pub fn builtin_template(tone: &str) -> Option<String> {
+    let template = match tone {
         "professional" => {
             r#"Generate a professional work summary in 4-6 sentences. Use third person perspective. Be factual and concise.
 
@@ -65,6 +321,8 @@ Input data:
 - Tickets in progress: {{tickets_in_progress_count}} ({{tickets_in_progress_list}})
 - Meetings attended: {{meetings_count}} ({{meetings_list}})
 - Focus time: {{focus_hours}} hours
+- Calendar: {{calendar_breakdown}}
+- Notes taken during the day: {{activity_log}}
 - Current blockers: {{blockers}}
 - Tomorrow's priorities: {{tomorrow_priorities}}
 
@@ -77,7 +335,6 @@ Structure your summary as follows:
 6. Tomorrow's plan
 
 Use formal, professional language. Do not use emojis. Be specific about ticket IDs and accomplishments."#
-                .to_string()
         }
         "casual" => {
             r#"Write a casual, first-person summary of my workday in 4-6 sentences. Be conversational and highlight wins.
@@ -87,11 +344,12 @@ Today's work:
 - Still working on {{tickets_in_progress_count}} tickets: {{tickets_in_progress_list}}
 - Attended {{meetings_count}} meetings: {{meetings_list}}
 - Got {{focus_hours}} hours of focus time
+- My calendar: {{calendar_breakdown}}
+- Notes I jotted down: {{activity_log}}
 - Blockers: {{blockers}}
 - Tomorrow I'm planning: {{tomorrow_priorities}}
 
 Write this like I'm telling a colleague what I did today. Use "I" and "my". Be upbeat about accomplishments. Keep it natural and conversational. Mention specific ticket IDs where relevant."#
-                .to_string()
         }
         "detailed" => {
             r#"Write a comprehensive work summary in 6-8 sentences with specific details and time breakdowns.
@@ -101,6 +359,8 @@ Detailed input:
 - Ongoing work ({{tickets_in_progress_count}}): {{tickets_in_progress_list}}
 - Meetings ({{meetings_count}} total): {{meetings_list}}
 - Focused work time: {{focus_hours}} hours
+- Calendar time: {{calendar_breakdown}}
+- Activity log: {{activity_log}}
 - Current blockers: {{blockers}}
 - Planned for tomorrow: {{tomorrow_priorities}}
 
@@ -113,8 +373,131 @@ Provide a thorough summary that includes:
 6. Clear priorities for tomorrow
 
 Use professional language. Include numbers, metrics, and specifics. This summary should give a complete picture of the day's work."#
-                .to_string()
         }
-        _ => get_template("professional"), // Default to professional
+        _ => return None,
+    };
+    Some(template.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::{DataSourcesStatus, Meeting, SourceStatusDetail, Ticket};
+
+    fn ticket(id: String) -> Ticket {
+        Ticket {
+            title: format!("Investigate flaky integration test for {}", id),
+            id,
+            status: "In Progress".to_string(),
+            url: String::new(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }
+    }
+
+    fn busy_day() -> AggregatedData {
+        AggregatedData {
+            tickets_closed: (1..=8).map(|n| ticket(format!("PROJ-{}", n))).collect(),
+            tickets_in_progress: (100..140)
+                .map(|n| ticket(format!("{}-{}", if n % 3 == 0 { "OPS" } else { "PROJ" }, n)))
+                .collect(),
+            meetings: (0..12)
+                .map(|n| Meeting {
+                    title: format!("Cross-team sync number {}", n),
+                    start: "2026-02-14T09:00:00Z".to_string(),
+                    end: "2026-02-14T09:30:00Z".to_string(),
+                    duration_minutes: 15 + n * 5,
+                    visibility: None,
+                    calendar_id: None,
+                    ..Default::default()
+                })
+                .collect(),
+            focus_hours: 1.5,
+            time_breakdown: Default::default(),
+            log_entries: Vec::new(),
+            calendar_breakdown: Default::default(),
+            data_sources_status: DataSourcesStatus {
+                jira: SourceStatusDetail::NotConfigured,
+                calendar: SourceStatusDetail::NotConfigured,
+                toggl: SourceStatusDetail::NotConfigured,
+            },
+        }
+    }
+
+    fn no_notes() -> SummaryInput {
+        SummaryInput {
+            blockers: None,
+            tomorrow_priorities: None,
+            manual_notes: None,
+            narrative: None,
+            tone: None,
+        }
+    }
+
+    #[test]
+    fn busy_day_prompt_stays_within_budget() {
+        let data = busy_day();
+        for tone in BUILTIN_TONES {
+            let template = builtin_template(tone).expect("built-in template");
+            for budget in [prompt_budget(2048), 700, 1200] {
+                let prompt = build_prompt(&data, &no_notes(), &template, budget);
+                assert!(
+                    estimate_tokens(&prompt) <= budget,
+                    "{} prompt is ~{} tokens, budget {}",
+                    tone,
+                    estimate_tokens(&prompt),
+                    budget
+                );
+                // Counts stay exact even when items are summarised
+                assert!(prompt.contains("40"), "{}", prompt);
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_keeps_closed_tickets_and_longest_meetings() {
+        let data = busy_day();
+        let template = builtin_template("professional").expect("built-in template");
+        let prompt = build_prompt(&data, &no_notes(), &template, 435);
+
+        assert!(prompt.contains("PROJ-8: "), "{}", prompt);
+        assert!(
+            prompt.contains("Cross-team sync number 11 (70m)"),
+            "{}",
+            prompt
+        );
+        assert!(!prompt.contains("Cross-team sync number 0 "), "{}", prompt);
+        assert!(
+            prompt.contains("and 6 more meetings (165m total)"),
+            "{}",
+            prompt
+        );
+        assert!(
+            prompt.contains("40 tickets (27 in project PROJ, 13 in project OPS)"),
+            "{}",
+            prompt
+        );
+    }
+
+    #[test]
+    fn quiet_day_lists_everything() {
+        let mut data = busy_day();
+        data.tickets_in_progress.truncate(2);
+        data.meetings.truncate(2);
+        let template = builtin_template("detailed").expect("built-in template");
+        let prompt = build_prompt(&data, &no_notes(), &template, prompt_budget(4096));
+
+        assert!(prompt.contains("PROJ-101: "));
+        assert!(prompt.contains("Cross-team sync number 1 (20m)"));
+        assert!(!prompt.contains("more ticket") && !prompt.contains("more meeting"));
+    }
+
+    #[test]
+    fn single_project_overflow_reads_naturally() {
+        assert_eq!(
+            ticket_overflow(&["PROJ-1", "PROJ-2"], true),
+            "and 2 more tickets in project PROJ"
+        );
     }
 }
@@ -0,0 +1,244 @@
+//! Post-processing for raw model output before it becomes a narrative.
+
+/// Narratives longer than this are cut at the last full sentence
+pub const MAX_NARRATIVE_CHARS: usize = 2000;
+
+/// Tags reasoning models wrap their chain of thought in
+const REASONING_TAGS: &[&str] = &["think", "thinking", "reasoning"];
+
+/// Clean raw model output: drop reasoning blocks, code fences and chatty
+/// preambles/sign-offs, normalise whitespace and enforce `max_chars`
+pub fn clean_narrative(raw: &str, max_chars: usize) -> String {
+    let text = raw.replace("\r\n", "\n");
+    let text = strip_reasoning(&text);
+    let text = strip_code_fences(&text);
+    let text = strip_boilerplate(&text);
+    let text = normalize_whitespace(&text);
+    truncate_at_sentence(&text, max_chars)
+}
+
+/// Case-insensitive find of an ASCII needle
+fn find_ascii_ci(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .to_ascii_lowercase()
+        .find(&needle.to_ascii_lowercase())
+}
+
+/// Remove `<think>…</think>` style blocks. An unclosed opening tag drops the
+/// rest of the output; a closing tag without an opening one (some chat
+/// templates pre-fill `<think>`) drops everything before it.
+pub fn strip_reasoning(text: &str) -> String {
+    let mut text = text.to_string();
+
+    for tag in REASONING_TAGS {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+
+        loop {
+            match (find_ascii_ci(&text, &open), find_ascii_ci(&text, &close)) {
+                (Some(start), Some(end)) if start < end => {
+                    text.replace_range(start..end + close.len(), "");
+                }
+                (Some(start), _) => {
+                    text.truncate(start);
+                }
+                (None, Some(end)) => {
+                    text.replace_range(..end + close.len(), "");
+                }
+                (None, None) => break,
+            }
+        }
+    }
+
+    text
+}
+
+/// Unwrap output that is entirely inside a ``` fence and drop stray fence lines
+pub fn strip_code_fences(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.trim_start().starts_with("```"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_preamble(line: &str) -> bool {
+    let lower = line
+        .trim()
+        .trim_start_matches(['*', '#', ' '])
+        .to_lowercase();
+    let openers = [
+        "here is",
+        "here's",
+        "sure",
+        "certainly",
+        "of course",
+        "okay",
+        "ok,",
+        "absolutely",
+    ];
+    let mentions_output = ["summary", "narrative", "recap", "overview", "update"]
+        .iter()
+        .any(|w| lower.contains(w));
+
+    // Real narrative sentences can start with "Okay", so only short lines count
+    lower.len() <= 100
+        && ((openers.iter().any(|o| lower.starts_with(o))
+            && (lower.ends_with(':') || mentions_output))
+            || ((lower.starts_with("work summary") || lower.starts_with("summary"))
+                && lower.ends_with(':')))
+}
+
+fn is_sign_off(line: &str) -> bool {
+    let lower = line.trim().to_lowercase();
+    [
+        "let me know",
+        "i hope this",
+        "hope this helps",
+        "feel free to",
+        "if you'd like",
+        "if you would like",
+        "would you like me",
+    ]
+    .iter()
+    .any(|p| lower.starts_with(p))
+}
+
+/// Drop leading "Here is your summary:" lines and trailing offers of help
+pub fn strip_boilerplate(text: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    while let Some(first) = lines.iter().position(|l| !l.trim().is_empty()) {
+        if is_preamble(lines[first]) {
+            lines.drain(..=first);
+        } else {
+            break;
+        }
+    }
+
+    while let Some(last) = lines.iter().rposition(|l| !l.trim().is_empty()) {
+        if is_sign_off(lines[last]) {
+            lines.truncate(last);
+        } else {
+            break;
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Trim lines, collapse runs of spaces and of blank lines
+pub fn normalize_whitespace(text: &str) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let line = line.replace('\u{a0}', " ");
+        let collapsed = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        if collapsed.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(collapsed);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join("\n"));
+    }
+
+    paragraphs.join("\n\n")
+}
+
+/// Cut to at most `max_chars` characters, preferring the end of a sentence,
+/// then a word boundary with an ellipsis
+pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let cut = text
+        .char_indices()
+        .nth(max_chars)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    let head = &text[..cut];
+
+    // Only accept a sentence end in the second half, otherwise too much is lost
+    if let Some(end) = head.rfind(['.', '!', '?']) {
+        if end >= cut / 2 {
+            return head[..=end].to_string();
+        }
+    }
+
+    // Leave room for the ellipsis
+    let room = text
+        .char_indices()
+        .nth(max_chars.saturating_sub(1))
+        .map_or(text.len(), |(i, _)| i);
+    let head = &text[..room];
+    match head.rfind(char::is_whitespace) {
+        Some(space) => format!("{}…", head[..space].trim_end()),
+        None => format!("{}…", head),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Captured from qwen3:14b with the professional tone
+    const QWEN3_THINKING: &str = "<think>\nOkay, the user wants a professional summary. \
+        They closed PROJ-12 and PROJ-15. Let me structure this.\n</think>\n\n\
+        Here is your professional work summary:\n\n\
+        The engineer closed PROJ-12 and PROJ-15, resolving the login regression.   \
+        Work continues on PROJ-20.\n\n\n\nTwo meetings took 45 minutes in total.\n\n\
+        Let me know if you'd like any changes!";
+
+    // Captured from llama3.1:8b, which wraps answers in a fence
+    const LLAMA_FENCED: &str = "Sure! Here's a summary of your day:\n\n```markdown\n\
+        I closed **PROJ-7** and reviewed two pull requests.\n```\n";
+
+    // Chat templates that pre-fill `<think>` only return the closing tag
+    const PREFILLED_THINK: &str =
+        "the user closed nothing today...\n</think>\n\nA quiet day focused on planning.";
+
+    #[test]
+    fn cleans_captured_qwen3_output() {
+        assert_eq!(
+            clean_narrative(QWEN3_THINKING, MAX_NARRATIVE_CHARS),
+            "The engineer closed PROJ-12 and PROJ-15, resolving the login regression. \
+             Work continues on PROJ-20.\n\nTwo meetings took 45 minutes in total."
+        );
+    }
+
+    #[test]
+    fn cleans_fenced_and_prefilled_output() {
+        assert_eq!(
+            clean_narrative(LLAMA_FENCED, MAX_NARRATIVE_CHARS),
+            "I closed **PROJ-7** and reviewed two pull requests."
+        );
+        assert_eq!(
+            clean_narrative(PREFILLED_THINK, MAX_NARRATIVE_CHARS),
+            "A quiet day focused on planning."
+        );
+        // An unterminated reasoning block leaves nothing usable
+        assert_eq!(clean_narrative("<think>still thinking", 100), "");
+    }
+
+    #[test]
+    fn keeps_ordinary_first_sentences() {
+        let text = "Okay progress today: PROJ-1 is nearly done.";
+        assert_eq!(clean_narrative(text, 100), text);
+    }
+
+    #[test]
+    fn truncates_at_sentence_or_word() {
+        let text = "First sentence here. Second sentence is much longer than allowed.";
+        assert_eq!(truncate_at_sentence(text, 30), "First sentence here.");
+        assert_eq!(
+            truncate_at_sentence("one two three four five", 12),
+            "one two…"
+        );
+    }
+}
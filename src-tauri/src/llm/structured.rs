@@ -0,0 +1,201 @@
+//! Structured (JSON) narrative mode: the model fills fixed sections instead of
+//! writing free prose, so every day's summary has the same layout.
+
+use super::sanitize;
+use crate::error::AppError;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Items longer than this are cut at a sentence boundary
+const MAX_ITEM_CHARS: usize = 300;
+
+/// More items than this per section are dropped
+const MAX_ITEMS_PER_SECTION: usize = 8;
+
+/// Whether the LLM writes prose or fills the structured sections
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NarrativeFormat {
+    #[default]
+    Prose,
+    Structured,
+}
+
+impl NarrativeFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NarrativeFormat::Prose => "prose",
+            NarrativeFormat::Structured => "structured",
+        }
+    }
+
+    /// Unknown values stored in settings fall back to prose
+    pub fn parse(value: &str) -> NarrativeFormat {
+        match value {
+            "structured" => NarrativeFormat::Structured,
+            _ => NarrativeFormat::Prose,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct StructuredNarrative {
+    /// Work finished today
+    pub accomplishments: Vec<String>,
+    /// Work started or continued but not finished
+    pub in_progress: Vec<String>,
+    /// Meetings, reviews and help given or received
+    pub collaboration: Vec<String>,
+    /// Blockers and risks
+    pub risks: Vec<String>,
+    /// Plans for the next working day
+    pub tomorrow: Vec<String>,
+}
+
+impl StructuredNarrative {
+    fn sections(&self) -> [(&'static str, &Vec<String>); 5] {
+        [
+            ("Accomplishments", &self.accomplishments),
+            ("In Progress", &self.in_progress),
+            ("Collaboration", &self.collaboration),
+            ("Risks", &self.risks),
+            ("Tomorrow", &self.tomorrow),
+        ]
+    }
+
+    fn sections_mut(&mut self) -> [&mut Vec<String>; 5] {
+        [
+            &mut self.accomplishments,
+            &mut self.in_progress,
+            &mut self.collaboration,
+            &mut self.risks,
+            &mut self.tomorrow,
+        ]
+    }
+
+    /// Narrative text: a bold heading and a bullet list per non-empty section
+    pub fn to_markdown(&self) -> String {
+        self.sections()
+            .into_iter()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(heading, items)| {
+                let bullets = items
+                    .iter()
+                    .map(|item| format!("- {}", item))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("**{}**\n{}", heading, bullets)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Instructions appended to the tone prompt in structured mode
+pub fn structured_prompt(prompt: &str) -> String {
+    format!(
+        "{}\n\nRespond only with a JSON object with the keys \"accomplishments\", \
+         \"inProgress\", \"collaboration\", \"risks\" and \"tomorrow\". Each value is an \
+         array of short, factual sentences; use an empty array when there is nothing to say.",
+        prompt
+    )
+}
+
+/// Parse and validate the model's JSON answer. Items are trimmed, empty items
+/// dropped and long ones shortened; an answer with no items at all is invalid.
+pub fn parse_structured(raw: &str) -> Result<StructuredNarrative, AppError> {
+    let text = sanitize::strip_code_fences(&sanitize::strip_reasoning(raw));
+    let json = match (text.find('{'), text.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => {
+            return Err(AppError::LlmInvalidOutput(
+                "expected a JSON object".to_string(),
+            ))
+        }
+    };
+
+    let mut narrative: StructuredNarrative = serde_json::from_str(json)
+        .map_err(|e| AppError::LlmInvalidOutput(format!("invalid JSON: {}", e)))?;
+
+    for items in narrative.sections_mut() {
+        *items = items
+            .iter()
+            .map(|item| sanitize::normalize_whitespace(item).replace('\n', " "))
+            .filter(|item| !item.is_empty())
+            .map(|item| sanitize::truncate_at_sentence(&item, MAX_ITEM_CHARS))
+            .take(MAX_ITEMS_PER_SECTION)
+            .collect();
+    }
+
+    if narrative
+        .sections()
+        .iter()
+        .all(|(_, items)| items.is_empty())
+    {
+        return Err(AppError::LlmInvalidOutput(
+            "all sections are empty".to_string(),
+        ));
+    }
+    Ok(narrative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Captured from qwen3:14b with format set to the schema
+    const CAPTURED: &str = r#"{"accomplishments": ["Closed PROJ-12 and PROJ-15.", "  "],
+        "inProgress": ["PROJ-20   billing export"], "collaboration": [],
+        "risks": [], "tomorrow": ["Finish PROJ-20."]}"#;
+
+    #[test]
+    fn parses_and_renders_sections() {
+        let narrative = parse_structured(CAPTURED).expect("captured output should parse");
+        assert_eq!(
+            narrative.accomplishments,
+            vec!["Closed PROJ-12 and PROJ-15."]
+        );
+        assert_eq!(
+            narrative.to_markdown(),
+            "**Accomplishments**\n- Closed PROJ-12 and PROJ-15.\n\n\
+             **In Progress**\n- PROJ-20 billing export\n\n\
+             **Tomorrow**\n- Finish PROJ-20."
+        );
+
+        let fenced = format!("<think>ok</think>\n```json\n{}\n```", CAPTURED);
+        assert_eq!(parse_structured(&fenced).ok(), Some(narrative));
+    }
+
+    #[test]
+    fn html_renderer_shows_sections_as_lists() {
+        let narrative = parse_structured(CAPTURED).expect("captured output should parse");
+        let markdown = narrative.to_markdown();
+        let html = crate::markdown::render_summary_to_html(crate::markdown::SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: &markdown,
+            tickets_closed: &[],
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 0.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        });
+        assert!(html.contains(
+            "<p><strong>Accomplishments</strong></p><ul><li>Closed PROJ-12 and PROJ-15.</li></ul>"
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_or_empty_answers() {
+        assert!(parse_structured("Here is your summary: closed two tickets").is_err());
+        assert!(parse_structured(r#"{"accomplishments": "one"}"#).is_err());
+        assert!(parse_structured(
+            r#"{"accomplishments": [], "inProgress": [], "collaboration": [], "risks": [], "tomorrow": []}"#
+        )
+        .is_err());
+    }
+}
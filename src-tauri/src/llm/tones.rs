@@ -0,0 +1,147 @@
+use super::prompts::{self, BUILTIN_TONES, PLACEHOLDERS};
+use crate::error::AppError;
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+
+/// A tone as shown in Settings; built-ins have no id and are read-only
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToneTemplate {
+    pub id: Option<i64>,
+    pub name: String,
+    pub template: String,
+    pub builtin: bool,
+}
+
+/// Built-in tones followed by the user's custom tones
+pub async fn list_tones(db: &SqlitePool) -> Result<Vec<ToneTemplate>, AppError> {
+    let mut tones: Vec<ToneTemplate> = BUILTIN_TONES
+        .iter()
+        .map(|name| ToneTemplate {
+            id: None,
+            name: name.to_string(),
+            template: prompts::builtin_template(name).unwrap_or_default(),
+            builtin: true,
+        })
+        .collect();
+
+    let rows = sqlx::query("SELECT id, name, template FROM custom_tones ORDER BY name")
+        .fetch_all(db)
+        .await?;
+    tones.extend(rows.into_iter().map(|row| ToneTemplate {
+        id: Some(row.get("id")),
+        name: row.get("name"),
+        template: row.get("template"),
+        builtin: false,
+    }));
+
+    Ok(tones)
+}
+
+/// Prompt template for a built-in or custom tone; unknown tones are an error
+pub async fn resolve_template(db: &SqlitePool, tone: &str) -> Result<String, AppError> {
+    if let Some(template) = prompts::builtin_template(tone) {
+        return Ok(template);
+    }
+
+    let template: Option<String> =
+        sqlx::query_scalar("SELECT template FROM custom_tones WHERE name = ?1")
+            .bind(tone)
+            .fetch_optional(db)
+            .await?;
+
+    template.ok_or_else(|| {
+        AppError::NotConfigured(format!("Unknown tone '{}'. Pick a tone in Settings.", tone))
+    })
+}
+
+pub async fn tone_exists(db: &SqlitePool, tone: &str) -> Result<bool, AppError> {
+    match resolve_template(db, tone).await {
+        Ok(_) => Ok(true),
+        Err(AppError::NotConfigured(_)) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Tone names are short identifiers that cannot shadow a built-in
+pub fn validate_name(name: &str) -> Result<(), AppError> {
+    if name.is_empty() || name.len() > 40 {
+        return Err(AppError::NotConfigured(
+            "Tone name must be 1-40 characters".to_string(),
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AppError::NotConfigured(
+            "Tone name may only contain letters, digits, '-' and '_'".to_string(),
+        ));
+    }
+    if BUILTIN_TONES.contains(&name) {
+        return Err(AppError::NotConfigured(format!(
+            "'{}' is a built-in tone",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Every `{{placeholder}}` must be known, and at least one must be used so the
+/// prompt actually contains the day's data
+pub fn validate_template(template: &str) -> Result<(), AppError> {
+    let mut used = 0;
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| AppError::NotConfigured("Unclosed {{ in tone template".to_string()))?;
+        // build_prompt substitutes exact `{{name}}` tokens, so no inner spaces
+        let name = &after[..end];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(AppError::NotConfigured(format!(
+                "Unknown placeholder {{{{{}}}}}. Available: {}",
+                name,
+                PLACEHOLDERS.join(", ")
+            )));
+        }
+        used += 1;
+        rest = &after[end + 2..];
+    }
+
+    if used == 0 {
+        return Err(AppError::NotConfigured(
+            "Tone template must use at least one placeholder, e.g. {{tickets_closed_list}}"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_templates_pass_validation() {
+        for tone in BUILTIN_TONES {
+            let template = prompts::builtin_template(tone).expect("built-in template");
+            assert!(validate_template(&template).is_ok(), "{}", tone);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders_and_names() {
+        assert!(validate_template("Closed {{tickets_closed_list}}").is_ok());
+        assert!(validate_template("Closed {{tickets}}").is_err());
+        assert!(validate_template("Closed {{ tickets_closed_list }}").is_err());
+        assert!(validate_template("Closed {{tickets_closed_list").is_err());
+        assert!(validate_template("No data at all").is_err());
+
+        assert!(validate_name("standup-short").is_ok());
+        assert!(validate_name("casual").is_err());
+        assert!(validate_name("with space").is_err());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::aggregation::{Meeting, Ticket};
+use crate::aggregation::meetings::CalendarBreakdown;
+use crate::aggregation::{LogEntry, Meeting, Ticket};
 
+#[derive(Clone, Copy)]
 pub struct SummaryMarkdownInput<'a> {
     pub date: &'a str,
     pub narrative: &'a str,
@@ -10,6 +12,31 @@ pub struct SummaryMarkdownInput<'a> {
     pub blockers: &'a str,
     pub tomorrow_priorities: &'a str,
     pub manual_notes: &'a str,
+    /// Notes captured during the day
+    pub log_entries: &'a [LogEntry],
+    /// Collaboration vs. focus-block time, if the day's calendar was read
+    pub calendar_breakdown: Option<&'a CalendarBreakdown>,
+}
+
+impl<'a> SummaryMarkdownInput<'a> {
+    /// The calendar breakdown; summaries saved before it existed store an
+    /// empty one, which is treated as missing
+    pub fn calendar(&self) -> Option<&'a CalendarBreakdown> {
+        self.calendar_breakdown
+            .filter(|breakdown| **breakdown != CalendarBreakdown::default())
+    }
+
+    /// Time in meetings with overlapping meetings counted once
+    pub fn meeting_minutes(&self) -> u32 {
+        match self.calendar() {
+            Some(breakdown) => breakdown.collaboration_minutes,
+            None => self
+                .meetings
+                .iter()
+                .map(|m| m.duration_minutes.max(0) as u32)
+                .sum(),
+        }
+    }
 }
 
 /// Render SummaryResponse to markdown format
@@ -24,6 +51,8 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
         blockers,
         tomorrow_priorities,
         manual_notes,
+        log_entries,
+        ..
     } = input;
 
     let mut sections = Vec::new();
@@ -46,8 +75,11 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
         sections.push(format!("## Tickets Closed ({})", tickets_closed.len()));
         for ticket in tickets_closed {
             sections.push(format!(
-                "- [{}]({}) - {}",
-                ticket.id, ticket.url, ticket.title
+                "- [{}]({}) - {}{}",
+                ticket.id,
+                ticket.url,
+                ticket.title,
+                tracked_suffix(ticket)
             ));
         }
         sections.push(String::new());
@@ -58,8 +90,11 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
         sections.push(format!("## In Progress ({})", tickets_in_progress.len()));
         for ticket in tickets_in_progress {
             sections.push(format!(
-                "- [{}]({}) - {}",
-                ticket.id, ticket.url, ticket.title
+                "- [{}]({}) - {}{}",
+                ticket.id,
+                ticket.url,
+                ticket.title,
+                tracked_suffix(ticket)
             ));
         }
         sections.push(String::new());
@@ -67,21 +102,24 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
 
     // Meetings
     if !meetings.is_empty() {
-        let total_minutes: i32 = meetings.iter().map(|m| m.duration_minutes).sum();
         sections.push(format!(
             "## Meetings ({}, {}m total)",
             meetings.len(),
-            total_minutes
+            input.meeting_minutes()
         ));
         for meeting in meetings {
-            sections.push(format!(
-                "- {} ({}m)",
-                meeting.title, meeting.duration_minutes
-            ));
+            sections.push(format!("- {} ({})", meeting.title, meeting.length_label()));
         }
         sections.push(String::new());
     }
 
+    // Calendar
+    if let Some(breakdown) = input.calendar() {
+        sections.push("## Calendar".to_string());
+        sections.push(breakdown.describe());
+        sections.push(String::new());
+    }
+
     // Focus Time
     if focus_hours > 0.0 {
         sections.push("## Focus Time".to_string());
@@ -89,6 +127,15 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
         sections.push(String::new());
     }
 
+    // Activity Log
+    if !log_entries.is_empty() {
+        sections.push("## Activity Log".to_string());
+        for entry in log_entries {
+            sections.push(format!("- {}", entry.describe()));
+        }
+        sections.push(String::new());
+    }
+
     // Blockers
     if !blockers.is_empty() {
         sections.push("## Blockers".to_string());
@@ -112,3 +159,306 @@ pub fn render_summary_to_markdown(input: SummaryMarkdownInput<'_>) -> String {
 
     sections.join("\n")
 }
+
+/// Render SummaryResponse to a standalone HTML fragment
+pub fn render_summary_to_html(input: SummaryMarkdownInput<'_>) -> String {
+    let SummaryMarkdownInput {
+        date,
+        narrative,
+        tickets_closed,
+        tickets_in_progress,
+        meetings,
+        focus_hours,
+        blockers,
+        tomorrow_priorities,
+        manual_notes,
+        log_entries,
+        ..
+    } = input;
+
+    let mut html = Vec::new();
+
+    html.push(format!("<h1>Work Summary — {}</h1>", escape_html(date)));
+
+    html.push("<h2>Narrative</h2>".to_string());
+    html.push(if narrative.is_empty() {
+        "<p>(No narrative)</p>".to_string()
+    } else {
+        text_to_html(narrative)
+    });
+
+    let ticket_list = |tickets: &[Ticket]| {
+        let items = tickets
+            .iter()
+            .map(|t| {
+                format!(
+                    "<li><a href=\"{}\">{}</a> - {}{}</li>",
+                    escape_html(&t.url),
+                    escape_html(&t.id),
+                    escape_html(&t.title),
+                    tracked_suffix(t)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("");
+        format!("<ul>{}</ul>", items)
+    };
+
+    if !tickets_closed.is_empty() {
+        html.push(format!(
+            "<h2>Tickets Closed ({})</h2>",
+            tickets_closed.len()
+        ));
+        html.push(ticket_list(tickets_closed));
+    }
+
+    if !tickets_in_progress.is_empty() {
+        html.push(format!(
+            "<h2>In Progress ({})</h2>",
+            tickets_in_progress.len()
+        ));
+        html.push(ticket_list(tickets_in_progress));
+    }
+
+    if !meetings.is_empty() {
+        html.push(format!(
+            "<h2>Meetings ({}, {}m total)</h2>",
+            meetings.len(),
+            input.meeting_minutes()
+        ));
+        let items = meetings
+            .iter()
+            .map(|m| format!("<li>{} ({})</li>", escape_html(&m.title), m.length_label()))
+            .collect::<Vec<_>>()
+            .join("");
+        html.push(format!("<ul>{}</ul>", items));
+    }
+
+    if let Some(breakdown) = input.calendar() {
+        html.push("<h2>Calendar</h2>".to_string());
+        html.push(format!("<p>{}</p>", escape_html(&breakdown.describe())));
+    }
+
+    if focus_hours > 0.0 {
+        html.push("<h2>Focus Time</h2>".to_string());
+        html.push(format!("<p>{:.1} hours</p>", focus_hours));
+    }
+
+    if !log_entries.is_empty() {
+        html.push("<h2>Activity Log</h2>".to_string());
+        let items = log_entries
+            .iter()
+            .map(|e| format!("<li>{}</li>", escape_html(&e.describe())))
+            .collect::<Vec<_>>()
+            .join("");
+        html.push(format!("<ul>{}</ul>", items));
+    }
+
+    for (heading, text) in [
+        ("Blockers", blockers),
+        ("Tomorrow's Priorities", tomorrow_priorities),
+        ("Notes", manual_notes),
+    ] {
+        if !text.is_empty() {
+            html.push(format!("<h2>{}</h2>", escape_html(heading)));
+            html.push(text_to_html(text));
+        }
+    }
+
+    html.join("\n")
+}
+
+/// Render SummaryResponse as plain text without any markup
+pub fn render_summary_to_text(input: SummaryMarkdownInput<'_>) -> String {
+    let SummaryMarkdownInput {
+        date,
+        narrative,
+        tickets_closed,
+        tickets_in_progress,
+        meetings,
+        focus_hours,
+        blockers,
+        tomorrow_priorities,
+        manual_notes,
+        log_entries,
+        ..
+    } = input;
+
+    let mut lines = Vec::new();
+
+    let title = format!("Work Summary — {}", date);
+    lines.push(title.clone());
+    lines.push("=".repeat(title.chars().count()));
+    lines.push(String::new());
+    lines.push(if narrative.is_empty() {
+        "(No narrative)".to_string()
+    } else {
+        narrative.to_string()
+    });
+    lines.push(String::new());
+
+    for (heading, tickets) in [
+        ("Tickets Closed", tickets_closed),
+        ("In Progress", tickets_in_progress),
+    ] {
+        if !tickets.is_empty() {
+            lines.push(format!("{} ({}):", heading, tickets.len()));
+            for ticket in tickets {
+                lines.push(format!(
+                    "  * {} - {}{} ({})",
+                    ticket.id,
+                    ticket.title,
+                    tracked_suffix(ticket),
+                    ticket.url
+                ));
+            }
+            lines.push(String::new());
+        }
+    }
+
+    if !meetings.is_empty() {
+        lines.push(format!(
+            "Meetings ({}, {}m total):",
+            meetings.len(),
+            input.meeting_minutes()
+        ));
+        for meeting in meetings {
+            lines.push(format!(
+                "  * {} ({})",
+                meeting.title,
+                meeting.length_label()
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    if let Some(breakdown) = input.calendar() {
+        lines.push(format!("Calendar: {}", breakdown.describe()));
+        lines.push(String::new());
+    }
+
+    if focus_hours > 0.0 {
+        lines.push(format!("Focus Time: {:.1} hours", focus_hours));
+        lines.push(String::new());
+    }
+
+    if !log_entries.is_empty() {
+        lines.push("Activity Log:".to_string());
+        for entry in log_entries {
+            lines.push(format!("  * {}", entry.describe()));
+        }
+        lines.push(String::new());
+    }
+
+    for (heading, text) in [
+        ("Blockers", blockers),
+        ("Tomorrow's Priorities", tomorrow_priorities),
+        ("Notes", manual_notes),
+    ] {
+        if !text.is_empty() {
+            lines.push(format!("{}:", heading));
+            lines.push(text.to_string());
+            lines.push(String::new());
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Render SummaryResponse as a structured JSON document
+pub fn render_summary_to_json(input: SummaryMarkdownInput<'_>) -> serde_json::Value {
+    serde_json::json!({
+        "date": input.date,
+        "narrative": input.narrative,
+        "ticketsClosed": input.tickets_closed,
+        "ticketsInProgress": input.tickets_in_progress,
+        "meetings": input.meetings,
+        "focusHours": (input.focus_hours as f64 * 10.0).round() / 10.0,
+        "blockers": input.blockers,
+        "tomorrowPriorities": input.tomorrow_priorities,
+        "manualNotes": input.manual_notes,
+        "logEntries": input.log_entries,
+        "calendarBreakdown": input.calendar(),
+    })
+}
+
+/// " (1h 30m)" for tickets with tracked time, otherwise empty
+fn tracked_suffix(ticket: &Ticket) -> String {
+    match ticket.activity.time_tracked_minutes {
+        0 => String::new(),
+        minutes => format!(" ({})", crate::aggregation::time::format_minutes(minutes)),
+    }
+}
+
+/// Escape text for inclusion in HTML element content or attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Free text to paragraphs: blank lines split paragraphs, single newlines become <br>,
+/// trailing `- ` lines become a list and `**bold**` becomes <strong>
+fn text_to_html(text: &str) -> String {
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(paragraph_to_html)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn paragraph_to_html(paragraph: &str) -> String {
+    let lines: Vec<&str> = paragraph.lines().map(str::trim).collect();
+    let list_start = lines
+        .iter()
+        .rposition(|line| !line.starts_with("- "))
+        .map_or(0, |i| i + 1);
+    if list_start == lines.len() {
+        return format!("<p>{}</p>", inline_html(paragraph));
+    }
+
+    let mut html = String::new();
+    if list_start > 0 {
+        html.push_str(&format!(
+            "<p>{}</p>",
+            inline_html(&lines[..list_start].join("\n"))
+        ));
+    }
+    let items = lines[list_start..]
+        .iter()
+        .map(|line| format!("<li>{}</li>", inline_html(&line[2..])))
+        .collect::<Vec<_>>()
+        .join("");
+    html.push_str(&format!("<ul>{}</ul>", items));
+    html
+}
+
+fn inline_html(text: &str) -> String {
+    let escaped = escape_html(text).replace('\n', "<br>");
+    let parts: Vec<&str> = escaped.split("**").collect();
+    // Unbalanced markers are left as typed
+    if parts.len() % 2 == 0 {
+        return escaped;
+    }
+    parts
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                format!("<strong>{}</strong>", part)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
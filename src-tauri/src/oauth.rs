@@ -4,7 +4,7 @@ use oauth2::{
     ClientSecret, CsrfToken, PkceCodeChallenge, RedirectUrl, Scope, TokenResponse, TokenUrl,
 };
 use std::net::TcpListener;
-use tauri::Emitter;
+use tauri::{Emitter, Manager};
 
 /// Google OAuth2 client configuration
 pub struct GoogleOAuthClient {
@@ -292,21 +292,22 @@ async fn complete_oauth_flow(app: tauri::AppHandle, code: String) -> Result<(),
             .ok_or_else(|| AppError::NotConfigured("No PKCE verifier found".to_string()))?;
 
     // Exchange code for tokens
-    let (_access_token, refresh_token) = oauth_client.exchange_code(code, pkce_verifier).await?;
+    let (access_token, refresh_token) = oauth_client.exchange_code(code, pkce_verifier).await?;
 
-    // Store refresh token in encrypted storage
-    crate::stronghold::store_secret(
-        &app,
-        crate::stronghold::keys::GOOGLE_REFRESH_TOKEN,
-        &refresh_token,
-    )?;
+    // Store refresh token in encrypted storage, keyed by the account's address
+    let email = crate::google_accounts::account_email(&access_token).await?;
+    let db = app.state::<sqlx::SqlitePool>();
+    crate::google_accounts::add(db.inner(), &app, &email, &refresh_token).await?;
 
     // Clean up temporary secrets
     crate::stronghold::delete_secret(&app, crate::stronghold::keys::OAUTH_CSRF_TOKEN)?;
     crate::stronghold::delete_secret(&app, crate::stronghold::keys::OAUTH_PKCE_VERIFIER)?;
 
     // Emit success event
-    let _ = app.emit("oauth-completed", "Google Calendar connected successfully!");
+    let _ = app.emit(
+        "oauth-completed",
+        format!("Connected Google account {}", email),
+    );
 
     Ok(())
 }
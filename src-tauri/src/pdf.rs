@@ -0,0 +1,688 @@
+//! Pure-Rust PDF layout for summaries, using the standard Helvetica fonts so
+//! nothing has to be embedded or installed.
+
+use crate::aggregation::Ticket;
+use crate::llm::structured::StructuredNarrative;
+use crate::markdown::SummaryMarkdownInput;
+use pdf_writer::types::{ActionType, AnnotationType, BorderType};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+const BODY_SIZE: f32 = 10.5;
+const TABLE_SIZE: f32 = 9.5;
+const LINE_SPACING: f32 = 1.35;
+const CELL_PADDING: f32 = 4.0;
+
+const REGULAR: Name<'static> = Name(b"F1");
+const BOLD: Name<'static> = Name(b"F2");
+
+const LINK_COLOR: (f32, f32, f32) = (0.05, 0.33, 0.75);
+const MUTED_COLOR: (f32, f32, f32) = (0.4, 0.4, 0.4);
+
+/// Helvetica advance widths for ASCII 32..=126, in 1/1000 em
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' '../
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // 0-9
+    278, 278, 584, 584, 584, 556, 1015, // :..@
+    667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, // A-M
+    722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, // N-Z
+    278, 278, 278, 469, 556, 333, // [..`
+    556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, // a-m
+    556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, // n-z
+    334, 260, 334, 584, // {..~
+];
+
+/// Helvetica-Bold is roughly this much wider; overestimating only wraps a little early
+const BOLD_WIDTH_FACTOR: f32 = 1.08;
+
+fn char_width(c: char) -> u16 {
+    match c as u32 {
+        32..=126 => HELVETICA_WIDTHS[(c as u32 - 32) as usize],
+        _ => 556,
+    }
+}
+
+fn text_width(text: &str, bold: bool, size: f32) -> f32 {
+    let units: u32 = text.chars().map(|c| char_width(c) as u32).sum();
+    let width = units as f32 * size / 1000.0;
+    if bold {
+        width * BOLD_WIDTH_FACTOR
+    } else {
+        width
+    }
+}
+
+/// Encode text as WinAnsi, the encoding the standard fonts are declared with.
+/// Characters outside it become '?'.
+fn encode_win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' => c as u8,
+            '\u{a0}'..='\u{ff}' => c as u32 as u8,
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '\t' => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Greedy word wrap; explicit newlines are kept and over-long words are split
+fn wrap_text(text: &str, bold: bool, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut current = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+
+            if text_width(&candidate, bold, size) <= width {
+                current = candidate;
+                continue;
+            }
+
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+
+            // A single word wider than the line is broken wherever it overflows
+            for c in word.chars() {
+                current.push(c);
+                if text_width(&current, bold, size) > width && current.chars().count() > 1 {
+                    current.pop();
+                    lines.push(std::mem::replace(&mut current, c.to_string()));
+                }
+            }
+        }
+
+        lines.push(current);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+struct Link {
+    rect: Rect,
+    url: String,
+}
+
+struct Page {
+    content: Content,
+    links: Vec<Link>,
+}
+
+/// One table cell; linked cells are drawn in link colour with a URI annotation
+struct Cell {
+    text: String,
+    link: Option<String>,
+}
+
+impl Cell {
+    fn text(text: impl Into<String>) -> Self {
+        Cell {
+            text: text.into(),
+            link: None,
+        }
+    }
+}
+
+/// Top-down layout cursor over a growing list of pages
+struct Layout {
+    pages: Vec<Page>,
+    /// Baseline region still free on the current page, measured from the bottom
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        let mut layout = Layout {
+            pages: Vec::new(),
+            y: 0.0,
+        };
+        layout.new_page();
+        layout
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(Page {
+            content: Content::new(),
+            links: Vec::new(),
+        });
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn page(&mut self) -> &mut Page {
+        self.pages.last_mut().expect("layout always has a page")
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn is_page_empty(&self) -> bool {
+        self.y >= PAGE_HEIGHT - MARGIN
+    }
+
+    fn draw_text(
+        &mut self,
+        x: f32,
+        baseline: f32,
+        text: &str,
+        bold: bool,
+        size: f32,
+        color: (f32, f32, f32),
+    ) {
+        let content = &mut self.page().content;
+        content.begin_text();
+        content.set_fill_rgb(color.0, color.1, color.2);
+        content.set_font(if bold { BOLD } else { REGULAR }, size);
+        content.next_line(x, baseline);
+        content.show(Str(&encode_win_ansi(text)));
+        content.end_text();
+    }
+
+    /// Horizontal line at the cursor from the left margin to `x_end`
+    fn rule(&mut self, x_end: f32, gray: f32, width: f32) {
+        let y = self.y;
+        let content = &mut self.page().content;
+        content.set_stroke_rgb(gray, gray, gray);
+        content.set_line_width(width);
+        content.move_to(MARGIN, y);
+        content.line_to(x_end, y);
+        content.stroke();
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn title(&mut self, text: &str) {
+        let size = 18.0;
+        self.ensure_space(size * LINE_SPACING);
+        self.y -= size;
+        self.draw_text(MARGIN, self.y, text, true, size, (0.0, 0.0, 0.0));
+        self.y -= 6.0;
+        self.rule(PAGE_WIDTH - MARGIN, 0.75, 0.75);
+        self.y -= 10.0;
+    }
+
+    fn heading(&mut self, text: &str) {
+        let size = 13.0;
+        // Keep the heading together with at least two lines of what follows
+        self.ensure_space(size * LINE_SPACING + 3.0 * BODY_SIZE * LINE_SPACING);
+        self.y -= 8.0 + size;
+        self.draw_text(MARGIN, self.y, text, true, size, (0.0, 0.0, 0.0));
+        self.y -= 6.0;
+    }
+
+    fn paragraph(&mut self, text: &str, color: (f32, f32, f32)) {
+        let line_height = BODY_SIZE * LINE_SPACING;
+        for line in wrap_text(text, false, BODY_SIZE, CONTENT_WIDTH) {
+            self.ensure_space(line_height);
+            self.y -= line_height;
+            self.draw_text(MARGIN, self.y, &line, false, BODY_SIZE, color);
+        }
+    }
+
+    fn table(&mut self, widths: &[f32], header: &[&str], rows: Vec<Vec<Cell>>) {
+        let line_height = TABLE_SIZE * LINE_SPACING;
+
+        self.table_header(widths, header);
+
+        for row in rows {
+            let wrapped: Vec<Vec<String>> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| {
+                    wrap_text(&cell.text, false, TABLE_SIZE, width - 2.0 * CELL_PADDING)
+                })
+                .collect();
+            let line_count = wrapped.iter().map(Vec::len).max().unwrap_or(1);
+            let row_height = line_count as f32 * line_height + 2.0 * CELL_PADDING;
+
+            if self.y - row_height < MARGIN {
+                self.new_page();
+                self.table_header(widths, header);
+            }
+
+            let top = self.y;
+            let mut x = MARGIN;
+            for ((cell, lines), width) in row.iter().zip(&wrapped).zip(widths) {
+                let color = if cell.link.is_some() {
+                    LINK_COLOR
+                } else {
+                    (0.0, 0.0, 0.0)
+                };
+                let mut baseline = top - CELL_PADDING;
+                for line in lines {
+                    baseline -= line_height;
+                    self.draw_text(
+                        x + CELL_PADDING,
+                        baseline + 2.0,
+                        line,
+                        false,
+                        TABLE_SIZE,
+                        color,
+                    );
+                }
+
+                if let Some(url) = &cell.link {
+                    self.page().links.push(Link {
+                        rect: Rect::new(x, top - row_height, x + width, top),
+                        url: url.clone(),
+                    });
+                }
+                x += width;
+            }
+
+            self.y = top - row_height;
+            self.rule(MARGIN + widths.iter().sum::<f32>(), 0.85, 0.5);
+        }
+    }
+
+    fn table_header(&mut self, widths: &[f32], header: &[&str]) {
+        let height = TABLE_SIZE * LINE_SPACING + 2.0 * CELL_PADDING;
+        self.ensure_space(height * 2.0);
+
+        let top = self.y;
+        let content = &mut self.page().content;
+        content.set_fill_rgb(0.93, 0.93, 0.93);
+        content.rect(MARGIN, top - height, widths.iter().sum(), height);
+        content.fill_nonzero();
+
+        let mut x = MARGIN;
+        for (label, width) in header.iter().zip(widths) {
+            let baseline = top - CELL_PADDING - TABLE_SIZE * LINE_SPACING + 2.0;
+            self.draw_text(
+                x + CELL_PADDING,
+                baseline,
+                label,
+                true,
+                TABLE_SIZE,
+                (0.0, 0.0, 0.0),
+            );
+            x += width;
+        }
+        self.y = top - height;
+    }
+
+    /// Serialize all pages, adding "Page n of N" footers
+    fn finish(self, title: &str) -> Vec<u8> {
+        let mut pdf = Pdf::new();
+        let mut next_id = 1;
+        let mut alloc = || {
+            let id = Ref::new(next_id);
+            next_id += 1;
+            id
+        };
+
+        let catalog_id = alloc();
+        let page_tree_id = alloc();
+        let regular_id = alloc();
+        let bold_id = alloc();
+        let info_id = alloc();
+        let page_ids: Vec<(Ref, Ref)> = self.pages.iter().map(|_| (alloc(), alloc())).collect();
+
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id)
+            .kids(page_ids.iter().map(|(page_id, _)| *page_id))
+            .count(page_ids.len() as i32);
+        pdf.type1_font(regular_id)
+            .base_font(Name(b"Helvetica"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+        pdf.type1_font(bold_id)
+            .base_font(Name(b"Helvetica-Bold"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+        pdf.document_info(info_id)
+            .title(TextStr(title))
+            .producer(TextStr("WorkdayDebrief"));
+
+        let page_count = self.pages.len();
+        for (index, (page, (page_id, content_id))) in
+            self.pages.into_iter().zip(page_ids).enumerate()
+        {
+            let mut content = page.content;
+            let footer = format!("Page {} of {}", index + 1, page_count);
+            let footer_size = 8.0;
+            content.begin_text();
+            content.set_fill_rgb(MUTED_COLOR.0, MUTED_COLOR.1, MUTED_COLOR.2);
+            content.set_font(REGULAR, footer_size);
+            content.next_line(
+                PAGE_WIDTH - MARGIN - text_width(&footer, false, footer_size),
+                MARGIN / 2.0,
+            );
+            content.show(Str(footer.as_bytes()));
+            content.end_text();
+
+            let mut page_writer = pdf.page(page_id);
+            page_writer
+                .media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+                .parent(page_tree_id)
+                .contents(content_id);
+            page_writer
+                .resources()
+                .fonts()
+                .pair(REGULAR, regular_id)
+                .pair(BOLD, bold_id);
+
+            if !page.links.is_empty() {
+                let mut annotations = page_writer.annotations();
+                for link in &page.links {
+                    let mut annotation = annotations.push();
+                    annotation.subtype(AnnotationType::Link).rect(link.rect);
+                    annotation
+                        .border_style()
+                        .width(0.0)
+                        .style(BorderType::Solid);
+                    annotation
+                        .action()
+                        .action_type(ActionType::Uri)
+                        .uri(Str(link.url.as_bytes()));
+                }
+            }
+            page_writer.finish();
+
+            pdf.stream(content_id, &content.finish());
+        }
+
+        pdf.finish()
+    }
+}
+
+fn ticket_rows(tickets: &[Ticket]) -> Vec<Vec<Cell>> {
+    tickets
+        .iter()
+        .map(|t| {
+            vec![
+                Cell {
+                    text: t.id.clone(),
+                    link: Some(t.url.clone()).filter(|url| !url.is_empty()),
+                },
+                Cell::text(t.title.clone()),
+                Cell::text(t.status.clone()),
+            ]
+        })
+        .collect()
+}
+
+/// `HH:MM` from an RFC 3339 timestamp, or the raw value if it does not parse
+fn clock_time(timestamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|dt| dt.format("%H:%M").to_string())
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+fn layout_summary(layout: &mut Layout, summary: SummaryMarkdownInput<'_>) {
+    layout.title(&format!("Work Summary — {}", summary.date));
+
+    layout.heading("Narrative");
+    if summary.narrative.is_empty() {
+        layout.paragraph("(No narrative)", MUTED_COLOR);
+    } else {
+        layout.paragraph(summary.narrative, (0.0, 0.0, 0.0));
+    }
+
+    let ticket_widths = [80.0, CONTENT_WIDTH - 180.0, 100.0];
+    if !summary.tickets_closed.is_empty() {
+        layout.heading(&format!(
+            "Tickets Closed ({})",
+            summary.tickets_closed.len()
+        ));
+        layout.table(
+            &ticket_widths,
+            &["Key", "Title", "Status"],
+            ticket_rows(summary.tickets_closed),
+        );
+    }
+
+    if !summary.tickets_in_progress.is_empty() {
+        layout.heading(&format!(
+            "In Progress ({})",
+            summary.tickets_in_progress.len()
+        ));
+        layout.table(
+            &ticket_widths,
+            &["Key", "Title", "Status"],
+            ticket_rows(summary.tickets_in_progress),
+        );
+    }
+
+    if !summary.meetings.is_empty() {
+        layout.heading(&format!(
+            "Meetings ({}, {}m total)",
+            summary.meetings.len(),
+            summary.meeting_minutes()
+        ));
+        let rows = summary
+            .meetings
+            .iter()
+            .map(|m| {
+                vec![
+                    Cell::text(format!("{}–{}", clock_time(&m.start), clock_time(&m.end))),
+                    Cell::text(m.title.clone()),
+                    Cell::text(format!("{}m", m.duration_minutes)),
+                ]
+            })
+            .collect();
+        layout.table(
+            &[80.0, CONTENT_WIDTH - 150.0, 70.0],
+            &["Time", "Meeting", "Duration"],
+            rows,
+        );
+    }
+
+    if let Some(breakdown) = summary.calendar() {
+        layout.heading("Calendar");
+        layout.paragraph(&breakdown.describe(), (0.0, 0.0, 0.0));
+    }
+
+    if summary.focus_hours > 0.0 {
+        layout.heading("Focus Time");
+        layout.paragraph(
+            &format!("{:.1} hours", summary.focus_hours),
+            (0.0, 0.0, 0.0),
+        );
+    }
+
+    if !summary.log_entries.is_empty() {
+        layout.heading("Activity Log");
+        let log = summary
+            .log_entries
+            .iter()
+            .map(|e| e.describe())
+            .collect::<Vec<_>>()
+            .join("\n");
+        layout.paragraph(&log, (0.0, 0.0, 0.0));
+    }
+
+    for (heading, text) in [
+        ("Blockers", summary.blockers),
+        ("Tomorrow's Priorities", summary.tomorrow_priorities),
+        ("Notes", summary.manual_notes),
+    ] {
+        if !text.is_empty() {
+            layout.heading(heading);
+            layout.paragraph(text, (0.0, 0.0, 0.0));
+        }
+    }
+}
+
+/// Render one summary as a PDF document
+pub fn render_summary_to_pdf(summary: SummaryMarkdownInput<'_>) -> Vec<u8> {
+    render_summaries_to_pdf(&[summary], &[])
+}
+
+/// Picks one section out of a structured narrative
+type Section = fn(&StructuredNarrative) -> &Vec<String>;
+
+/// One section of each day's structured narrative, listed by date
+fn rollup_sections(
+    summaries: &[SummaryMarkdownInput<'_>],
+    structured: &[Option<StructuredNarrative>],
+    section: Section,
+) -> String {
+    summaries
+        .iter()
+        .zip(structured)
+        .filter_map(|(summary, narrative)| Some((summary.date, narrative.as_ref()?)))
+        .flat_map(|(date, narrative)| {
+            section(narrative)
+                .iter()
+                .map(move |item| format!("• {}: {}", date, item))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render a rollup: an overview of the range followed by each day on its own page.
+/// Summaries are expected in date order; `structured` holds each day's parsed
+/// narrative sections, if any, and feeds the overview's highlights and risks.
+pub fn render_summaries_to_pdf(
+    summaries: &[SummaryMarkdownInput<'_>],
+    structured: &[Option<StructuredNarrative>],
+) -> Vec<u8> {
+    let mut layout = Layout::new();
+
+    let title = match summaries {
+        [] => "Work Summary".to_string(),
+        [only] => format!("Work Summary — {}", only.date),
+        [first, .., last] => format!("Work Summaries {} – {}", first.date, last.date),
+    };
+
+    if summaries.len() > 1 {
+        layout.title(&title);
+
+        let closed: usize = summaries.iter().map(|s| s.tickets_closed.len()).sum();
+        let meetings: usize = summaries.iter().map(|s| s.meetings.len()).sum();
+        let meeting_minutes: u32 = summaries.iter().map(|s| s.meeting_minutes()).sum();
+        let focus_hours: f32 = summaries.iter().map(|s| s.focus_hours).sum();
+
+        layout.heading("Overview");
+        layout.paragraph(
+            &format!(
+                "{} days, {} tickets closed, {} meetings ({}m), {:.1} focus hours",
+                summaries.len(),
+                closed,
+                meetings,
+                meeting_minutes,
+                focus_hours
+            ),
+            (0.0, 0.0, 0.0),
+        );
+
+        let sections: [(&str, Section); 2] = [
+            ("Highlights", |n| &n.accomplishments),
+            ("Risks", |n| &n.risks),
+        ];
+        for (heading, section) in sections {
+            let items = rollup_sections(summaries, structured, section);
+            if !items.is_empty() {
+                layout.heading(heading);
+                layout.paragraph(&items, (0.0, 0.0, 0.0));
+            }
+        }
+        layout.gap(6.0);
+    }
+
+    for summary in summaries {
+        if !layout.is_page_empty() {
+            layout.new_page();
+        }
+        layout_summary(&mut layout, *summary);
+    }
+
+    layout.finish(&title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_text_respects_width_and_newlines() {
+        let lines = wrap_text("one two three\nfour", false, 10.0, 50.0);
+        assert_eq!(lines, vec!["one two", "three", "four"]);
+
+        // Long words are split rather than overflowing
+        let lines = wrap_text(&"W".repeat(20), false, 10.0, 50.0);
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| text_width(line, false, 10.0) <= 50.0));
+    }
+
+    #[test]
+    fn encodes_typographic_characters_as_win_ansi() {
+        assert_eq!(
+            encode_win_ansi("a—b’é✓"),
+            vec![b'a', 0x97, b'b', 0x92, 0xe9, b'?']
+        );
+    }
+
+    #[test]
+    fn renders_pdf_with_link_annotations() {
+        let closed = vec![Ticket {
+            id: "PROJ-1".to_string(),
+            title: "Fix login".to_string(),
+            status: "Done".to_string(),
+            url: "https://jira/browse/PROJ-1".to_string(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }];
+        let summary = SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Shipped the login fix.",
+            tickets_closed: &closed,
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 3.5,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        };
+
+        let structured = StructuredNarrative {
+            accomplishments: vec!["Shipped the login fix".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            rollup_sections(
+                &[summary, summary],
+                &[None, Some(structured.clone())],
+                |n| { &n.accomplishments }
+            ),
+            "• 2026-02-14: Shipped the login fix"
+        );
+
+        let bytes = render_summaries_to_pdf(&[summary, summary], &[None, Some(structured)]);
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(bytes.starts_with(b"%PDF-"));
+        assert!(text.contains("/URI (https://jira/browse/PROJ-1)"));
+        assert!(text.contains("/Count 3"));
+    }
+}
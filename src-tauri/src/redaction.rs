@@ -0,0 +1,423 @@
+//! Redaction rules applied to aggregated data before it reaches the LLM prompt
+//! or a delivery target's renderer.
+//!
+//! A profile combines regex patterns, keyword lists, calendars whose events
+//! are always private, Google's private/confidential event visibility and
+//! Jira security levels. Every masked value is reported so the UI can show a
+//! preview of what a profile hides.
+
+use crate::aggregation::{AggregatedData, LogEntry, Meeting, Ticket};
+use crate::error::AppError;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// Title shown for meetings hidden by a calendar or visibility rule
+const PRIVATE_MEETING_TITLE: &str = "Private event";
+
+/// Title shown for tickets hidden by a security level rule
+const RESTRICTED_TICKET_TITLE: &str = "Restricted ticket";
+
+fn default_replacement() -> String {
+    "[redacted]".to_string()
+}
+
+/// Rules stored as JSON in a redaction profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionRules {
+    /// Regular expressions; every match is replaced
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Words or phrases matched case-insensitively on word boundaries
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Calendar ids whose events are always shown as "Private event"
+    #[serde(default)]
+    pub private_calendars: Vec<String>,
+    /// Hide events marked private or confidential in Google Calendar
+    #[serde(default)]
+    pub mask_private_events: bool,
+    /// Jira security levels whose tickets are hidden; `*` matches any level
+    #[serde(default)]
+    pub security_levels: Vec<String>,
+    #[serde(default = "default_replacement")]
+    pub replacement: String,
+}
+
+impl Default for RedactionRules {
+    fn default() -> Self {
+        RedactionRules {
+            patterns: Vec::new(),
+            keywords: Vec::new(),
+            private_calendars: Vec::new(),
+            mask_private_events: false,
+            security_levels: Vec::new(),
+            replacement: default_replacement(),
+        }
+    }
+}
+
+/// A saved, named set of rules
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionProfile {
+    pub id: i64,
+    pub name: String,
+    pub rules: RedactionRules,
+}
+
+/// One masked value, for the preview
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Redaction {
+    /// Where the value was, e.g. "meetings[2].title" or "blockers"
+    pub field: String,
+    /// Which rule matched, e.g. "keyword", "pattern /ACME-\d+/"
+    pub rule: String,
+    /// The text that was hidden
+    pub original: String,
+}
+
+impl Redaction {
+    /// Whether a whole meeting or ticket title was hidden, rather than a match
+    /// inside it
+    fn hides_title(&self) -> bool {
+        matches!(self.rule.as_str(), "private calendar" | "private event")
+            || self.rule.starts_with("security level ")
+    }
+}
+
+/// Compiled rules
+pub struct Redactor {
+    text_rules: Vec<(String, Regex)>,
+    private_calendars: Vec<String>,
+    mask_private_events: bool,
+    security_levels: Vec<String>,
+    replacement: String,
+}
+
+impl Redactor {
+    /// Compile a profile's rules; invalid patterns are reported by position
+    pub fn new(rules: &RedactionRules) -> Result<Redactor, AppError> {
+        let mut text_rules = Vec::new();
+
+        for (i, pattern) in rules.patterns.iter().enumerate() {
+            let regex = Regex::new(pattern).map_err(|e| {
+                AppError::NotConfigured(format!("Redaction pattern {} is invalid: {}", i + 1, e))
+            })?;
+            text_rules.push((format!("pattern /{}/", pattern), regex));
+        }
+
+        let keywords: Vec<String> = rules
+            .keywords
+            .iter()
+            .map(|k| k.trim())
+            .filter(|k| !k.is_empty())
+            .map(regex::escape)
+            .collect();
+        if !keywords.is_empty() {
+            let regex = Regex::new(&format!(r"(?i)\b(?:{})\b", keywords.join("|")))
+                .map_err(|e| AppError::NotConfigured(format!("Invalid keyword list: {}", e)))?;
+            text_rules.push(("keyword".to_string(), regex));
+        }
+
+        Ok(Redactor {
+            text_rules,
+            private_calendars: rules.private_calendars.clone(),
+            mask_private_events: rules.mask_private_events,
+            security_levels: rules.security_levels.clone(),
+            replacement: rules.replacement.clone(),
+        })
+    }
+
+    /// Replace every pattern and keyword match in `text`
+    pub fn text(&self, text: &str, field: &str, report: &mut Vec<Redaction>) -> String {
+        let mut result = text.to_string();
+        for (rule, regex) in &self.text_rules {
+            for found in regex.find_iter(&result) {
+                report.push(Redaction {
+                    field: field.to_string(),
+                    rule: rule.clone(),
+                    original: found.as_str().to_string(),
+                });
+            }
+            result = regex
+                .replace_all(&result, regex::NoExpand(&self.replacement))
+                .into_owned();
+        }
+        result
+    }
+
+    /// Replace mentions of meeting and ticket titles hidden earlier in `report`,
+    /// e.g. a private event named in the narrative
+    fn hidden_titles(&self, text: &str, field: &str, report: &mut Vec<Redaction>) -> String {
+        let mut titles: Vec<&str> = report
+            .iter()
+            .filter(|r| r.hides_title())
+            .map(|r| r.original.trim())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if titles.is_empty() {
+            return text.to_string();
+        }
+        // Longest first, so a title containing another is replaced whole
+        titles.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        titles.dedup();
+        let escaped: Vec<String> = titles.into_iter().map(regex::escape).collect();
+        let regex = match Regex::new(&format!("(?i){}", escaped.join("|"))) {
+            Ok(regex) => regex,
+            Err(_) => return text.to_string(),
+        };
+
+        let found: Vec<Redaction> = regex
+            .find_iter(text)
+            .map(|found| Redaction {
+                field: field.to_string(),
+                rule: "hidden title".to_string(),
+                original: found.as_str().to_string(),
+            })
+            .collect();
+        report.extend(found);
+        regex
+            .replace_all(text, regex::NoExpand(&self.replacement))
+            .into_owned()
+    }
+
+    /// Redact free text: hidden meeting and ticket titles already in `report`,
+    /// then every pattern and keyword match
+    pub fn free_text(&self, text: &str, field: &str, report: &mut Vec<Redaction>) -> String {
+        let text = self.hidden_titles(text, field, report);
+        self.text(&text, field, report)
+    }
+
+    fn hides_meeting(&self, meeting: &Meeting) -> Option<&'static str> {
+        let calendar = meeting.calendar_id.as_deref().unwrap_or("primary");
+        if self.private_calendars.iter().any(|c| c == calendar) {
+            return Some("private calendar");
+        }
+        let private = matches!(
+            meeting.visibility.as_deref(),
+            Some("private") | Some("confidential")
+        );
+        (self.mask_private_events && private).then_some("private event")
+    }
+
+    fn hides_ticket(&self, ticket: &Ticket) -> bool {
+        ticket.security_level.as_deref().is_some_and(|level| {
+            self.security_levels
+                .iter()
+                .any(|l| l == "*" || l.eq_ignore_ascii_case(level))
+        })
+    }
+
+    pub fn meetings(&self, meetings: &mut [Meeting], report: &mut Vec<Redaction>) {
+        for (i, meeting) in meetings.iter_mut().enumerate() {
+            let field = format!("meetings[{}].title", i);
+            match self.hides_meeting(meeting) {
+                Some(rule) => {
+                    meeting.organizer = None;
+                    report.push(Redaction {
+                        field,
+                        rule: rule.to_string(),
+                        original: std::mem::replace(
+                            &mut meeting.title,
+                            PRIVATE_MEETING_TITLE.to_string(),
+                        ),
+                    });
+                }
+                None => meeting.title = self.text(&meeting.title, &field, report),
+            }
+        }
+    }
+
+    pub fn tickets(&self, tickets: &mut [Ticket], section: &str, report: &mut Vec<Redaction>) {
+        for (i, ticket) in tickets.iter_mut().enumerate() {
+            let field = format!("{}[{}].title", section, i);
+            if self.hides_ticket(ticket) {
+                report.push(Redaction {
+                    field,
+                    rule: format!(
+                        "security level {}",
+                        ticket.security_level.as_deref().unwrap_or_default()
+                    ),
+                    original: std::mem::replace(
+                        &mut ticket.title,
+                        RESTRICTED_TICKET_TITLE.to_string(),
+                    ),
+                });
+            } else {
+                ticket.title = self.text(&ticket.title, &field, report);
+            }
+        }
+    }
+
+    pub fn log_entries(&self, entries: &mut [LogEntry], report: &mut Vec<Redaction>) {
+        for (i, entry) in entries.iter_mut().enumerate() {
+            entry.text = self.free_text(&entry.text, &format!("logEntries[{}].text", i), report);
+        }
+    }
+
+    /// Redacted copy of the aggregated data, e.g. for the LLM prompt; run it
+    /// before [`Redactor::free_text`] so hidden titles are known
+    pub fn aggregated(&self, data: &AggregatedData, report: &mut Vec<Redaction>) -> AggregatedData {
+        let mut data = data.clone();
+        self.tickets(&mut data.tickets_closed, "ticketsClosed", report);
+        self.tickets(&mut data.tickets_in_progress, "ticketsInProgress", report);
+        self.meetings(&mut data.meetings, report);
+        self.log_entries(&mut data.log_entries, report);
+        data
+    }
+}
+
+fn profile_from_row(row: sqlx::sqlite::SqliteRow) -> Result<RedactionProfile, AppError> {
+    let id: i64 = row.get("id");
+    let rules = serde_json::from_str(&row.get::<String, _>("rules")).map_err(|e| {
+        AppError::DatabaseError(format!("Redaction profile {} is corrupt: {}", id, e))
+    })?;
+    Ok(RedactionProfile {
+        id,
+        name: row.get("name"),
+        rules,
+    })
+}
+
+pub async fn list_profiles(db: &SqlitePool) -> Result<Vec<RedactionProfile>, AppError> {
+    sqlx::query("SELECT id, name, rules FROM redaction_profiles ORDER BY name")
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(profile_from_row)
+        .collect()
+}
+
+/// Compiled rules of a saved profile; a missing profile is a configuration error
+pub async fn load_redactor(db: &SqlitePool, profile_id: i64) -> Result<Redactor, AppError> {
+    let row = sqlx::query("SELECT id, name, rules FROM redaction_profiles WHERE id = ?1")
+        .bind(profile_id)
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotConfigured(format!("Redaction profile {} no longer exists", profile_id))
+        })?;
+    Redactor::new(&profile_from_row(row)?.rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meeting(title: &str, visibility: Option<&str>, calendar: Option<&str>) -> Meeting {
+        Meeting {
+            title: title.to_string(),
+            start: "2026-02-14T09:00:00Z".to_string(),
+            end: "2026-02-14T09:30:00Z".to_string(),
+            duration_minutes: 30,
+            visibility: visibility.map(str::to_string),
+            calendar_id: calendar.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn masks_keywords_patterns_and_private_events() {
+        let redactor = Redactor::new(&RedactionRules {
+            patterns: vec![r"INC-\d+".to_string()],
+            keywords: vec!["Acme Corp".to_string(), "performance review".to_string()],
+            private_calendars: vec!["hr@example.com".to_string()],
+            mask_private_events: true,
+            ..Default::default()
+        })
+        .expect("rules should compile");
+
+        let mut meetings = vec![
+            meeting("ACME CORP escalation for INC-4411", None, None),
+            meeting("1:1", Some("private"), None),
+            meeting("Benefits briefing", None, Some("hr@example.com")),
+            meeting("Standup", Some("public"), None),
+        ];
+        let mut report = Vec::new();
+        redactor.meetings(&mut meetings, &mut report);
+
+        let titles: Vec<&str> = meetings.iter().map(|m| m.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "[redacted] escalation for [redacted]",
+                "Private event",
+                "Private event",
+                "Standup"
+            ]
+        );
+        assert_eq!(report.len(), 4);
+        assert_eq!(report[0].original, "INC-4411");
+        assert_eq!(report[1].original, "ACME CORP");
+        assert_eq!(report[3].rule, "private calendar");
+
+        // Keywords match whole words only
+        let mut report = Vec::new();
+        assert_eq!(
+            redactor.text("Acme Corporation", "notes", &mut report),
+            "Acme Corporation"
+        );
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn hides_tickets_by_security_level() {
+        let redactor = Redactor::new(&RedactionRules {
+            security_levels: vec!["Internal".to_string()],
+            ..Default::default()
+        })
+        .expect("rules should compile");
+        let mut tickets = vec![Ticket {
+            id: "SEC-1".to_string(),
+            title: "Rotate leaked key".to_string(),
+            status: "Done".to_string(),
+            url: String::new(),
+            resolved_at: None,
+            security_level: Some("internal".to_string()),
+            activity: Default::default(),
+        }];
+        let mut report = Vec::new();
+        redactor.tickets(&mut tickets, "ticketsClosed", &mut report);
+
+        assert_eq!(tickets[0].title, "Restricted ticket");
+        assert_eq!(report[0].field, "ticketsClosed[0].title");
+        assert_eq!(report[0].original, "Rotate leaked key");
+    }
+
+    #[test]
+    fn hidden_titles_are_masked_in_free_text() {
+        let redactor = Redactor::new(&RedactionRules {
+            mask_private_events: true,
+            ..Default::default()
+        })
+        .expect("rules should compile");
+        let mut meetings = vec![meeting("Interview with Dana", Some("private"), None)];
+        let mut report = Vec::new();
+        redactor.meetings(&mut meetings, &mut report);
+
+        let narrative = redactor.free_text(
+            "Prepared for the interview with Dana, then shipped the fix.",
+            "narrative",
+            &mut report,
+        );
+        assert_eq!(
+            narrative,
+            "Prepared for the [redacted], then shipped the fix."
+        );
+        assert_eq!(report[1].field, "narrative");
+        assert_eq!(report[1].rule, "hidden title");
+        assert_eq!(report[1].original, "interview with Dana");
+    }
+
+    #[test]
+    fn invalid_patterns_are_rejected() {
+        let rules = RedactionRules {
+            patterns: vec!["(unclosed".to_string()],
+            ..Default::default()
+        };
+        assert!(Redactor::new(&rules).is_err());
+    }
+}
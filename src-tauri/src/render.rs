@@ -0,0 +1,416 @@
+use crate::aggregation::Ticket;
+use crate::markdown::{self, SummaryMarkdownInput};
+use serde::{Deserialize, Serialize};
+
+/// Turns a summary into the text a delivery target sends
+pub trait SummaryRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String;
+
+    /// MIME type of the rendered text
+    fn content_type(&self) -> &'static str;
+}
+
+pub struct CommonMarkRenderer;
+pub struct HtmlRenderer;
+pub struct PlainTextRenderer;
+pub struct JsonRenderer;
+pub struct SlackMrkdwnRenderer;
+pub struct JiraWikiRenderer;
+
+impl SummaryRenderer for CommonMarkRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        markdown::render_summary_to_markdown(summary)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/markdown"
+    }
+}
+
+impl SummaryRenderer for HtmlRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        markdown::render_summary_to_html(summary)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/html"
+    }
+}
+
+impl SummaryRenderer for PlainTextRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        markdown::render_summary_to_text(summary)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/plain"
+    }
+}
+
+impl SummaryRenderer for JsonRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        serde_json::to_string_pretty(&markdown::render_summary_to_json(summary)).unwrap_or_default()
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/json"
+    }
+}
+
+impl SummaryRenderer for SlackMrkdwnRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        render_summary_to_slack(summary)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/plain"
+    }
+}
+
+impl SummaryRenderer for JiraWikiRenderer {
+    fn render(&self, summary: SummaryMarkdownInput<'_>) -> String {
+        render_summary_to_jira_wiki(summary)
+    }
+
+    fn content_type(&self) -> &'static str {
+        "text/plain"
+    }
+}
+
+/// Renderer choice stored in a delivery config
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RendererKind {
+    #[default]
+    Markdown,
+    Html,
+    Text,
+    Json,
+    SlackMrkdwn,
+    JiraWiki,
+}
+
+impl RendererKind {
+    pub fn renderer(self) -> &'static dyn SummaryRenderer {
+        match self {
+            RendererKind::Markdown => &CommonMarkRenderer,
+            RendererKind::Html => &HtmlRenderer,
+            RendererKind::Text => &PlainTextRenderer,
+            RendererKind::Json => &JsonRenderer,
+            RendererKind::SlackMrkdwn => &SlackMrkdwnRenderer,
+            RendererKind::JiraWiki => &JiraWikiRenderer,
+        }
+    }
+
+    pub fn render(self, summary: SummaryMarkdownInput<'_>) -> String {
+        self.renderer().render(summary)
+    }
+
+    pub fn content_type(self) -> &'static str {
+        self.renderer().content_type()
+    }
+
+    /// Name as stored in delivery configs
+    pub fn name(self) -> &'static str {
+        match self {
+            RendererKind::Markdown => "markdown",
+            RendererKind::Html => "html",
+            RendererKind::Text => "text",
+            RendererKind::Json => "json",
+            RendererKind::SlackMrkdwn => "slackMrkdwn",
+            RendererKind::JiraWiki => "jiraWiki",
+        }
+    }
+}
+
+/// Slack only needs `&`, `<` and `>` escaped
+fn escape_slack(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Render the summary as Slack mrkdwn (`*bold*`, `<url|text>` links)
+pub fn render_summary_to_slack(input: SummaryMarkdownInput<'_>) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("*Work Summary — {}*", escape_slack(input.date)));
+    lines.push(String::new());
+    lines.push(if input.narrative.is_empty() {
+        "_(No narrative)_".to_string()
+    } else {
+        escape_slack(input.narrative)
+    });
+    lines.push(String::new());
+
+    let ticket_line = |t: &Ticket| {
+        let key = if t.url.is_empty() {
+            escape_slack(&t.id)
+        } else {
+            format!("<{}|{}>", t.url, escape_slack(&t.id))
+        };
+        format!("• {} - {}", key, escape_slack(&t.title))
+    };
+
+    for (heading, tickets) in [
+        ("Tickets Closed", input.tickets_closed),
+        ("In Progress", input.tickets_in_progress),
+    ] {
+        if !tickets.is_empty() {
+            lines.push(format!("*{} ({})*", heading, tickets.len()));
+            lines.extend(tickets.iter().map(ticket_line));
+            lines.push(String::new());
+        }
+    }
+
+    if !input.meetings.is_empty() {
+        lines.push(format!(
+            "*Meetings ({}, {}m total)*",
+            input.meetings.len(),
+            input.meeting_minutes()
+        ));
+        for meeting in input.meetings {
+            lines.push(format!(
+                "• {} ({})",
+                escape_slack(&meeting.title),
+                meeting.length_label()
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    if let Some(breakdown) = input.calendar() {
+        lines.push(format!("*Calendar:* {}", breakdown.describe()));
+        lines.push(String::new());
+    }
+
+    if input.focus_hours > 0.0 {
+        lines.push(format!("*Focus Time:* {:.1} hours", input.focus_hours));
+        lines.push(String::new());
+    }
+
+    if !input.log_entries.is_empty() {
+        lines.push("*Activity Log*".to_string());
+        for entry in input.log_entries {
+            lines.push(format!("• {}", escape_slack(&entry.describe())));
+        }
+        lines.push(String::new());
+    }
+
+    for (heading, text) in [
+        ("Blockers", input.blockers),
+        ("Tomorrow's Priorities", input.tomorrow_priorities),
+        ("Notes", input.manual_notes),
+    ] {
+        if !text.is_empty() {
+            lines.push(format!("*{}*", heading));
+            lines.push(escape_slack(text));
+            lines.push(String::new());
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Escape characters Jira wiki markup would otherwise interpret inside plain text
+fn escape_jira(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '[' | ']' | '{' | '}' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Render the summary as Jira/Confluence wiki markup
+pub fn render_summary_to_jira_wiki(input: SummaryMarkdownInput<'_>) -> String {
+    let mut lines = Vec::new();
+
+    lines.push(format!("h1. Work Summary — {}", input.date));
+    lines.push(String::new());
+    lines.push("h2. Narrative".to_string());
+    lines.push(if input.narrative.is_empty() {
+        "_(No narrative)_".to_string()
+    } else {
+        escape_jira(input.narrative)
+    });
+    lines.push(String::new());
+
+    let ticket_line = |t: &Ticket| {
+        let key = if t.url.is_empty() {
+            escape_jira(&t.id)
+        } else {
+            format!("[{}|{}]", escape_jira(&t.id), t.url)
+        };
+        format!("* {} - {}", key, escape_jira(&t.title))
+    };
+
+    for (heading, tickets) in [
+        ("Tickets Closed", input.tickets_closed),
+        ("In Progress", input.tickets_in_progress),
+    ] {
+        if !tickets.is_empty() {
+            lines.push(format!("h2. {} ({})", heading, tickets.len()));
+            lines.extend(tickets.iter().map(ticket_line));
+            lines.push(String::new());
+        }
+    }
+
+    if !input.meetings.is_empty() {
+        lines.push(format!(
+            "h2. Meetings ({}, {}m total)",
+            input.meetings.len(),
+            input.meeting_minutes()
+        ));
+        lines.push("||Meeting||Duration||".to_string());
+        for meeting in input.meetings {
+            lines.push(format!(
+                "|{}|{}|",
+                escape_jira(&meeting.title),
+                meeting.length_label()
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    if let Some(breakdown) = input.calendar() {
+        lines.push("h2. Calendar".to_string());
+        lines.push(breakdown.describe());
+        lines.push(String::new());
+    }
+
+    if input.focus_hours > 0.0 {
+        lines.push("h2. Focus Time".to_string());
+        lines.push(format!("{:.1} hours", input.focus_hours));
+        lines.push(String::new());
+    }
+
+    if !input.log_entries.is_empty() {
+        lines.push("h2. Activity Log".to_string());
+        for entry in input.log_entries {
+            lines.push(format!("* {}", escape_jira(&entry.describe())));
+        }
+        lines.push(String::new());
+    }
+
+    for (heading, text) in [
+        ("Blockers", input.blockers),
+        ("Tomorrow's Priorities", input.tomorrow_priorities),
+        ("Notes", input.manual_notes),
+    ] {
+        if !text.is_empty() {
+            lines.push(format!("h2. {}", heading));
+            lines.push(escape_jira(text));
+            lines.push(String::new());
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregation::meetings::CalendarBreakdown;
+    use crate::aggregation::Meeting;
+
+    fn sample<'a>(tickets: &'a [Ticket]) -> SummaryMarkdownInput<'a> {
+        SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "Fixed <script> & [brackets]",
+            tickets_closed: tickets,
+            tickets_in_progress: &[],
+            meetings: &[],
+            focus_hours: 0.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        }
+    }
+
+    #[test]
+    fn slack_and_jira_renderers_escape_and_link() {
+        let tickets = vec![Ticket {
+            id: "PROJ-1".to_string(),
+            title: "Fix login".to_string(),
+            status: "Done".to_string(),
+            url: "https://jira/browse/PROJ-1".to_string(),
+            resolved_at: None,
+            security_level: None,
+            activity: Default::default(),
+        }];
+
+        let slack = RendererKind::SlackMrkdwn.render(sample(&tickets));
+        assert!(slack.contains("Fixed &lt;script&gt; &amp; [brackets]"));
+        assert!(slack.contains("• <https://jira/browse/PROJ-1|PROJ-1> - Fix login"));
+
+        let jira = RendererKind::JiraWiki.render(sample(&tickets));
+        assert!(jira.contains("Fixed <script> & \\[brackets\\]"));
+        assert!(jira.contains("* [PROJ-1|https://jira/browse/PROJ-1] - Fix login"));
+    }
+
+    #[test]
+    fn meeting_total_counts_overlaps_once() {
+        let meetings: Vec<Meeting> = ["Standup", "Design review"]
+            .iter()
+            .map(|title| Meeting {
+                title: title.to_string(),
+                duration_minutes: 60,
+                ..Default::default()
+            })
+            .collect();
+        let breakdown = CalendarBreakdown {
+            collaboration_minutes: 90,
+            ..Default::default()
+        };
+        let summary = SummaryMarkdownInput {
+            meetings: &meetings,
+            calendar_breakdown: Some(&breakdown),
+            ..sample(&[])
+        };
+
+        for kind in [
+            RendererKind::Markdown,
+            RendererKind::Html,
+            RendererKind::Text,
+            RendererKind::SlackMrkdwn,
+            RendererKind::JiraWiki,
+        ] {
+            let rendered = kind.render(summary);
+            assert!(rendered.contains("(2, 90m total)"), "{}", kind.name());
+            assert!(rendered.contains("1h 30m in meetings"), "{}", kind.name());
+        }
+        assert!(RendererKind::JiraWiki
+            .render(summary)
+            .contains("|Standup|60m|"));
+
+        let legacy = SummaryMarkdownInput {
+            calendar_breakdown: Some(&CalendarBreakdown::default()),
+            ..summary
+        };
+        let markdown = RendererKind::Markdown.render(legacy);
+        assert!(markdown.contains("(2, 120m total)"));
+        assert!(!markdown.contains("## Calendar"));
+    }
+
+    #[test]
+    fn renderer_kind_deserializes_camel_case() {
+        let kind: RendererKind =
+            serde_json::from_str("\"slackMrkdwn\"").expect("renderer kind should deserialize");
+        assert_eq!(kind, RendererKind::SlackMrkdwn);
+        assert_eq!(kind.content_type(), "text/plain");
+
+        for kind in [
+            RendererKind::Markdown,
+            RendererKind::Html,
+            RendererKind::JiraWiki,
+        ] {
+            let name = serde_json::to_value(kind).expect("renderer kind should serialize");
+            assert_eq!(name, kind.name());
+        }
+    }
+}
@@ -272,10 +272,19 @@ fn save_secrets_with_passphrase(
 pub mod keys {
     pub const SMTP_PASSWORD: &str = "smtp_password";
     pub const SLACK_WEBHOOK_URL: &str = "slack_webhook_url";
+    pub const TEAMS_WEBHOOK_URL: &str = "teams_webhook_url";
+    pub const DISCORD_WEBHOOK_URL: &str = "discord_webhook_url";
+    pub const MATTERMOST_WEBHOOK_URL: &str = "mattermost_webhook_url";
+    pub const WEBHOOK_SECRETS: &str = "webhook_secrets";
     pub const JIRA_API_TOKEN: &str = "jira_api_token";
     pub const JIRA_EMAIL: &str = "jira_email";
+    /// Single-account token from before multiple accounts were supported
     pub const GOOGLE_REFRESH_TOKEN: &str = "google_refresh_token";
+    /// JSON object of refresh tokens keyed by account email
+    pub const GOOGLE_REFRESH_TOKENS: &str = "google_refresh_tokens";
     pub const TOGGL_API_TOKEN: &str = "toggl_api_token";
+    pub const CLOCKIFY_API_KEY: &str = "clockify_api_key";
+    pub const HARVEST_ACCESS_TOKEN: &str = "harvest_access_token";
     pub const OAUTH_CSRF_TOKEN: &str = "oauth_csrf_token";
     pub const OAUTH_PKCE_VERIFIER: &str = "oauth_pkce_verifier";
 }
@@ -0,0 +1,251 @@
+//! User-defined output templates rendered with minijinja (Jinja2 syntax).
+//!
+//! Templates see `date`, `narrative`, `tickets_closed`, `tickets_in_progress`,
+//! `tickets`, `meetings`, `meeting_minutes`, `calendar`, `focus_hours`,
+//! `blockers`, `tomorrow_priorities`, `notes` and `log`. Tickets have `id`, `title`,
+//! `status`, `url` and `resolvedAt`; meetings have `title`, `start`, `end` and
+//! `durationMinutes`; log entries have `time` (local `HH:MM`), `text`,
+//! `ticketKey` and `tags`; `calendar` has `collaborationMinutes`,
+//! `focusBlockMinutes`, `outOfOfficeMinutes` and `longestGapMinutes`.
+
+use crate::aggregation::{LogEntry, Meeting, Ticket};
+use crate::error::AppError;
+use crate::markdown::SummaryMarkdownInput;
+use chrono::format::{Item, StrftimeItems};
+use minijinja::{Environment, Error, ErrorKind, UndefinedBehavior, Value};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryTemplate {
+    pub id: i64,
+    pub name: String,
+    pub body: String,
+    pub updated_at: String,
+}
+
+/// `{{ date | date("%A %d %B") }}`; accepts `YYYY-MM-DD` or RFC 3339 values
+fn date_filter(value: String, format: Option<String>) -> Result<String, Error> {
+    let format = format.unwrap_or_else(|| "%Y-%m-%d".to_string());
+    let items: Vec<Item> = StrftimeItems::new(&format).collect();
+    if items.contains(&Item::Error) {
+        return Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!("date filter has an invalid format '{}'", format),
+        ));
+    }
+
+    let mut formatted = String::new();
+    let written = if let Ok(date) = chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+        write!(formatted, "{}", date.format_with_items(items.iter()))
+    } else {
+        let dt = chrono::DateTime::parse_from_rfc3339(&value).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidOperation,
+                format!("date filter cannot parse '{}'", value),
+            )
+        })?;
+        write!(formatted, "{}", dt.format_with_items(items.iter()))
+    };
+    // A date without a time cannot fill in %H and the like
+    written.map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidOperation,
+            format!("date filter cannot format '{}' as '{}'", value, format),
+        )
+    })?;
+    Ok(formatted)
+}
+
+/// `{{ meeting.start | time }}` gives `HH:MM`
+fn time_filter(value: String) -> Result<String, Error> {
+    date_filter(value, Some("%H:%M".to_string()))
+}
+
+/// `{{ meeting.durationMinutes | duration }}` gives `1h 30m`
+fn duration_filter(minutes: i64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// `{{ focus_hours | hours }}` gives `3.5h`
+fn hours_filter(hours: f64) -> String {
+    format!("{:.1}h", hours)
+}
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    // Typos in field names fail loudly instead of rendering as empty strings
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.add_filter("date", date_filter);
+    env.add_filter("time", time_filter);
+    env.add_filter("duration", duration_filter);
+    env.add_filter("hours", hours_filter);
+    env
+}
+
+fn template_context(summary: SummaryMarkdownInput<'_>) -> Value {
+    let tickets: Vec<&Ticket> = summary
+        .tickets_closed
+        .iter()
+        .chain(summary.tickets_in_progress.iter())
+        .collect();
+    let log: Vec<serde_json::Value> = summary
+        .log_entries
+        .iter()
+        .map(|entry| {
+            serde_json::json!({
+                "time": entry.time_of_day(),
+                "text": entry.text,
+                "ticketKey": entry.ticket_key,
+                "tags": entry.tags,
+            })
+        })
+        .collect();
+
+    Value::from_serialize(serde_json::json!({
+        "date": summary.date,
+        "narrative": summary.narrative,
+        "tickets_closed": summary.tickets_closed,
+        "tickets_in_progress": summary.tickets_in_progress,
+        "tickets": tickets,
+        "meetings": summary.meetings,
+        "meeting_minutes": summary.meeting_minutes(),
+        "calendar": summary.calendar(),
+        "focus_hours": (summary.focus_hours as f64 * 10.0).round() / 10.0,
+        "blockers": summary.blockers,
+        "tomorrow_priorities": summary.tomorrow_priorities,
+        "notes": summary.manual_notes,
+        "log": log,
+    }))
+}
+
+/// "line 3: unknown filter" style message for the UI
+fn describe_error(error: &Error) -> AppError {
+    let detail = error
+        .detail()
+        .map(str::to_string)
+        .unwrap_or_else(|| error.kind().to_string());
+    match error.line() {
+        Some(line) => AppError::TemplateError(format!("line {}: {}", line, detail)),
+        None => AppError::TemplateError(detail),
+    }
+}
+
+/// Render a user template against a summary
+pub fn render_template(body: &str, summary: SummaryMarkdownInput<'_>) -> Result<String, AppError> {
+    environment()
+        .render_str(body, template_context(summary))
+        .map_err(|e| describe_error(&e))
+}
+
+/// Check syntax and render once against a sample summary, so unknown fields
+/// and filters are reported at save time rather than at delivery
+pub fn validate_template(body: &str) -> Result<(), AppError> {
+    if body.trim().is_empty() {
+        return Err(AppError::TemplateError("Template is empty".to_string()));
+    }
+
+    let tickets = [Ticket {
+        id: "PROJ-1".to_string(),
+        title: "Sample ticket".to_string(),
+        status: "Done".to_string(),
+        url: "https://example.atlassian.net/browse/PROJ-1".to_string(),
+        resolved_at: Some("2026-02-14T15:00:00Z".to_string()),
+        security_level: None,
+        activity: Default::default(),
+    }];
+    let meetings = [Meeting {
+        title: "Standup".to_string(),
+        start: "2026-02-14T09:00:00Z".to_string(),
+        end: "2026-02-14T09:15:00Z".to_string(),
+        duration_minutes: 15,
+        visibility: None,
+        calendar_id: None,
+        ..Default::default()
+    }];
+    let log_entries = [LogEntry {
+        id: 1,
+        logged_at: "2026-02-14T10:42:00Z".to_string(),
+        text: "Found the cache bug".to_string(),
+        ticket_key: Some("PROJ-1".to_string()),
+        tags: vec!["debugging".to_string()],
+    }];
+    let sample = SummaryMarkdownInput {
+        date: "2026-02-14",
+        narrative: "Sample narrative.",
+        tickets_closed: &tickets,
+        tickets_in_progress: &tickets,
+        meetings: &meetings,
+        focus_hours: 4.5,
+        blockers: "Sample blocker",
+        tomorrow_priorities: "Sample priority",
+        manual_notes: "Sample note",
+        log_entries: &log_entries,
+        calendar_breakdown: None,
+    };
+
+    render_template(body, sample).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STANDUP: &str = "*Yesterday ({{ date | date(\"%a %d %b\") }})*\n\
+        {% for t in tickets_closed %}- {{ t.id }} {{ t.title }}\n{% endfor %}\
+        {% for m in meetings %}- {{ m.title }} at {{ m.start | time }} ({{ m.durationMinutes | duration }})\n{% endfor %}\
+        Focus: {{ focus_hours | hours }}\n\
+        {% if blockers %}Blockers: {{ blockers }}{% endif %}";
+
+    #[test]
+    fn standup_template_renders_with_filters() {
+        assert!(validate_template(STANDUP).is_ok());
+
+        let meetings = [Meeting {
+            title: "Planning".to_string(),
+            start: "2026-02-14T13:30:00Z".to_string(),
+            end: "2026-02-14T15:00:00Z".to_string(),
+            duration_minutes: 90,
+            visibility: None,
+            calendar_id: None,
+            ..Default::default()
+        }];
+        let summary = SummaryMarkdownInput {
+            date: "2026-02-14",
+            narrative: "",
+            tickets_closed: &[],
+            tickets_in_progress: &[],
+            meetings: &meetings,
+            focus_hours: 2.0,
+            blockers: "",
+            tomorrow_priorities: "",
+            manual_notes: "",
+            log_entries: &[],
+            calendar_breakdown: None,
+        };
+
+        assert_eq!(
+            render_template(STANDUP, summary).expect("template should render"),
+            "*Yesterday (Sat 14 Feb)*\n- Planning at 13:30 (1h 30m)\nFocus: 2.0h\n"
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_template_line() {
+        let error =
+            validate_template("Title\n{{ narative }}").expect_err("misspelt field should fail");
+        assert!(error.to_string().contains("line 2"), "{}", error);
+
+        let error = validate_template("ok\nok\n{% for t in tickets %}")
+            .expect_err("unclosed loop should fail");
+        assert!(error.to_string().contains("line 3"), "{}", error);
+
+        assert!(validate_template("{{ date | date(\"%Q\") }}").is_err());
+        assert!(validate_template("{{ date | date(\"%H:%M\") }}").is_err());
+    }
+}
@@ -0,0 +1,417 @@
+//! Built-in time tracker for users without an external service.
+//!
+//! Entries live in the `time_entries` table with UTC RFC 3339 timestamps, so
+//! range queries compare them as text. At most one entry is running (has no
+//! `stopped_at`); starting a task stops the running one at the same instant.
+
+use crate::aggregation::day::SummaryDay;
+use crate::aggregation::time::{mentions_key, TimeEntry};
+use crate::error::AppError;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// What is being worked on
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimerTask {
+    pub description: String,
+    /// Ticket the time counts towards, e.g. "PROJ-123"
+    pub jira_key: Option<String>,
+    pub project: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl TimerTask {
+    /// Trim fields and check the Jira key looks like one
+    pub fn normalized(mut self) -> Result<TimerTask, AppError> {
+        self.description = self.description.trim().to_string();
+        self.jira_key = self
+            .jira_key
+            .map(|k| k.trim().to_ascii_uppercase())
+            .filter(|k| !k.is_empty());
+        self.project = self
+            .project
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty());
+        self.tags = self
+            .tags
+            .into_iter()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        if let Some(key) = &self.jira_key {
+            if !is_issue_key(key) {
+                return Err(AppError::NotConfigured(format!(
+                    "'{}' is not a Jira issue key (expected e.g. PROJ-123)",
+                    key
+                )));
+            }
+        }
+        Ok(self)
+    }
+}
+
+fn is_issue_key(key: &str) -> bool {
+    match key.split_once('-') {
+        Some((project, number)) => {
+            project.starts_with(|c: char| c.is_ascii_alphabetic())
+                && project
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// A stored timer entry
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerEntry {
+    pub id: i64,
+    #[serde(flatten)]
+    pub task: TimerTask,
+    /// RFC 3339, UTC
+    pub started_at: String,
+    /// None while running
+    pub stopped_at: Option<String>,
+}
+
+impl TimerEntry {
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> TimerEntry {
+        TimerEntry {
+            id: row.get("id"),
+            task: TimerTask {
+                description: row.get("description"),
+                jira_key: row.get("jira_key"),
+                project: row.get("project"),
+                tags: serde_json::from_str(&row.get::<String, _>("tags")).unwrap_or_default(),
+            },
+            started_at: row.get("started_at"),
+            stopped_at: row.get("stopped_at"),
+        }
+    }
+
+    /// The part of this entry inside `[from, to)`, as a time entry. The Jira
+    /// key leads the description so the time links to its ticket.
+    pub fn to_time_entry(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> TimeEntry {
+        let start = parse_timestamp(&self.started_at).unwrap_or(now);
+        let stop = self
+            .stopped_at
+            .as_deref()
+            .and_then(parse_timestamp)
+            .unwrap_or(now);
+        let seconds = (stop.min(to) - start.max(from)).num_seconds().max(0);
+
+        let description = match &self.task.jira_key {
+            Some(key) if !mentions_key(&self.task.description, key) => {
+                format!("{} {}", key, self.task.description)
+                    .trim_end()
+                    .to_string()
+            }
+            _ => self.task.description.clone(),
+        };
+
+        TimeEntry {
+            description,
+            start: self.started_at.clone(),
+            stop: self.stopped_at.clone(),
+            duration_seconds: seconds,
+            project: self.task.project.clone(),
+            client: None,
+            tags: self.task.tags.clone(),
+        }
+    }
+}
+
+pub fn format_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Parse a timestamp from the frontend, normalized to UTC
+pub fn parse_input_timestamp(value: &str) -> Result<DateTime<Utc>, AppError> {
+    parse_timestamp(value)
+        .ok_or_else(|| AppError::NotConfigured(format!("'{}' is not an RFC 3339 timestamp", value)))
+}
+
+fn encode_tags(tags: &[String]) -> Result<String, AppError> {
+    serde_json::to_string(tags)
+        .map_err(|e| AppError::DatabaseError(format!("Cannot serialize tags: {}", e)))
+}
+
+pub async fn running(db: &SqlitePool) -> Result<Option<TimerEntry>, AppError> {
+    let row = sqlx::query("SELECT * FROM time_entries WHERE stopped_at IS NULL")
+        .fetch_optional(db)
+        .await?;
+    Ok(row.as_ref().map(TimerEntry::from_row))
+}
+
+async fn get(db: &SqlitePool, id: i64) -> Result<TimerEntry, AppError> {
+    let row = sqlx::query("SELECT * FROM time_entries WHERE id = ?1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?
+        .ok_or_else(|| AppError::DatabaseError(format!("Time entry {} not found", id)))?;
+    Ok(TimerEntry::from_row(&row))
+}
+
+/// Start `task` at `at`, stopping any running entry at the same instant
+pub async fn start(
+    db: &SqlitePool,
+    task: TimerTask,
+    at: DateTime<Utc>,
+) -> Result<TimerEntry, AppError> {
+    let task = task.normalized()?;
+    let at = format_timestamp(at);
+
+    let mut tx = db.begin().await?;
+    sqlx::query(
+        "UPDATE time_entries SET stopped_at = MAX(started_at, ?1) WHERE stopped_at IS NULL",
+    )
+    .bind(&at)
+    .execute(&mut *tx)
+    .await?;
+    let id: i64 = sqlx::query_scalar(
+        r#"
+        INSERT INTO time_entries (description, jira_key, project, tags, started_at)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        RETURNING id
+        "#,
+    )
+    .bind(&task.description)
+    .bind(&task.jira_key)
+    .bind(&task.project)
+    .bind(encode_tags(&task.tags)?)
+    .bind(&at)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    get(db, id).await
+}
+
+/// Stop the running entry at `at`; returns it, or None if nothing was running
+pub async fn stop(db: &SqlitePool, at: DateTime<Utc>) -> Result<Option<TimerEntry>, AppError> {
+    let Some(entry) = running(db).await? else {
+        return Ok(None);
+    };
+    sqlx::query("UPDATE time_entries SET stopped_at = MAX(started_at, ?1) WHERE id = ?2")
+        .bind(format_timestamp(at))
+        .bind(entry.id)
+        .execute(db)
+        .await?;
+    get(db, entry.id).await.map(Some)
+}
+
+/// Idle hook: the user was away since `idle_since`. The running entry ends
+/// there (and is dropped if it started later); with `resume` the same task
+/// starts again now. Returns the entry that is running afterwards.
+pub async fn discard_idle(
+    db: &SqlitePool,
+    idle_since: DateTime<Utc>,
+    resume: bool,
+) -> Result<Option<TimerEntry>, AppError> {
+    let Some(entry) = running(db).await? else {
+        return Ok(None);
+    };
+
+    let started = parse_timestamp(&entry.started_at).unwrap_or(idle_since);
+    if idle_since <= started {
+        delete(db, entry.id).await?;
+    } else {
+        sqlx::query("UPDATE time_entries SET stopped_at = ?1 WHERE id = ?2")
+            .bind(format_timestamp(idle_since))
+            .bind(entry.id)
+            .execute(db)
+            .await?;
+    }
+
+    if resume {
+        start(db, entry.task, Utc::now()).await.map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Add a finished entry by hand
+pub async fn create(
+    db: &SqlitePool,
+    task: TimerTask,
+    started_at: DateTime<Utc>,
+    stopped_at: DateTime<Utc>,
+) -> Result<TimerEntry, AppError> {
+    let task = task.normalized()?;
+    if stopped_at <= started_at {
+        return Err(AppError::NotConfigured(
+            "An entry must stop after it starts".to_string(),
+        ));
+    }
+
+    let id: i64 = sqlx::query_scalar(
+        r#"
+        INSERT INTO time_entries (description, jira_key, project, tags, started_at, stopped_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        RETURNING id
+        "#,
+    )
+    .bind(&task.description)
+    .bind(&task.jira_key)
+    .bind(&task.project)
+    .bind(encode_tags(&task.tags)?)
+    .bind(format_timestamp(started_at))
+    .bind(format_timestamp(stopped_at))
+    .fetch_one(db)
+    .await?;
+    get(db, id).await
+}
+
+/// Edit an entry. A running entry keeps running when `stopped_at` is None;
+/// a stopped one can't be restarted this way.
+pub async fn update(
+    db: &SqlitePool,
+    id: i64,
+    task: TimerTask,
+    started_at: DateTime<Utc>,
+    stopped_at: Option<DateTime<Utc>>,
+) -> Result<TimerEntry, AppError> {
+    let task = task.normalized()?;
+    let existing = get(db, id).await?;
+    if stopped_at.is_none() && existing.stopped_at.is_some() {
+        return Err(AppError::NotConfigured(
+            "A stopped entry needs a stop time".to_string(),
+        ));
+    }
+    if stopped_at.is_some_and(|stop| stop <= started_at) {
+        return Err(AppError::NotConfigured(
+            "An entry must stop after it starts".to_string(),
+        ));
+    }
+
+    sqlx::query(
+        r#"
+        UPDATE time_entries
+        SET description = ?1, jira_key = ?2, project = ?3, tags = ?4,
+            started_at = ?5, stopped_at = ?6
+        WHERE id = ?7
+        "#,
+    )
+    .bind(&task.description)
+    .bind(&task.jira_key)
+    .bind(&task.project)
+    .bind(encode_tags(&task.tags)?)
+    .bind(format_timestamp(started_at))
+    .bind(stopped_at.map(format_timestamp))
+    .bind(id)
+    .execute(db)
+    .await?;
+    get(db, id).await
+}
+
+pub async fn delete(db: &SqlitePool, id: i64) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM time_entries WHERE id = ?1")
+        .bind(id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// Entries overlapping the summary day, oldest first
+pub async fn list_for_day(db: &SqlitePool, day: &SummaryDay) -> Result<Vec<TimerEntry>, AppError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT * FROM time_entries
+        WHERE started_at < ?2 AND (stopped_at IS NULL OR stopped_at > ?1)
+        ORDER BY started_at
+        "#,
+    )
+    .bind(format_timestamp(day.start))
+    .bind(format_timestamp(day.end))
+    .fetch_all(db)
+    .await?;
+    Ok(rows.iter().map(TimerEntry::from_row).collect())
+}
+
+/// The day's tracked time for aggregation, clipped to the day
+pub async fn time_entries_for_day(
+    db: &SqlitePool,
+    day: &SummaryDay,
+) -> Result<Vec<TimeEntry>, AppError> {
+    let now = Utc::now();
+    Ok(list_for_day(db, day)
+        .await?
+        .iter()
+        .map(|entry| entry.to_time_entry(day.start, day.end, now))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_timestamp(value).expect("valid timestamp")
+    }
+
+    #[test]
+    fn normalizes_task_and_checks_key() {
+        let task = TimerTask {
+            description: "  export ".to_string(),
+            jira_key: Some(" proj-7 ".to_string()),
+            project: Some(" ".to_string()),
+            tags: vec!["deep-work".to_string(), "".to_string()],
+        }
+        .normalized()
+        .expect("task should be valid");
+        assert_eq!(task.description, "export");
+        assert_eq!(task.jira_key.as_deref(), Some("PROJ-7"));
+        assert_eq!(task.project, None);
+        assert_eq!(task.tags, ["deep-work"]);
+
+        let bad = TimerTask {
+            jira_key: Some("PROJ7".to_string()),
+            ..Default::default()
+        };
+        assert!(bad.normalized().is_err());
+    }
+
+    #[test]
+    fn clips_to_the_day_and_leads_with_the_key() {
+        let entry = TimerEntry {
+            id: 1,
+            task: TimerTask {
+                description: "billing export".to_string(),
+                jira_key: Some("PROJ-7".to_string()),
+                ..Default::default()
+            },
+            started_at: "2026-02-13T23:30:00Z".to_string(),
+            stopped_at: Some("2026-02-14T01:00:00Z".to_string()),
+        };
+        let day = (at("2026-02-14T00:00:00Z"), at("2026-02-15T00:00:00Z"));
+        let mapped = entry.to_time_entry(day.0, day.1, at("2026-02-14T12:00:00Z"));
+        assert_eq!(mapped.duration_seconds, 3600);
+        assert_eq!(mapped.description, "PROJ-7 billing export");
+
+        // A running entry counts up to now
+        let running = TimerEntry {
+            stopped_at: None,
+            started_at: "2026-02-14T11:00:00Z".to_string(),
+            ..entry
+        };
+        let mapped = running.to_time_entry(day.0, day.1, at("2026-02-14T11:45:00Z"));
+        assert_eq!(mapped.duration_seconds, 2700);
+    }
+}
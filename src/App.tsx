@@ -1,12 +1,23 @@
-import { useState } from 'react';
+import { useState, useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
 import SummaryReviewPanel from './components/summary-review-panel';
 import HistoricalBrowser from './components/historical-browser';
 import SettingsPanelV2 from './components/settings-panel-v2';
+import QuickCapture from './components/quick-capture';
 
 type Tab = 'summary' | 'history' | 'settings';
 
 export default function App() {
   const [activeTab, setActiveTab] = useState<Tab>('summary');
+  const [capturing, setCapturing] = useState(false);
+
+  // Opened from the tray menu or the global shortcut
+  useEffect(() => {
+    const unlisten = listen('quick-capture', () => setCapturing(true));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
 
   return (
     <div className="min-h-screen bg-gray-50">
@@ -43,6 +54,13 @@ export default function App() {
           >
             Settings
           </button>
+          <button
+            onClick={() => setCapturing(true)}
+            className="ml-auto px-1 py-4 text-sm font-medium text-gray-500 hover:text-gray-700"
+            title="Capture a note (Ctrl/Cmd+Shift+L)"
+          >
+            + Note
+          </button>
         </nav>
       </div>
 
@@ -52,6 +70,8 @@ export default function App() {
         {activeTab === 'history' && <HistoricalBrowser />}
         {activeTab === 'settings' && <SettingsPanelV2 />}
       </main>
+
+      {capturing && <QuickCapture onClose={() => setCapturing(false)} />}
     </div>
   );
 }
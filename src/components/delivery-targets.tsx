@@ -0,0 +1,426 @@
+import { useState, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import Card from './ui/card';
+import Button from './ui/button';
+import Input from './ui/input';
+import Toggle from './ui/toggle';
+import { RedactionProfileSelect } from './redaction-profiles';
+import type { RedactionProfile } from '../types';
+
+type TargetConfig = Record<string, any>;
+
+type Field =
+  | { kind: 'text'; key: string; label: string; type?: 'text' | 'url' | 'password' | 'email';
+      placeholder?: string; helpText?: string; required?: boolean }
+  | { kind: 'list'; key: string; label: string; placeholder?: string; helpText?: string; numeric?: boolean }
+  | { kind: 'textarea'; key: string; label: string; placeholder?: string; helpText?: string }
+  | { kind: 'select'; key: string; label: string; options: [string, string][] }
+  | { kind: 'toggle'; key: string; label: string; description?: string; defaultOn?: boolean }
+  | { kind: 'headers'; key: string; label: string };
+
+interface Target {
+  type: string;
+  title: string;
+  description: string;
+  fields: Field[];
+  /** Jira needs the saved connection, which only a real send fills in */
+  testable: boolean;
+}
+
+/** Renderers chat targets can display; HTML would arrive as raw tags */
+const CHAT_RENDERER: Field = {
+  kind: 'select',
+  key: 'renderer',
+  label: 'Format',
+  options: [['markdown', 'Markdown'], ['text', 'Plain text']],
+};
+
+const TARGETS: Target[] = [
+  {
+    type: 'teams',
+    title: 'Microsoft Teams',
+    description: 'Post summaries to a Teams channel as an Adaptive Card',
+    testable: true,
+    fields: [
+      { kind: 'text', key: 'webhookUrl', label: 'Webhook URL', type: 'url', required: true,
+        placeholder: 'https://example.webhook.office.com/...',
+        helpText: 'Incoming Webhook or Workflows URL for the channel' },
+      CHAT_RENDERER,
+    ],
+  },
+  {
+    type: 'discord',
+    title: 'Discord',
+    description: 'Post summaries to a Discord channel via webhook',
+    testable: true,
+    fields: [
+      { kind: 'text', key: 'webhookUrl', label: 'Webhook URL', type: 'url', required: true,
+        placeholder: 'https://discord.com/api/webhooks/...' },
+      { kind: 'text', key: 'username', label: 'Bot Name', placeholder: 'WorkdayDebrief',
+        helpText: 'Leave blank to use the name set on the webhook' },
+      CHAT_RENDERER,
+    ],
+  },
+  {
+    type: 'mattermost',
+    title: 'Mattermost',
+    description: 'Post summaries to a Mattermost channel via webhook',
+    testable: true,
+    fields: [
+      { kind: 'text', key: 'webhookUrl', label: 'Webhook URL', type: 'url', required: true,
+        placeholder: 'https://mattermost.example.com/hooks/...' },
+      { kind: 'text', key: 'channel', label: 'Channel', placeholder: 'town-square',
+        helpText: 'Leave blank to use the webhook\'s channel' },
+      { kind: 'text', key: 'username', label: 'Username', placeholder: 'WorkdayDebrief' },
+      CHAT_RENDERER,
+    ],
+  },
+  {
+    type: 'webhook',
+    title: 'Custom Webhook',
+    description: 'Send each summary to any HTTP endpoint',
+    testable: true,
+    fields: [
+      { kind: 'select', key: 'method', label: 'Method',
+        options: [['POST', 'POST'], ['PUT', 'PUT'], ['PATCH', 'PATCH']] },
+      { kind: 'text', key: 'url', label: 'URL', type: 'url', required: true,
+        placeholder: 'https://example.com/hooks/debrief' },
+      { kind: 'headers', key: 'headers', label: 'Headers' },
+      { kind: 'textarea', key: 'bodyTemplate', label: 'Body Template',
+        placeholder: '{"text": "{{narrative}}"}',
+        helpText: 'Use {{field}} placeholders. Leave blank to send the full summary as JSON.' },
+      { kind: 'text', key: 'contentType', label: 'Content Type', placeholder: 'application/json' },
+      { kind: 'text', key: 'signingSecret', label: 'Signing Secret', type: 'password',
+        helpText: 'Signs the body with HMAC-SHA256. Stored in the encrypted vault.' },
+      { kind: 'text', key: 'signatureHeader', label: 'Signature Header', placeholder: 'X-Signature-256' },
+      { kind: 'list', key: 'retryOnStatus', label: 'Retry On Status', numeric: true,
+        placeholder: '408, 429, 500, 502, 503, 504',
+        helpText: 'Response codes worth retrying. Timeouts are always retried.' },
+    ],
+  },
+  {
+    type: 'jira',
+    title: 'Jira',
+    description: 'Comment on today\'s tickets and log tracked time as worklogs, using the Jira connection from Data Sources',
+    testable: false,
+    fields: [
+      { kind: 'toggle', key: 'addComments', label: 'Add comments', defaultOn: true,
+        description: 'Post the summary as a comment on each ticket' },
+      { kind: 'toggle', key: 'logWork', label: 'Log work',
+        description: 'Create worklogs from time entries that mention a ticket key' },
+      { kind: 'toggle', key: 'includeClosed', label: 'Closed tickets', defaultOn: true },
+      { kind: 'toggle', key: 'includeInProgress', label: 'In-progress tickets', defaultOn: true },
+    ],
+  },
+  {
+    type: 'notes',
+    title: 'Notes Vault',
+    description: 'Write the summary into your daily note in an Obsidian or Logseq vault',
+    testable: true,
+    fields: [
+      { kind: 'text', key: 'vaultPath', label: 'Vault Folder', required: true,
+        placeholder: '/Users/you/Notes' },
+      { kind: 'text', key: 'filenamePattern', label: 'Daily Note Path', placeholder: '{date}.md',
+        helpText: 'Relative to the vault. Use {yyyy}, {mm}, {dd} or {date} for the date.' },
+      { kind: 'select', key: 'mode', label: 'Existing Debrief',
+        options: [['replace', 'Replace the Work Debrief block'], ['append', 'Add to the end of the block']] },
+      { kind: 'list', key: 'tags', label: 'Tags', placeholder: 'work, debrief' },
+      { kind: 'toggle', key: 'wikiLinks', label: 'Wiki links', defaultOn: true,
+        description: 'Link ticket keys as [[KEY-1]]' },
+      { kind: 'toggle', key: 'frontMatter', label: 'Front matter', defaultOn: true,
+        description: 'Add tags, ticket keys and focus hours to the note\'s YAML front matter' },
+    ],
+  },
+  {
+    type: 'git',
+    title: 'Git Journal',
+    description: 'Commit each summary to a local git repository',
+    testable: true,
+    fields: [
+      { kind: 'text', key: 'repoPath', label: 'Repository', required: true,
+        placeholder: '/Users/you/journal' },
+      { kind: 'text', key: 'pathTemplate', label: 'File Path', placeholder: '{yyyy}/{mm}/{date}.{ext}' },
+      { kind: 'select', key: 'renderer', label: 'Format',
+        options: [['markdown', 'Markdown'], ['text', 'Plain text'], ['html', 'HTML'], ['json', 'JSON']] },
+      { kind: 'text', key: 'commitMessage', label: 'Commit Message', placeholder: 'Debrief for {date}',
+        helpText: 'Leave blank for a generated message' },
+      { kind: 'text', key: 'authorName', label: 'Author Name',
+        helpText: 'Leave blank to use the repository\'s git config' },
+      { kind: 'text', key: 'authorEmail', label: 'Author Email', type: 'email' },
+      { kind: 'toggle', key: 'push', label: 'Push after commit' },
+      { kind: 'text', key: 'remote', label: 'Remote', placeholder: 'origin' },
+      { kind: 'toggle', key: 'overwriteManualEdits', label: 'Overwrite manual edits',
+        description: 'Replace the file even if it was edited by hand since the last debrief' },
+    ],
+  },
+];
+
+const SELECT_CLASS =
+  'w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500';
+
+/** Config as sent to the backend: blank optional text is dropped so the
+ *  backend default applies, and unknown keys (template, redaction) are kept */
+function toBackendConfig(target: Target, config: TargetConfig): TargetConfig {
+  const result: TargetConfig = { ...config };
+  for (const field of target.fields) {
+    const value = result[field.key];
+    if (field.kind === 'list' && typeof value === 'string') {
+      const items = value.split(',').map((item) => item.trim()).filter(Boolean);
+      result[field.key] = field.numeric ? items.map(Number).filter(Number.isInteger) : items;
+    } else if (field.kind === 'toggle' && value === undefined) {
+      result[field.key] = field.defaultOn ?? false;
+    } else if (field.kind === 'headers' && Array.isArray(value)) {
+      result[field.key] = value.filter((header: any) => header.name.trim());
+    }
+    const cleared = result[field.key];
+    if (cleared === '' || (Array.isArray(cleared) && field.kind === 'list' && cleared.length === 0)) {
+      delete result[field.key];
+    }
+  }
+  return result;
+}
+
+function missingRequired(target: Target, config: TargetConfig): boolean {
+  return target.fields.some(
+    (field) => field.kind === 'text' && field.required && !config[field.key],
+  );
+}
+
+interface DeliveryTargetsProps {
+  profiles: RedactionProfile[];
+  onToast: (toast: { type: 'success' | 'error'; message: string }) => void;
+}
+
+/** Settings cards for the webhook, chat, Jira, notes and git targets */
+export default function DeliveryTargets({ profiles, onToast }: DeliveryTargetsProps) {
+  const [configs, setConfigs] = useState<Record<string, TargetConfig>>({});
+  const [saving, setSaving] = useState<string | null>(null);
+  const [testing, setTesting] = useState<string | null>(null);
+
+  useEffect(() => {
+    loadConfigs();
+  }, []);
+
+  const loadConfigs = async () => {
+    try {
+      const rows = await invoke<any[]>('get_delivery_configs');
+      const loaded: Record<string, TargetConfig> = {};
+      rows.forEach((row: any) => {
+        if (row.config) {
+          loaded[row.deliveryType] = row.config;
+        }
+      });
+      setConfigs(loaded);
+    } catch (error) {
+      console.error('Failed to load delivery configs:', error);
+    }
+  };
+
+  const update = (type: string, key: string, value: any) => {
+    setConfigs((prev) => ({ ...prev, [type]: { ...prev[type], [key]: value } }));
+  };
+
+  const handleSave = async (target: Target) => {
+    try {
+      setSaving(target.type);
+      await invoke('save_delivery_config', {
+        input: {
+          deliveryType: target.type,
+          config: toBackendConfig(target, configs[target.type] ?? {}),
+          isEnabled: true,
+        },
+      });
+      onToast({ type: 'success', message: `${target.title} configuration saved!` });
+      await loadConfigs();
+    } catch (error: any) {
+      console.error('Failed to save delivery config:', error);
+      onToast({ type: 'error', message: error.toString().replace('Error: ', '') });
+    } finally {
+      setSaving(null);
+    }
+  };
+
+  const handleTest = async (target: Target) => {
+    try {
+      setTesting(target.type);
+      const result = await invoke<string>('test_delivery', {
+        deliveryType: target.type,
+        config: { type: target.type, ...toBackendConfig(target, configs[target.type] ?? {}) },
+      });
+      onToast({ type: 'success', message: result });
+    } catch (error: any) {
+      console.error('Test delivery failed:', error);
+      onToast({ type: 'error', message: error.toString().replace('Error: ', '') });
+    } finally {
+      setTesting(null);
+    }
+  };
+
+  const renderField = (target: Target, field: Field) => {
+    const config = configs[target.type] ?? {};
+    const value = config[field.key];
+    const set = (next: any) => update(target.type, field.key, next);
+
+    switch (field.kind) {
+      case 'text':
+        return (
+          <Input
+            key={field.key}
+            label={field.label}
+            value={value ?? ''}
+            onChange={set}
+            type={field.type}
+            placeholder={field.placeholder}
+            helpText={field.helpText}
+            required={field.required}
+          />
+        );
+      case 'list':
+        return (
+          <Input
+            key={field.key}
+            label={field.label}
+            value={Array.isArray(value) ? value.join(', ') : value ?? ''}
+            onChange={set}
+            placeholder={field.placeholder}
+            helpText={field.helpText ?? 'Comma-separated'}
+          />
+        );
+      case 'textarea':
+        return (
+          <div key={field.key} className="mb-4">
+            <label className="block text-sm font-medium text-gray-700 mb-2">{field.label}</label>
+            <textarea
+              value={value ?? ''}
+              onChange={(e) => set(e.target.value)}
+              placeholder={field.placeholder}
+              rows={4}
+              className={`${SELECT_CLASS} font-mono`}
+            />
+            {field.helpText && <p className="mt-1 text-xs text-gray-500">{field.helpText}</p>}
+          </div>
+        );
+      case 'select':
+        return (
+          <div key={field.key} className="mb-4">
+            <label className="block text-sm font-medium text-gray-700 mb-2">{field.label}</label>
+            <select
+              value={value ?? field.options[0][0]}
+              onChange={(e) => set(e.target.value)}
+              className={SELECT_CLASS}
+            >
+              {field.options.map(([option, label]) => (
+                <option key={option} value={option}>
+                  {label}
+                </option>
+              ))}
+            </select>
+          </div>
+        );
+      case 'toggle':
+        return (
+          <Toggle
+            key={field.key}
+            enabled={value ?? field.defaultOn ?? false}
+            onChange={set}
+            label={field.label}
+            description={field.description}
+          />
+        );
+      case 'headers': {
+        const headers: { name: string; value: string; secret: boolean }[] = value ?? [];
+        const setHeader = (index: number, patch: Partial<{ name: string; value: string; secret: boolean }>) =>
+          set(headers.map((header, i) => (i === index ? { ...header, ...patch } : header)));
+        return (
+          <div key={field.key} className="mb-4 space-y-2">
+            <label className="block text-sm font-medium text-gray-700">{field.label}</label>
+            {headers.map((header, index) => (
+              <div key={index} className="flex items-center gap-2">
+                <input
+                  value={header.name}
+                  onChange={(e) => setHeader(index, { name: e.target.value })}
+                  placeholder="Authorization"
+                  className={SELECT_CLASS}
+                />
+                <input
+                  value={header.value}
+                  onChange={(e) => setHeader(index, { value: e.target.value })}
+                  type={header.secret ? 'password' : 'text'}
+                  placeholder="Value"
+                  className={SELECT_CLASS}
+                />
+                <label className="flex items-center gap-1 text-xs text-gray-600 whitespace-nowrap">
+                  <input
+                    type="checkbox"
+                    checked={header.secret}
+                    onChange={(e) => setHeader(index, { secret: e.target.checked })}
+                  />
+                  Secret
+                </label>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => set(headers.filter((_, i) => i !== index))}
+                >
+                  Remove
+                </Button>
+              </div>
+            ))}
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => set([...headers, { name: '', value: '', secret: false }])}
+            >
+              Add Header
+            </Button>
+          </div>
+        );
+      }
+    }
+  };
+
+  return (
+    <>
+      {TARGETS.map((target) => {
+        const config = configs[target.type] ?? {};
+        const incomplete = missingRequired(target, config);
+        return (
+          <Card key={target.type} title={target.title}>
+            <div className="space-y-4">
+              <p className="text-sm text-gray-600">{target.description}</p>
+              {target.fields.map((field) => renderField(target, field))}
+              <RedactionProfileSelect
+                profiles={profiles}
+                value={config.redactionProfileId ?? null}
+                onChange={(id) => update(target.type, 'redactionProfileId', id ?? undefined)}
+              />
+
+              <div className="flex gap-2 pt-2">
+                <Button
+                  variant="primary"
+                  size="sm"
+                  onClick={() => handleSave(target)}
+                  loading={saving === target.type}
+                  disabled={saving !== null || incomplete}
+                >
+                  Save {target.title} Config
+                </Button>
+                {target.testable && (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => handleTest(target)}
+                    loading={testing === target.type}
+                    disabled={testing !== null || incomplete}
+                  >
+                    Test {target.title}
+                  </Button>
+                )}
+              </div>
+            </div>
+          </Card>
+        );
+      })}
+    </>
+  );
+}
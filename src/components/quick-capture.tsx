@@ -0,0 +1,80 @@
+import { useState, useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import type { LogEntry } from '../types';
+
+interface QuickCaptureProps {
+  onClose: () => void;
+}
+
+export default function QuickCapture({ onClose }: QuickCaptureProps) {
+  const [text, setText] = useState('');
+  const [saving, setSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const inputRef = useRef<HTMLTextAreaElement>(null);
+
+  useEffect(() => {
+    inputRef.current?.focus();
+  }, []);
+
+  const handleSave = async () => {
+    if (!text.trim()) return;
+    try {
+      setSaving(true);
+      setError(null);
+      await invoke<LogEntry>('capture_note', { text });
+      onClose();
+    } catch (err: any) {
+      console.error('Capture failed:', err);
+      setError(err.toString().replace('Error: ', ''));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent<HTMLTextAreaElement>) => {
+    if (e.key === 'Enter' && !e.shiftKey) {
+      e.preventDefault();
+      handleSave();
+    } else if (e.key === 'Escape') {
+      onClose();
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-start justify-center bg-black bg-opacity-50 pt-24">
+      <div className="w-full max-w-lg rounded-lg bg-white p-4 shadow-xl">
+        <h2 className="mb-2 text-sm font-semibold text-gray-900">Capture Note</h2>
+        <textarea
+          ref={inputRef}
+          value={text}
+          onChange={(e) => setText(e.target.value)}
+          onKeyDown={handleKeyDown}
+          rows={3}
+          placeholder="What just happened? e.g. Found the cache bug in PROJ-7 #debugging"
+          className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500"
+        />
+        {error && <p className="mt-1 text-sm text-red-600">{error}</p>}
+        <div className="mt-2 flex items-center justify-between">
+          <p className="text-xs text-gray-500">
+            Enter to save, Shift+Enter for a new line, Esc to cancel
+          </p>
+          <div className="flex space-x-2">
+            <button
+              onClick={onClose}
+              className="rounded-md px-3 py-1.5 text-sm text-gray-600 hover:bg-gray-100"
+            >
+              Cancel
+            </button>
+            <button
+              onClick={handleSave}
+              disabled={saving || !text.trim()}
+              className="rounded-md bg-blue-600 px-3 py-1.5 text-sm font-medium text-white hover:bg-blue-700 disabled:opacity-50"
+            >
+              {saving ? 'Saving...' : 'Save'}
+            </button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+}
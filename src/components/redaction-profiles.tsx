@@ -0,0 +1,319 @@
+import { useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import type { RedactionPreview, RedactionProfile, RedactionRules } from '../types';
+import Card from './ui/card';
+import Button from './ui/button';
+import Input from './ui/input';
+import Toggle from './ui/toggle';
+
+const FIELD_CLASS =
+  'w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500 disabled:opacity-50';
+
+const EMPTY_RULES: RedactionRules = {
+  patterns: [],
+  keywords: [],
+  privateCalendars: [],
+  maskPrivateEvents: false,
+  securityLevels: [],
+  replacement: '[redacted]',
+};
+
+/** Rules being edited; lists are kept as typed and split on save */
+interface Draft {
+  id: number | null;
+  name: string;
+  patterns: string;
+  keywords: string;
+  privateCalendars: string;
+  maskPrivateEvents: boolean;
+  securityLevels: string;
+  replacement: string;
+}
+
+function toDraft(profile: RedactionProfile | null): Draft {
+  const rules = profile?.rules ?? EMPTY_RULES;
+  return {
+    id: profile?.id ?? null,
+    name: profile?.name ?? '',
+    patterns: rules.patterns.join('\n'),
+    keywords: rules.keywords.join(', '),
+    privateCalendars: rules.privateCalendars.join(', '),
+    maskPrivateEvents: rules.maskPrivateEvents,
+    securityLevels: rules.securityLevels.join(', '),
+    replacement: rules.replacement,
+  };
+}
+
+function splitList(value: string, separator: string | RegExp): string[] {
+  return value.split(separator).map((item) => item.trim()).filter(Boolean);
+}
+
+function toRules(draft: Draft): RedactionRules {
+  return {
+    patterns: splitList(draft.patterns, '\n'),
+    keywords: splitList(draft.keywords, ','),
+    privateCalendars: splitList(draft.privateCalendars, ','),
+    maskPrivateEvents: draft.maskPrivateEvents,
+    securityLevels: splitList(draft.securityLevels, ','),
+    replacement: draft.replacement || EMPTY_RULES.replacement,
+  };
+}
+
+function today(): string {
+  const now = new Date();
+  const pad = (n: number) => n.toString().padStart(2, '0');
+  return `${now.getFullYear()}-${pad(now.getMonth() + 1)}-${pad(now.getDate())}`;
+}
+
+interface RedactionProfileSelectProps {
+  profiles: RedactionProfile[];
+  value: number | null;
+  onChange: (id: number | null) => void;
+  label?: string;
+  helpText?: string;
+  disabled?: boolean;
+}
+
+/** Picker for the profile applied to one target, or to the LLM prompt */
+export function RedactionProfileSelect({
+  profiles,
+  value,
+  onChange,
+  label = 'Redaction Profile',
+  helpText,
+  disabled = false,
+}: RedactionProfileSelectProps) {
+  return (
+    <div className="mb-4">
+      <label className="block text-sm font-medium text-gray-700 mb-2">{label}</label>
+      <select
+        value={value ?? ''}
+        onChange={(e) => onChange(e.target.value ? parseInt(e.target.value) : null)}
+        disabled={disabled}
+        className={FIELD_CLASS}
+      >
+        <option value="">None</option>
+        {profiles.map((profile) => (
+          <option key={profile.id} value={profile.id}>
+            {profile.name}
+          </option>
+        ))}
+      </select>
+      {helpText && <p className="mt-1 text-xs text-gray-500">{helpText}</p>}
+    </div>
+  );
+}
+
+interface RedactionProfilesProps {
+  profiles: RedactionProfile[];
+  /** Called after a profile is saved or deleted, to reload the list */
+  onChange: () => Promise<void>;
+  onToast: (toast: { type: 'success' | 'error'; message: string }) => void;
+}
+
+/** Create, edit and preview redaction profiles */
+export default function RedactionProfiles({ profiles, onChange, onToast }: RedactionProfilesProps) {
+  const [draft, setDraft] = useState<Draft>(toDraft(null));
+  const [saving, setSaving] = useState(false);
+  const [previewDate, setPreviewDate] = useState(today());
+  const [previewing, setPreviewing] = useState(false);
+  const [preview, setPreview] = useState<RedactionPreview | null>(null);
+
+  const update = (patch: Partial<Draft>) => setDraft((prev) => ({ ...prev, ...patch }));
+
+  const select = (id: string) => {
+    setDraft(toDraft(profiles.find((p) => p.id.toString() === id) ?? null));
+    setPreview(null);
+  };
+
+  const handleSave = async () => {
+    try {
+      setSaving(true);
+      const id = await invoke<number>('save_redaction_profile', {
+        input: { id: draft.id, name: draft.name, rules: toRules(draft) },
+      });
+      update({ id });
+      onToast({ type: 'success', message: `Redaction profile '${draft.name.trim()}' saved` });
+      await onChange();
+    } catch (error: any) {
+      console.error('Failed to save redaction profile:', error);
+      onToast({ type: 'error', message: error.toString().replace('Error: ', '') });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (draft.id === null) return;
+    try {
+      await invoke('delete_redaction_profile', { id: draft.id });
+      setDraft(toDraft(null));
+      setPreview(null);
+      await onChange();
+    } catch (error: any) {
+      console.error('Failed to delete redaction profile:', error);
+      onToast({ type: 'error', message: error.toString().replace('Error: ', '') });
+    }
+  };
+
+  const handlePreview = async () => {
+    try {
+      setPreviewing(true);
+      setPreview(
+        await invoke<RedactionPreview>('preview_redaction', {
+          date: previewDate,
+          rules: toRules(draft),
+        }),
+      );
+    } catch (error: any) {
+      console.error('Redaction preview failed:', error);
+      setPreview(null);
+      onToast({ type: 'error', message: error.toString().replace('Error: ', '') });
+    } finally {
+      setPreviewing(false);
+    }
+  };
+
+  return (
+    <Card title="Redaction Profiles">
+      <div className="space-y-4">
+        <p className="text-sm text-gray-600">
+          Hide sensitive titles and text before a summary reaches the LLM or a delivery target.
+          Pick a profile for the LLM above and for each target under Delivery.
+        </p>
+
+        <div className="mb-4">
+          <label className="block text-sm font-medium text-gray-700 mb-2">Profile</label>
+          <select
+            value={draft.id ?? ''}
+            onChange={(e) => select(e.target.value)}
+            className={FIELD_CLASS}
+          >
+            <option value="">New profile</option>
+            {profiles.map((profile) => (
+              <option key={profile.id} value={profile.id}>
+                {profile.name}
+              </option>
+            ))}
+          </select>
+        </div>
+
+        <Input
+          label="Name"
+          value={draft.name}
+          onChange={(name) => update({ name })}
+          placeholder="External channels"
+          required
+        />
+
+        <div className="mb-4">
+          <label className="block text-sm font-medium text-gray-700 mb-2">Patterns</label>
+          <textarea
+            value={draft.patterns}
+            onChange={(e) => update({ patterns: e.target.value })}
+            placeholder={'INC-\\d+\n\\b\\d{4}-\\d{4}-\\d{4}\\b'}
+            rows={3}
+            className={`${FIELD_CLASS} font-mono`}
+          />
+          <p className="mt-1 text-xs text-gray-500">One regular expression per line</p>
+        </div>
+
+        <Input
+          label="Keywords"
+          value={draft.keywords}
+          onChange={(keywords) => update({ keywords })}
+          placeholder="Acme Corp, performance review"
+          helpText="Comma-separated; matched as whole words, ignoring case"
+        />
+        <Input
+          label="Private Calendars"
+          value={draft.privateCalendars}
+          onChange={(privateCalendars) => update({ privateCalendars })}
+          placeholder="hr@example.com"
+          helpText="Calendar ids whose events are always shown as 'Private event'"
+        />
+        <Toggle
+          enabled={draft.maskPrivateEvents}
+          onChange={(maskPrivateEvents) => update({ maskPrivateEvents })}
+          label="Hide private events"
+          description="Hide events marked private or confidential in Google Calendar"
+        />
+        <Input
+          label="Jira Security Levels"
+          value={draft.securityLevels}
+          onChange={(securityLevels) => update({ securityLevels })}
+          placeholder="Internal, *"
+          helpText="Tickets with these levels are shown as 'Restricted ticket'. * matches any level."
+        />
+        <Input
+          label="Replacement"
+          value={draft.replacement}
+          onChange={(replacement) => update({ replacement })}
+          placeholder="[redacted]"
+        />
+
+        <div className="flex gap-2 pt-2">
+          <Button
+            variant="primary"
+            size="sm"
+            onClick={handleSave}
+            loading={saving}
+            disabled={saving || !draft.name.trim()}
+          >
+            Save Profile
+          </Button>
+          {draft.id !== null && (
+            <Button variant="ghost" size="sm" onClick={handleDelete}>
+              Delete Profile
+            </Button>
+          )}
+        </div>
+
+        <div className="border-t border-gray-200 pt-4">
+          <label className="block text-sm font-medium text-gray-700 mb-2">Preview</label>
+          <div className="flex items-center gap-2">
+            <input
+              type="date"
+              value={previewDate}
+              onChange={(e) => setPreviewDate(e.target.value)}
+              className={FIELD_CLASS}
+            />
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={handlePreview}
+              loading={previewing}
+              disabled={previewing || !previewDate}
+            >
+              Preview
+            </Button>
+          </div>
+          <p className="mt-1 text-xs text-gray-500">
+            Applies the rules above, saved or not, to the summary for this day
+          </p>
+
+          {preview && (
+            <div className="mt-4 space-y-3">
+              {preview.redactions.length === 0 ? (
+                <p className="text-sm text-gray-600">Nothing would be hidden.</p>
+              ) : (
+                <ul className="space-y-1 text-sm">
+                  {preview.redactions.map((redaction, index) => (
+                    <li key={index} className="text-gray-700">
+                      <span className="font-mono text-xs text-gray-500">{redaction.field}</span>{' '}
+                      <span className="line-through">{redaction.original}</span>{' '}
+                      <span className="text-xs text-gray-500">({redaction.rule})</span>
+                    </li>
+                  ))}
+                </ul>
+              )}
+              <pre className="max-h-64 overflow-auto whitespace-pre-wrap rounded-md bg-gray-50 p-3 text-xs text-gray-800">
+                {preview.markdown}
+              </pre>
+            </div>
+          )}
+        </div>
+      </div>
+    </Card>
+  );
+}
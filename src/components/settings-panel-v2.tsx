@@ -1,7 +1,20 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import type { Settings } from '../types';
+import type {
+  CalendarListEntry,
+  GoogleAccount,
+  MeetingKind,
+  MeetingRules,
+  RedactionProfile,
+  Settings,
+  TimeCategories,
+  TimeCategory,
+  TimeSourceKind,
+  ToneTemplate,
+} from '../types';
 import Toast from './toast';
+import DeliveryTargets from './delivery-targets';
+import RedactionProfiles, { RedactionProfileSelect } from './redaction-profiles';
 import Card from './ui/card';
 import Button from './ui/button';
 import Input from './ui/input';
@@ -11,6 +24,82 @@ import { InfoTooltip } from './ui/tooltip';
 
 type TabId = 'llm' | 'data-sources' | 'delivery' | 'schedule';
 
+/** Comma-separated names from the rule for `category` */
+function categoryField(
+  categories: TimeCategories,
+  category: TimeCategory,
+  field: 'projects' | 'tags',
+): string {
+  const rule = categories.rules.find((r) => r.category === category);
+  return rule ? rule[field].join(', ') : '';
+}
+
+/** Copy of `categories` with one field of a category's rule replaced. Project
+ *  names also match clients, so one list covers both. */
+function withCategoryField(
+  categories: TimeCategories,
+  category: TimeCategory,
+  field: 'projects' | 'tags',
+  value: string,
+): TimeCategories {
+  // Blank names are kept while typing and dropped on save
+  const names = value.split(',').map((n) => n.trimStart());
+  const existing = categories.rules.find((r) => r.category === category)
+    ?? { category, projects: [], clients: [], tags: [] };
+  const updated = field === 'projects'
+    ? { ...existing, projects: names, clients: names }
+    : { ...existing, tags: names };
+  return {
+    rules: [...categories.rules.filter((r) => r.category !== category), updated],
+  };
+}
+
+const DEFAULT_MEETING_RULES: MeetingRules = {
+  rules: [],
+  internalDomains: [],
+  workdayStart: '09:00',
+  workdayEnd: '17:00',
+};
+
+/** Kinds a user can assign by keyword or person, with their labels */
+const MEETING_KINDS: [MeetingKind, string][] = [
+  ['oneOnOne', '1:1'],
+  ['teamSync', 'Team sync'],
+  ['external', 'External'],
+  ['interview', 'Interview'],
+  ['focusBlock', 'Focus block'],
+];
+
+/** Comma-separated keywords or people from the rule for `kind` */
+function meetingRuleField(
+  meetingRules: MeetingRules,
+  kind: MeetingKind,
+  field: 'titleKeywords' | 'attendees',
+): string {
+  const rule = meetingRules.rules.find((r) => r.kind === kind);
+  return rule ? rule[field].join(', ') : '';
+}
+
+/** Copy of `meetingRules` with one field of a kind's rule replaced. People
+ *  match both the organizer and the guests. */
+function withMeetingRuleField(
+  meetingRules: MeetingRules,
+  kind: MeetingKind,
+  field: 'titleKeywords' | 'attendees',
+  value: string,
+): MeetingRules {
+  const names = value.split(',').map((n) => n.trimStart());
+  const existing = meetingRules.rules.find((r) => r.kind === kind)
+    ?? { kind, titleKeywords: [], organizers: [], attendees: [] };
+  const updated = field === 'titleKeywords'
+    ? { ...existing, titleKeywords: names }
+    : { ...existing, attendees: names, organizers: names };
+  return {
+    ...meetingRules,
+    rules: [...meetingRules.rules.filter((r) => r.kind !== kind), updated],
+  };
+}
+
 export default function SettingsPanelV2() {
   const [activeTab, setActiveTab] = useState<TabId>('llm');
   const [loading, setLoading] = useState(true);
@@ -19,17 +108,36 @@ export default function SettingsPanelV2() {
 
   // Form state
   const [scheduledTime, setScheduledTime] = useState('17:00');
+  const [timeZone, setTimeZone] = useState('');
+  const [dayStart, setDayStart] = useState('00:00');
   const [defaultTone, setDefaultTone] = useState('professional');
+  const [tones, setTones] = useState<ToneTemplate[]>([]);
   const [enableLlm, setEnableLlm] = useState(true);
   const [llmModel, setLlmModel] = useState('qwen3:14b');
   const [llmTemperature, setLlmTemperature] = useState(0.7);
   const [llmTimeout, setLlmTimeout] = useState(15);
+  const [llmNumCtx, setLlmNumCtx] = useState(4096);
+  const [llmRedactionProfileId, setLlmRedactionProfileId] = useState<number | null>(null);
+  const [redactionProfiles, setRedactionProfiles] = useState<RedactionProfile[]>([]);
+  const [llmOutputFormat, setLlmOutputFormat] = useState<'prose' | 'structured'>('prose');
   const [jiraBaseUrl, setJiraBaseUrl] = useState('');
   const [jiraProjectKey, setJiraProjectKey] = useState('');
+  const [jiraJql, setJiraJql] = useState('');
+  const [jiraAuthMode, setJiraAuthMode] = useState<'cloud' | 'datacenter'>('cloud');
+  const [jiraCaCertPath, setJiraCaCertPath] = useState('');
   const [jiraEmail, setJiraEmail] = useState('');
   const [jiraApiToken, setJiraApiToken] = useState('');
   const [togglWorkspaceId, setTogglWorkspaceId] = useState('');
+  const [timeCategories, setTimeCategories] = useState<TimeCategories>({ rules: [] });
+  const [meetingRules, setMeetingRules] = useState<MeetingRules>(DEFAULT_MEETING_RULES);
   const [togglApiToken, setTogglApiToken] = useState('');
+  const [timeSource, setTimeSource] = useState<TimeSourceKind>('toggl');
+  const [clockifyApiKey, setClockifyApiKey] = useState('');
+  const [clockifyWorkspaceId, setClockifyWorkspaceId] = useState('');
+  const [clockifyBaseUrl, setClockifyBaseUrl] = useState('');
+  const [harvestAccessToken, setHarvestAccessToken] = useState('');
+  const [harvestAccountId, setHarvestAccountId] = useState('');
+  const [harvestBaseUrl, setHarvestBaseUrl] = useState('');
   const [retentionDays, setRetentionDays] = useState(90);
 
   // Delivery config state
@@ -42,15 +150,19 @@ export default function SettingsPanelV2() {
   const [smtpUseTls, setSmtpUseTls] = useState(true);
   const [slackWebhookUrl, setSlackWebhookUrl] = useState('');
   const [fileDirectory, setFileDirectory] = useState('');
+  // Redaction profile of the email, Slack and file targets
+  const [targetRedaction, setTargetRedaction] = useState<Record<string, number | null>>({});
   const [testingDelivery, setTestingDelivery] = useState<string | null>(null);
 
   // Google Calendar OAuth state
-  const [googleConnected, setGoogleConnected] = useState(false);
+  const [googleAccounts, setGoogleAccounts] = useState<GoogleAccount[]>([]);
+  // Calendars of each account, loaded when the user opens its calendar list
+  const [googleCalendars, setGoogleCalendars] = useState<Record<string, CalendarListEntry[]>>({});
   const [connectingGoogle, setConnectingGoogle] = useState(false);
 
   // Connection testing state
   const [testingJira, setTestingJira] = useState(false);
-  const [testingToggl, setTestingToggl] = useState(false);
+  const [testingTimeSource, setTestingTimeSource] = useState(false);
 
   // Validation errors
   const [errors, setErrors] = useState<Record<string, string>>({});
@@ -89,6 +201,18 @@ export default function SettingsPanelV2() {
       newErrors.scheduledTime = 'Invalid time format (use HH:MM)';
     }
 
+    if (!validateTime(dayStart)) {
+      newErrors.dayStart = 'Invalid time format (use HH:MM)';
+    } else if (dayStart.padStart(5, '0') >= '12:00') {
+      newErrors.dayStart = 'The day must start before 12:00';
+    }
+
+    if (!validateTime(meetingRules.workdayStart) || !validateTime(meetingRules.workdayEnd)) {
+      newErrors.workday = 'Invalid time format (use HH:MM)';
+    } else if (meetingRules.workdayStart.padStart(5, '0') >= meetingRules.workdayEnd.padStart(5, '0')) {
+      newErrors.workday = 'The working day must end after it starts';
+    }
+
     if (llmTemperature < 0 || llmTemperature > 1) {
       newErrors.llmTemperature = 'Temperature must be between 0 and 1';
     }
@@ -118,7 +242,7 @@ export default function SettingsPanelV2() {
 
       const unlisten_success = await listen<string>('oauth-completed', (event) => {
         console.log('OAuth completed:', event.payload);
-        setGoogleConnected(true);
+        loadGoogleAccounts();
         setConnectingGoogle(false);
         setToast({ type: 'success', message: event.payload });
         unlisten_success();
@@ -145,18 +269,54 @@ export default function SettingsPanelV2() {
     }
   };
 
-  const handleDisconnectGoogle = async () => {
+  const loadGoogleAccounts = async () => {
     try {
-      // Delete refresh token from stronghold
-      await invoke('delete_secret', { key: 'google_refresh_token' });
-      setGoogleConnected(false);
-      setToast({ type: 'success', message: 'Google Calendar disconnected' });
+      setGoogleAccounts(await invoke<GoogleAccount[]>('list_google_accounts'));
+    } catch (error: any) {
+      console.error('Failed to load Google accounts:', error);
+    }
+  };
+
+  const handleDisconnectGoogle = async (email: string) => {
+    try {
+      // Deletes the account's refresh token from stronghold
+      await invoke('disconnect_google_account', { email });
+      setGoogleAccounts(googleAccounts.filter((a) => a.email !== email));
+      setToast({ type: 'success', message: `Disconnected ${email}` });
     } catch (error: any) {
       console.error('Failed to disconnect:', error);
       setToast({ type: 'error', message: 'Failed to disconnect' });
     }
   };
 
+  const handleShowCalendars = async (email: string) => {
+    try {
+      const calendars = await invoke<CalendarListEntry[]>('list_google_calendars', { email });
+      setGoogleCalendars({ ...googleCalendars, [email]: calendars });
+    } catch (error: any) {
+      setToast({ type: 'error', message: error.toString() || 'Failed to load calendars' });
+    }
+  };
+
+  const handleToggleCalendar = async (account: GoogleAccount, calendar: CalendarListEntry) => {
+    // An empty selection means the primary calendar
+    const primaryId = googleCalendars[account.email]?.find((c) => c.primary)?.id;
+    const current = account.calendarIds.length > 0
+      ? account.calendarIds
+      : primaryId ? [primaryId] : [];
+    const calendarIds = current.includes(calendar.id)
+      ? current.filter((id) => id !== calendar.id)
+      : [...current, calendar.id];
+    try {
+      await invoke('set_google_calendars', { email: account.email, calendarIds });
+      setGoogleAccounts(googleAccounts.map((a) =>
+        a.email === account.email ? { ...a, calendarIds } : a
+      ));
+    } catch (error: any) {
+      setToast({ type: 'error', message: error.toString() || 'Failed to save calendars' });
+    }
+  };
+
   const handleTestJira = async () => {
     try {
       setTestingJira(true);
@@ -169,6 +329,9 @@ export default function SettingsPanelV2() {
         email: jiraEmail,
         apiToken: actualToken,
         projectKey: jiraProjectKey,
+        jql: jiraJql || null,
+        authMode: jiraAuthMode,
+        caCertPath: jiraCaCertPath || null,
       });
 
       setToast({ type: 'success', message: result });
@@ -181,25 +344,41 @@ export default function SettingsPanelV2() {
     }
   };
 
-  const handleTestToggl = async () => {
-    try {
-      setTestingToggl(true);
-      const actualToken = togglApiToken === '••••••'
-        ? (await invoke<string | null>('get_secret', { key: 'toggl_api_token' })) || ''
-        : togglApiToken;
+  const unmaskSecret = async (value: string, key: string) =>
+    value === '••••••'
+      ? (await invoke<string | null>('get_secret', { key })) || ''
+      : value;
 
-      const result = await invoke<string>('test_toggl_connection', {
-        apiToken: actualToken,
-        workspaceId: togglWorkspaceId,
-      });
+  const handleTestTimeSource = async () => {
+    try {
+      setTestingTimeSource(true);
+      let result: string;
+      if (timeSource === 'clockify') {
+        result = await invoke<string>('test_clockify_connection', {
+          apiKey: await unmaskSecret(clockifyApiKey, 'clockify_api_key'),
+          workspaceId: clockifyWorkspaceId,
+          baseUrl: clockifyBaseUrl || null,
+        });
+      } else if (timeSource === 'harvest') {
+        result = await invoke<string>('test_harvest_connection', {
+          accessToken: await unmaskSecret(harvestAccessToken, 'harvest_access_token'),
+          accountId: harvestAccountId,
+          baseUrl: harvestBaseUrl || null,
+        });
+      } else {
+        result = await invoke<string>('test_toggl_connection', {
+          apiToken: await unmaskSecret(togglApiToken, 'toggl_api_token'),
+          workspaceId: togglWorkspaceId,
+        });
+      }
 
       setToast({ type: 'success', message: result });
     } catch (error: any) {
-      console.error('Toggl test failed:', error);
+      console.error('Time tracking test failed:', error);
       const message = error.toString().replace('Error: ', '');
-      setToast({ type: 'error', message: `Toggl test failed: ${message}` });
+      setToast({ type: 'error', message: `Time tracking test failed: ${message}` });
     } finally {
-      setTestingToggl(false);
+      setTestingTimeSource(false);
     }
   };
 
@@ -242,24 +421,38 @@ export default function SettingsPanelV2() {
 
       // Populate form
       setScheduledTime(data.scheduledTime);
+      setTimeZone(data.timeZone || '');
+      setDayStart(data.dayStart ?? '00:00');
       setDefaultTone(data.defaultTone);
+      setTones(await invoke<ToneTemplate[]>('list_tones'));
+      await loadRedactionProfiles();
       setEnableLlm(data.enableLlm);
       setLlmModel(data.llmModel);
       setLlmTemperature(data.llmTemperature);
       setLlmTimeout(data.llmTimeoutSecs);
+      setLlmOutputFormat(data.llmOutputFormat ?? 'prose');
+      setLlmNumCtx(data.llmNumCtx ?? 4096);
+      setLlmRedactionProfileId(data.llmRedactionProfileId ?? null);
       setJiraBaseUrl(data.jiraBaseUrl || '');
       setJiraProjectKey(data.jiraProjectKey || '');
+      setJiraJql(data.jiraJql || '');
+      setJiraAuthMode(data.jiraAuthMode ?? 'cloud');
+      setJiraCaCertPath(data.jiraCaCertPath || '');
       setTogglWorkspaceId(data.togglWorkspaceId || '');
+      setTimeSource(data.timeSource ?? 'toggl');
+      setClockifyWorkspaceId(data.clockifyWorkspaceId || '');
+      setClockifyBaseUrl(data.clockifyBaseUrl || '');
+      setHarvestAccountId(data.harvestAccountId || '');
+      setHarvestBaseUrl(data.harvestBaseUrl || '');
+      setTimeCategories(data.timeCategories ?? { rules: [] });
+      setMeetingRules({ ...DEFAULT_MEETING_RULES, ...data.meetingRules });
       setRetentionDays(data.retentionDays);
 
       // Load delivery configs
       await loadDeliveryConfigs();
 
-      // Check Google Calendar connection
-      const googleToken = await invoke<string | null>('get_secret', {
-        key: 'google_refresh_token',
-      });
-      setGoogleConnected(googleToken !== null);
+      // Check Google Calendar connections
+      await loadGoogleAccounts();
 
       // Load API tokens from encrypted storage
       const jiraEmailSecret = await invoke<string | null>('get_secret', {
@@ -271,10 +464,18 @@ export default function SettingsPanelV2() {
       const togglTokenSecret = await invoke<string | null>('get_secret', {
         key: 'toggl_api_token',
       });
+      const clockifyKeySecret = await invoke<string | null>('get_secret', {
+        key: 'clockify_api_key',
+      });
+      const harvestTokenSecret = await invoke<string | null>('get_secret', {
+        key: 'harvest_access_token',
+      });
 
       setJiraEmail(jiraEmailSecret || '');
       setJiraApiToken(jiraTokenSecret ? '••••••' : '');
       setTogglApiToken(togglTokenSecret ? '••••••' : '');
+      setClockifyApiKey(clockifyKeySecret ? '••••••' : '');
+      setHarvestAccessToken(harvestTokenSecret ? '••••••' : '');
     } catch (error) {
       console.error('Failed to load settings:', error);
       setToast({ type: 'error', message: 'Failed to load settings' });
@@ -283,11 +484,24 @@ export default function SettingsPanelV2() {
     }
   };
 
+  const loadRedactionProfiles = async () => {
+    try {
+      setRedactionProfiles(await invoke<RedactionProfile[]>('list_redaction_profiles'));
+    } catch (error) {
+      console.error('Failed to load redaction profiles:', error);
+    }
+  };
+
+  const setRedactionFor = (deliveryType: string, id: number | null) => {
+    setTargetRedaction((prev) => ({ ...prev, [deliveryType]: id }));
+  };
+
   const loadDeliveryConfigs = async () => {
     try {
       const configs = await invoke<any[]>('get_delivery_configs');
 
       configs.forEach((config: any) => {
+        setRedactionFor(config.deliveryType, config.config?.redactionProfileId ?? null);
         if (config.deliveryType === 'email' && config.config) {
           setSmtpHost(config.config.host || '');
           setSmtpPort(config.config.port?.toString() || '587');
@@ -324,16 +538,47 @@ export default function SettingsPanelV2() {
 
       const updatedSettings: Settings = {
         scheduledTime,
+        timeZone: timeZone.trim() || null,
+        dayStart,
         defaultTone,
         enableLlm,
         llmModel,
         llmTemperature,
         llmTimeoutSecs: llmTimeout,
+        llmOutputFormat,
+        llmNumCtx,
+        llmRedactionProfileId,
         calendarSource: 'none',
         retentionDays,
         jiraBaseUrl: jiraBaseUrl || null,
         jiraProjectKey: jiraProjectKey || null,
+        jiraJql: jiraJql || null,
+        jiraAuthMode,
+        jiraCaCertPath: jiraCaCertPath || null,
         togglWorkspaceId: togglWorkspaceId || null,
+        timeSource,
+        clockifyWorkspaceId: clockifyWorkspaceId || null,
+        clockifyBaseUrl: clockifyBaseUrl || null,
+        harvestAccountId: harvestAccountId || null,
+        harvestBaseUrl: harvestBaseUrl || null,
+        timeCategories: {
+          rules: timeCategories.rules.map((rule) => ({
+            ...rule,
+            projects: rule.projects.map((n) => n.trim()).filter(Boolean),
+            clients: rule.clients.map((n) => n.trim()).filter(Boolean),
+            tags: rule.tags.map((n) => n.trim()).filter(Boolean),
+          })),
+        },
+        meetingRules: {
+          ...meetingRules,
+          internalDomains: meetingRules.internalDomains.map((d) => d.trim()).filter(Boolean),
+          rules: meetingRules.rules.map((rule) => ({
+            ...rule,
+            titleKeywords: rule.titleKeywords.map((n) => n.trim()).filter(Boolean),
+            organizers: rule.organizers.map((n) => n.trim()).filter(Boolean),
+            attendees: rule.attendees.map((n) => n.trim()).filter(Boolean),
+          })),
+        },
       };
 
       await invoke('save_settings', { settings: updatedSettings });
@@ -348,6 +593,12 @@ export default function SettingsPanelV2() {
       if (togglApiToken && togglApiToken !== '••••••') {
         await invoke('store_secret', { key: 'toggl_api_token', value: togglApiToken });
       }
+      if (clockifyApiKey && clockifyApiKey !== '••••••') {
+        await invoke('store_secret', { key: 'clockify_api_key', value: clockifyApiKey });
+      }
+      if (harvestAccessToken && harvestAccessToken !== '••••••') {
+        await invoke('store_secret', { key: 'harvest_access_token', value: harvestAccessToken });
+      }
 
       setToast({ type: 'success', message: 'Settings saved successfully!' });
       await loadSettings();
@@ -386,6 +637,9 @@ export default function SettingsPanelV2() {
       } else if (deliveryType === 'file') {
         config = { directoryPath: fileDirectory };
       }
+      if (targetRedaction[deliveryType] != null) {
+        config.redactionProfileId = targetRedaction[deliveryType];
+      }
 
       await invoke('save_delivery_config', {
         input: {
@@ -498,67 +752,118 @@ export default function SettingsPanelV2() {
       {/* Tab Content */}
       <div className="min-h-[400px]">
         {activeTab === 'llm' && (
-          <Card title="LLM Configuration">
-            <div className="space-y-6">
-              <Toggle
-                enabled={enableLlm}
-                onChange={setEnableLlm}
-                label="Enable LLM Narrative Generation"
-                description="Auto-generate narrative summaries using local Ollama"
-              />
-
-              <Input
-                label="Model Name"
-                value={llmModel}
-                onChange={setLlmModel}
-                placeholder="qwen3:14b"
-                helpText="Recommended: qwen3:14b (M4 Pro 48GB). Install with: ollama pull qwen3:14b"
-                disabled={!enableLlm}
-              />
+          <div className="space-y-6">
+            <Card title="LLM Configuration">
+              <div className="space-y-6">
+                <Toggle
+                  enabled={enableLlm}
+                  onChange={setEnableLlm}
+                  label="Enable LLM Narrative Generation"
+                  description="Auto-generate narrative summaries using local Ollama"
+                />
 
-              <div>
-                <label className="block text-sm font-medium text-gray-700 mb-2">
-                  Temperature: {llmTemperature.toFixed(1)}
-                  <InfoTooltip content="Lower = more factual and consistent. Higher = more creative and varied." />
-                </label>
-                <input
-                  type="range"
-                  min="0"
-                  max="1"
-                  step="0.1"
-                  value={llmTemperature}
-                  onChange={(e) => setLlmTemperature(parseFloat(e.target.value))}
+                <Input
+                  label="Model Name"
+                  value={llmModel}
+                  onChange={setLlmModel}
+                  placeholder="qwen3:14b"
+                  helpText="Recommended: qwen3:14b (M4 Pro 48GB). Install with: ollama pull qwen3:14b"
                   disabled={!enableLlm}
-                  className="w-full disabled:opacity-50"
                 />
-                <div className="flex justify-between text-xs text-gray-500 mt-1">
-                  <span>0.0 (Deterministic)</span>
-                  <span>1.0 (Creative)</span>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Temperature: {llmTemperature.toFixed(1)}
+                    <InfoTooltip content="Lower = more factual and consistent. Higher = more creative and varied." />
+                  </label>
+                  <input
+                    type="range"
+                    min="0"
+                    max="1"
+                    step="0.1"
+                    value={llmTemperature}
+                    onChange={(e) => setLlmTemperature(parseFloat(e.target.value))}
+                    disabled={!enableLlm}
+                    className="w-full disabled:opacity-50"
+                  />
+                  <div className="flex justify-between text-xs text-gray-500 mt-1">
+                    <span>0.0 (Deterministic)</span>
+                    <span>1.0 (Creative)</span>
+                  </div>
                 </div>
-              </div>
 
-              <div>
-                <label className="block text-sm font-medium text-gray-700 mb-2">
-                  Timeout: {llmTimeout}s
-                  <InfoTooltip content="How long to wait for LLM response before showing bullet-point fallback." />
-                </label>
-                <input
-                  type="range"
-                  min="5"
-                  max="30"
-                  step="5"
-                  value={llmTimeout}
-                  onChange={(e) => setLlmTimeout(parseInt(e.target.value))}
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Timeout: {llmTimeout}s
+                    <InfoTooltip content="How long to wait for LLM response before showing bullet-point fallback." />
+                  </label>
+                  <input
+                    type="range"
+                    min="5"
+                    max="30"
+                    step="5"
+                    value={llmTimeout}
+                    onChange={(e) => setLlmTimeout(parseInt(e.target.value))}
+                    disabled={!enableLlm}
+                    className="w-full disabled:opacity-50"
+                  />
+                  <div className="flex justify-between text-xs text-gray-500 mt-1">
+                    <span>5s (Fast)</span>
+                    <span>30s (Patient)</span>
+                  </div>
+                </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Context Size
+                    <InfoTooltip content="Tokens the model can read at once. On busy days the prompt lists as many tickets and meetings as fit and summarises the rest. Larger values need more memory." />
+                  </label>
+                  <select
+                    value={llmNumCtx}
+                    onChange={(e) => setLlmNumCtx(parseInt(e.target.value))}
+                    disabled={!enableLlm}
+                    className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500 disabled:opacity-50"
+                  >
+                    {[2048, 4096, 8192, 16384, 32768].map((size) => (
+                      <option key={size} value={size}>
+                        {size.toLocaleString()} tokens
+                      </option>
+                    ))}
+                  </select>
+                </div>
+
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Narrative Format
+                    <InfoTooltip content="Prose writes a short paragraph. Structured fills fixed sections (accomplishments, in progress, collaboration, risks, tomorrow) so every day has the same layout." />
+                  </label>
+                  <select
+                    value={llmOutputFormat}
+                    onChange={(e) => setLlmOutputFormat(e.target.value as 'prose' | 'structured')}
+                    disabled={!enableLlm}
+                    className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500 disabled:opacity-50"
+                  >
+                    <option value="prose">Prose</option>
+                    <option value="structured">Structured sections</option>
+                  </select>
+                </div>
+
+                <RedactionProfileSelect
+                  profiles={redactionProfiles}
+                  value={llmRedactionProfileId}
+                  onChange={setLlmRedactionProfileId}
+                  helpText="Hides matching titles and text before anything is put in the prompt"
                   disabled={!enableLlm}
-                  className="w-full disabled:opacity-50"
                 />
-                <div className="flex justify-between text-xs text-gray-500 mt-1">
-                  <span>5s (Fast)</span>
-                  <span>30s (Patient)</span>
-                </div>
               </div>
-            </div>
-          </Card>
+            </Card>
+
+            <RedactionProfiles
+              profiles={redactionProfiles}
+              onChange={loadRedactionProfiles}
+              onToast={setToast}
+            />
+          </div>
         )}
 
         {activeTab === 'data-sources' && (
@@ -572,34 +877,71 @@ export default function SettingsPanelV2() {
                   onChange={setJiraBaseUrl}
                   type="url"
                   placeholder="https://company.atlassian.net"
-                  helpText="Your Jira Cloud instance URL"
+                  helpText="Your Jira Cloud or Data Center URL"
                   error={errors.jiraBaseUrl}
                 />
 
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Authentication
+                    <InfoTooltip content="Jira Cloud uses your account email with an API token. Jira Server and Data Center use a personal access token (Profile → Personal Access Tokens). Test Connection detects which one your server is." />
+                  </label>
+                  <select
+                    value={jiraAuthMode}
+                    onChange={(e) => setJiraAuthMode(e.target.value as 'cloud' | 'datacenter')}
+                    className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500"
+                  >
+                    <option value="cloud">Cloud (email + API token)</option>
+                    <option value="datacenter">Server / Data Center (personal access token)</option>
+                  </select>
+                </div>
+
                 <Input
-                  label="Project Key"
+                  label="Project Keys"
                   value={jiraProjectKey}
                   onChange={setJiraProjectKey}
-                  placeholder="PROJ"
-                  helpText="The project key for your tickets (e.g., PROJ in PROJ-123)"
+                  placeholder="PROJ, OPS"
+                  helpText="One or more project keys, comma-separated (e.g., PROJ in PROJ-123)"
                 />
 
                 <Input
-                  label="Email"
-                  value={jiraEmail}
-                  onChange={setJiraEmail}
-                  type="email"
-                  placeholder="you@company.com"
-                  helpText="Your Jira account email"
+                  label="Custom JQL (optional)"
+                  value={jiraJql}
+                  onChange={setJiraJql}
+                  placeholder="assignee = currentUser() OR watcher = currentUser()"
+                  helpText="Replaces the default assignee filter; only issues updated today are included"
                 />
 
+                {jiraAuthMode === 'cloud' && (
+                  <Input
+                    label="Email"
+                    value={jiraEmail}
+                    onChange={setJiraEmail}
+                    type="email"
+                    placeholder="you@company.com"
+                    helpText="Your Jira account email"
+                  />
+                )}
+
                 <Input
-                  label="API Token"
+                  label={jiraAuthMode === 'cloud' ? 'API Token' : 'Personal Access Token'}
                   value={jiraApiToken}
                   onChange={setJiraApiToken}
                   type="password"
-                  placeholder="Enter API token"
-                  helpText="Generate at: https://id.atlassian.com/manage-profile/security/api-tokens"
+                  placeholder={jiraAuthMode === 'cloud' ? 'Enter API token' : 'Enter personal access token'}
+                  helpText={
+                    jiraAuthMode === 'cloud'
+                      ? 'Generate at: https://id.atlassian.com/manage-profile/security/api-tokens'
+                      : 'Create in Jira under Profile → Personal Access Tokens'
+                  }
+                />
+
+                <Input
+                  label="CA Certificate (optional)"
+                  value={jiraCaCertPath}
+                  onChange={setJiraCaCertPath}
+                  placeholder="/etc/ssl/certs/company-ca.pem"
+                  helpText="Path to a PEM file for servers using a private or self-signed certificate"
                 />
 
                 <div className="pt-2">
@@ -608,7 +950,7 @@ export default function SettingsPanelV2() {
                     size="sm"
                     onClick={handleTestJira}
                     loading={testingJira}
-                    disabled={!jiraBaseUrl || !jiraEmail || !jiraApiToken || testingJira}
+                    disabled={!jiraBaseUrl || (jiraAuthMode === 'cloud' && !jiraEmail) || !jiraApiToken || testingJira}
                   >
                     {testingJira ? 'Testing...' : 'Test Jira Connection'}
                   </Button>
@@ -620,74 +962,290 @@ export default function SettingsPanelV2() {
             <Card title="Google Calendar">
               <div className="space-y-4">
                 <p className="text-sm text-gray-600">
-                  Connect your Google Calendar to automatically include meeting data in summaries.
+                  Connect one or more Google accounts to include meeting data in summaries.
                 </p>
-                {googleConnected ? (
-                  <>
-                    <Badge variant="success">Connected</Badge>
-                    <div className="pt-2">
-                      <Button
-                        variant="danger"
-                        size="sm"
-                        onClick={handleDisconnectGoogle}
-                      >
-                        Disconnect
-                      </Button>
-                    </div>
-                  </>
-                ) : (
-                  <>
-                    <Badge variant="neutral">Not Connected</Badge>
-                    <div className="pt-2">
-                      <Button
-                        variant="primary"
-                        size="sm"
-                        onClick={handleConnectGoogle}
-                        loading={connectingGoogle}
-                        disabled={connectingGoogle}
-                      >
-                        {connectingGoogle ? 'Connecting...' : 'Connect Google Account'}
-                      </Button>
+                {googleAccounts.length === 0 && <Badge variant="neutral">Not Connected</Badge>}
+                {googleAccounts.map((account) => {
+                  const calendars = googleCalendars[account.email];
+                  const isSelected = (calendar: CalendarListEntry) =>
+                    account.calendarIds.length > 0
+                      ? account.calendarIds.includes(calendar.id)
+                      : calendar.primary;
+                  return (
+                    <div key={account.email} className="rounded-md border border-gray-200 p-3 space-y-2">
+                      <div className="flex items-center justify-between">
+                        <div className="flex items-center gap-2">
+                          <Badge variant="success">Connected</Badge>
+                          <span className="text-sm text-gray-900">{account.email}</span>
+                        </div>
+                        <div className="flex gap-2">
+                          {!calendars && (
+                            <Button variant="ghost" size="sm" onClick={() => handleShowCalendars(account.email)}>
+                              Choose Calendars
+                            </Button>
+                          )}
+                          <Button variant="danger" size="sm" onClick={() => handleDisconnectGoogle(account.email)}>
+                            Disconnect
+                          </Button>
+                        </div>
+                      </div>
+                      {calendars ? (
+                        <div className="space-y-1">
+                          {calendars.map((calendar) => (
+                            <label key={calendar.id} className="flex items-center gap-2 text-sm text-gray-700">
+                              <input
+                                type="checkbox"
+                                checked={isSelected(calendar)}
+                                onChange={() => handleToggleCalendar(account, calendar)}
+                              />
+                              {calendar.summary || calendar.id}
+                              {calendar.primary && <span className="text-xs text-gray-500">(primary)</span>}
+                            </label>
+                          ))}
+                        </div>
+                      ) : (
+                        <p className="text-xs text-gray-500">
+                          {account.calendarIds.length > 0
+                            ? `${account.calendarIds.length} calendar${account.calendarIds.length === 1 ? '' : 's'} selected`
+                            : 'Primary calendar only'}
+                        </p>
+                      )}
                     </div>
-                    <p className="text-xs text-gray-500">
-                      Opens Google authorization in your browser
-                    </p>
-                  </>
-                )}
+                  );
+                })}
+                <div className="pt-2">
+                  <Button
+                    variant={googleAccounts.length > 0 ? 'ghost' : 'primary'}
+                    size="sm"
+                    onClick={handleConnectGoogle}
+                    loading={connectingGoogle}
+                    disabled={connectingGoogle}
+                  >
+                    {connectingGoogle
+                      ? 'Connecting...'
+                      : googleAccounts.length > 0 ? 'Connect Another Account' : 'Connect Google Account'}
+                  </Button>
+                </div>
+                <p className="text-xs text-gray-500">
+                  Opens Google authorization in your browser. Events that appear on several calendars
+                  are counted once.
+                </p>
               </div>
             </Card>
 
-            {/* Toggl Section */}
-            <Card title="Toggl Track">
+            {/* Meeting Classification Section */}
+            <Card title="Meeting Classification">
               <div className="space-y-4">
+                <p className="text-sm text-gray-600">
+                  Events are sorted into 1:1s, team syncs, external meetings, interviews, focus blocks
+                  and time off from their guests and event type. Keywords and people listed here take
+                  precedence.
+                </p>
+                <div className="grid grid-cols-2 gap-4">
+                  <Input
+                    label="Working day starts"
+                    value={meetingRules.workdayStart}
+                    onChange={(value) => setMeetingRules({ ...meetingRules, workdayStart: value })}
+                    placeholder="09:00"
+                    error={errors.workday}
+                  />
+                  <Input
+                    label="Working day ends"
+                    value={meetingRules.workdayEnd}
+                    onChange={(value) => setMeetingRules({ ...meetingRules, workdayEnd: value })}
+                    placeholder="17:00"
+                    helpText="Used for the longest uninterrupted stretch"
+                  />
+                </div>
                 <Input
-                  label="API Token"
-                  value={togglApiToken}
-                  onChange={setTogglApiToken}
-                  type="password"
-                  placeholder="Enter API token"
-                  helpText="Find at: https://track.toggl.com/profile (bottom of page)"
-                />
-
-                <Input
-                  label="Workspace ID"
-                  value={togglWorkspaceId}
-                  onChange={setTogglWorkspaceId}
-                  placeholder="1234567"
-                  helpText="Find this in Toggl Track settings under workspace"
+                  label="Internal domains"
+                  value={meetingRules.internalDomains.join(', ')}
+                  onChange={(value) =>
+                    setMeetingRules({
+                      ...meetingRules,
+                      internalDomains: value.split(',').map((d) => d.trimStart()),
+                    })
+                  }
+                  placeholder="acme.com, acme.co.uk"
+                  helpText="Guests from other domains make a meeting external. Blank uses your own domain."
                 />
+                {MEETING_KINDS.map(([kind, label]) => (
+                  <div key={kind} className="grid grid-cols-2 gap-4">
+                    <Input
+                      label={`${label} title keywords`}
+                      value={meetingRuleField(meetingRules, kind, 'titleKeywords')}
+                      onChange={(value) =>
+                        setMeetingRules(withMeetingRuleField(meetingRules, kind, 'titleKeywords', value))
+                      }
+                      helpText="Comma-separated"
+                    />
+                    <Input
+                      label={`${label} people`}
+                      value={meetingRuleField(meetingRules, kind, 'attendees')}
+                      onChange={(value) =>
+                        setMeetingRules(withMeetingRuleField(meetingRules, kind, 'attendees', value))
+                      }
+                      placeholder="lead@acme.com, @recruiting.acme.com"
+                      helpText="Organizer or guest; @domain matches everyone there"
+                    />
+                  </div>
+                ))}
+              </div>
+            </Card>
 
-                <div className="pt-2">
-                  <Button
-                    variant="ghost"
-                    size="sm"
-                    onClick={handleTestToggl}
-                    loading={testingToggl}
-                    disabled={!togglApiToken || !togglWorkspaceId || testingToggl}
+            {/* Time Tracking Section */}
+            <Card title="Time Tracking">
+              <div className="space-y-4">
+                <div>
+                  <label className="block text-sm font-medium text-gray-700 mb-2">
+                    Service
+                  </label>
+                  <select
+                    value={timeSource}
+                    onChange={(e) => setTimeSource(e.target.value as TimeSourceKind)}
+                    className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500"
                   >
-                    {testingToggl ? 'Testing...' : 'Test Toggl Connection'}
-                  </Button>
+                    <option value="toggl">Toggl Track</option>
+                    <option value="clockify">Clockify</option>
+                    <option value="harvest">Harvest</option>
+                    <option value="native">Built-in timer</option>
+                  </select>
                 </div>
+
+                {timeSource === 'toggl' && (
+                  <>
+                    <Input
+                      label="API Token"
+                      value={togglApiToken}
+                      onChange={setTogglApiToken}
+                      type="password"
+                      placeholder="Enter API token"
+                      helpText="Find at: https://track.toggl.com/profile (bottom of page)"
+                    />
+
+                    <Input
+                      label="Workspace ID"
+                      value={togglWorkspaceId}
+                      onChange={setTogglWorkspaceId}
+                      placeholder="1234567"
+                      helpText="Only entries from this workspace are counted; leave empty for all workspaces"
+                    />
+                  </>
+                )}
+
+                {timeSource === 'clockify' && (
+                  <>
+                    <Input
+                      label="API Key"
+                      value={clockifyApiKey}
+                      onChange={setClockifyApiKey}
+                      type="password"
+                      placeholder="Enter API key"
+                      helpText="Find at: https://app.clockify.me/user/preferences#advanced"
+                    />
+
+                    <Input
+                      label="Workspace ID"
+                      value={clockifyWorkspaceId}
+                      onChange={setClockifyWorkspaceId}
+                      placeholder="5f1a2b3c4d5e6f7a8b9c0d1e"
+                      helpText="Leave empty to use your active workspace"
+                    />
+
+                    <Input
+                      label="API Base URL (optional)"
+                      value={clockifyBaseUrl}
+                      onChange={setClockifyBaseUrl}
+                      placeholder="https://api.clockify.me"
+                      helpText="Only needed for regional or self-hosted Clockify"
+                    />
+                  </>
+                )}
+
+                {timeSource === 'harvest' && (
+                  <>
+                    <Input
+                      label="Personal Access Token"
+                      value={harvestAccessToken}
+                      onChange={setHarvestAccessToken}
+                      type="password"
+                      placeholder="Enter access token"
+                      helpText="Create at: https://id.getharvest.com/developers"
+                    />
+
+                    <Input
+                      label="Account ID"
+                      value={harvestAccountId}
+                      onChange={setHarvestAccountId}
+                      placeholder="123456"
+                      helpText="Shown next to your token on the developers page"
+                    />
+
+                    <Input
+                      label="API Base URL (optional)"
+                      value={harvestBaseUrl}
+                      onChange={setHarvestBaseUrl}
+                      placeholder="https://api.harvestapp.com"
+                    />
+                  </>
+                )}
+
+                {timeSource === 'native' && (
+                  <p className="text-sm text-gray-600">
+                    Time tracked with the app's own timer. Start a task with a Jira key to count
+                    its time towards that ticket.
+                  </p>
+                )}
+
+                {timeSource !== 'native' && (
+                  <div className="pt-2">
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={handleTestTimeSource}
+                      loading={testingTimeSource}
+                      disabled={
+                        testingTimeSource ||
+                        (timeSource === 'toggl' && !togglApiToken) ||
+                        (timeSource === 'clockify' && !clockifyApiKey) ||
+                        (timeSource === 'harvest' && (!harvestAccessToken || !harvestAccountId))
+                      }
+                    >
+                      {testingTimeSource ? 'Testing...' : 'Test Connection'}
+                    </Button>
+                  </div>
+                )}
+              </div>
+            </Card>
+
+            {/* Time Categories Section */}
+            <Card title="Time Categories">
+              <div className="space-y-4">
+                <p className="text-sm text-gray-600">
+                  Tracked time counts as focus time unless its project, client or tag is listed below.
+                </p>
+                {(['meetings', 'admin'] as const).map((category) => (
+                  <div key={category} className="grid grid-cols-2 gap-4">
+                    <Input
+                      label={`${category === 'meetings' ? 'Meeting' : 'Admin'} projects or clients`}
+                      value={categoryField(timeCategories, category, 'projects')}
+                      onChange={(value) =>
+                        setTimeCategories(withCategoryField(timeCategories, category, 'projects', value))
+                      }
+                      placeholder={category === 'meetings' ? 'Meetings' : 'Internal, Acme Corp'}
+                      helpText="Comma-separated"
+                    />
+                    <Input
+                      label={`${category === 'meetings' ? 'Meeting' : 'Admin'} tags`}
+                      value={categoryField(timeCategories, category, 'tags')}
+                      onChange={(value) =>
+                        setTimeCategories(withCategoryField(timeCategories, category, 'tags', value))
+                      }
+                      placeholder={category === 'meetings' ? 'meeting, call' : 'email, expenses'}
+                      helpText="Comma-separated"
+                    />
+                  </div>
+                ))}
               </div>
             </Card>
           </div>
@@ -769,6 +1327,11 @@ export default function SettingsPanelV2() {
                   label="Use TLS"
                   description="Enable TLS encryption (recommended)"
                 />
+                <RedactionProfileSelect
+                  profiles={redactionProfiles}
+                  value={targetRedaction.email ?? null}
+                  onChange={(id) => setRedactionFor('email', id)}
+                />
 
                 <div className="flex gap-2 pt-2">
                   <Button
@@ -809,6 +1372,11 @@ export default function SettingsPanelV2() {
                   error={errors.slackWebhookUrl}
                   required
                 />
+                <RedactionProfileSelect
+                  profiles={redactionProfiles}
+                  value={targetRedaction.slack ?? null}
+                  onChange={(id) => setRedactionFor('slack', id)}
+                />
 
                 <div className="flex gap-2 pt-2">
                   <Button
@@ -848,6 +1416,11 @@ export default function SettingsPanelV2() {
                   error={errors.fileDirectory}
                   required
                 />
+                <RedactionProfileSelect
+                  profiles={redactionProfiles}
+                  value={targetRedaction.file ?? null}
+                  onChange={(id) => setRedactionFor('file', id)}
+                />
 
                 <div className="flex gap-2 pt-2">
                   <Button
@@ -871,6 +1444,8 @@ export default function SettingsPanelV2() {
                 </div>
               </div>
             </Card>
+
+            <DeliveryTargets profiles={redactionProfiles} onToast={setToast} />
           </div>
         )}
 
@@ -900,6 +1475,47 @@ export default function SettingsPanelV2() {
                 )}
               </div>
 
+              <div>
+                <label className="block text-sm font-medium text-gray-700 mb-2">
+                  Summary Time Zone
+                </label>
+                <input
+                  type="text"
+                  value={timeZone}
+                  onChange={(e) => setTimeZone(e.target.value)}
+                  placeholder={Intl.DateTimeFormat().resolvedOptions().timeZone}
+                  className="w-full rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500"
+                />
+                <p className="mt-1 text-sm text-gray-500">
+                  IANA name such as Europe/Berlin. Jira, calendar and time tracking all use this
+                  zone; leave blank to follow this computer.
+                </p>
+              </div>
+
+              <div>
+                <label className="block text-sm font-medium text-gray-700 mb-2">
+                  Day Starts At
+                </label>
+                <input
+                  type="time"
+                  value={dayStart}
+                  onChange={(e) => setDayStart(e.target.value)}
+                  className={`rounded-md border px-3 py-2 text-sm focus:outline-none focus:ring-1 ${
+                    errors.dayStart
+                      ? 'border-red-500 focus:border-red-500 focus:ring-red-500'
+                      : 'border-gray-300 focus:border-blue-500 focus:ring-blue-500'
+                  }`}
+                />
+                {errors.dayStart ? (
+                  <p className="mt-1 text-sm text-red-600">{errors.dayStart}</p>
+                ) : (
+                  <p className="mt-1 text-sm text-gray-500">
+                    Work before this time counts toward the previous day, e.g. 04:00 if you often
+                    work past midnight
+                  </p>
+                )}
+              </div>
+
               <div>
                 <label className="block text-sm font-medium text-gray-700 mb-2">
                   Default Tone
@@ -909,9 +1525,11 @@ export default function SettingsPanelV2() {
                   onChange={(e) => setDefaultTone(e.target.value)}
                   className="rounded-md border border-gray-300 px-3 py-2 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500"
                 >
-                  <option value="professional">Professional</option>
-                  <option value="casual">Casual</option>
-                  <option value="detailed">Detailed</option>
+                  {tones.map((t) => (
+                    <option key={t.name} value={t.name}>
+                      {t.builtin ? t.name.charAt(0).toUpperCase() + t.name.slice(1) : t.name}
+                    </option>
+                  ))}
                 </select>
               </div>
 
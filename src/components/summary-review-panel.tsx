@@ -1,6 +1,6 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import type { SummaryResponse, SummaryInput } from '../types';
+import type { CheckedNarrative, SummaryResponse, SummaryInput, ToneTemplate } from '../types';
 import Toast from './toast';
 import SendModal from './send-modal';
 import TomorrowPriorities from './tomorrow-priorities';
@@ -18,7 +18,10 @@ export default function SummaryReviewPanel() {
   const [tomorrowPriorities, setTomorrowPriorities] = useState('');
   const [manualNotes, setManualNotes] = useState('');
   const [narrative, setNarrative] = useState('');
-  const [tone, setTone] = useState<'professional' | 'casual' | 'detailed'>('professional');
+  // Fact-check findings for the generated narrative; cleared by hand edits
+  const [narrativeWarnings, setNarrativeWarnings] = useState<string[]>([]);
+  const [tone, setTone] = useState('professional');
+  const [tones, setTones] = useState<ToneTemplate[]>([]);
   const [narrativeEditable, setNarrativeEditable] = useState(false);
   const [llmSlowWarningShown, setLlmSlowWarningShown] = useState(false);
   const [showSendModal, setShowSendModal] = useState(false);
@@ -26,14 +29,24 @@ export default function SummaryReviewPanel() {
   // Load today's summary on mount
   useEffect(() => {
     loadTodaySummary();
+    loadTones();
   }, []);
 
+  const loadTones = async () => {
+    try {
+      setTones(await invoke<ToneTemplate[]>('list_tones'));
+    } catch (error) {
+      console.error('Failed to load tones:', error);
+    }
+  };
+
   const loadTodaySummary = async () => {
     try {
       setLoading(true);
       const data = await invoke<SummaryResponse | null>('get_today_summary');
       if (data) {
         setSummary(data);
+        setNarrativeWarnings(data.narrativeWarnings ?? []);
         setBlockers(data.blockers || '');
         setTomorrowPriorities(data.tomorrowPriorities || '');
         setManualNotes(data.manualNotes || '');
@@ -59,6 +72,7 @@ export default function SummaryReviewPanel() {
 
       const savedSummary = await invoke<SummaryResponse>('save_summary', { input });
       setSummary(savedSummary);
+      setNarrativeWarnings(savedSummary.narrativeWarnings ?? []);
       setToast({ type: 'success', message: 'Summary saved successfully!' });
     } catch (error) {
       console.error('Failed to save summary:', error);
@@ -78,15 +92,20 @@ export default function SummaryReviewPanel() {
         setLlmSlowWarningShown(true);
       }, 8000);
 
-      const newNarrative = await invoke<string>('regenerate_narrative', {
+      const checked = await invoke<CheckedNarrative>('regenerate_narrative', {
         summaryId,
         tone: selectedTone,
       });
 
       clearTimeout(slowWarningTimeout);
-      setNarrative(newNarrative);
+      setNarrative(checked.narrative);
+      setNarrativeWarnings(checked.warnings);
       setNarrativeEditable(false);
-      setToast({ type: 'success', message: 'Narrative generated successfully!' });
+      if (checked.warnings.length > 0) {
+        setToast({ type: 'warning', message: checked.warnings.join(' • ') });
+      } else {
+        setToast({ type: 'success', message: 'Narrative generated successfully!' });
+      }
     } catch (error) {
       console.error('Failed to regenerate narrative:', error);
       setToast({
@@ -104,6 +123,7 @@ export default function SummaryReviewPanel() {
       setGenerating(true);
       const generatedSummary = await invoke<SummaryResponse>('generate_summary');
       setSummary(generatedSummary);
+      setNarrativeWarnings(generatedSummary.narrativeWarnings ?? []);
       setBlockers(generatedSummary.blockers || '');
       setTomorrowPriorities(generatedSummary.tomorrowPriorities || '');
       setManualNotes(generatedSummary.manualNotes || '');
@@ -115,7 +135,7 @@ export default function SummaryReviewPanel() {
         const warnings: string[] = [];
         if (status.jira?.status === 'Failed') warnings.push('Jira');
         if (status.calendar?.status === 'Failed') warnings.push('Calendar');
-        if (status.toggl?.status === 'Failed') warnings.push('Toggl');
+        if (status.toggl?.status === 'Failed') warnings.push('Time tracking');
 
         if (warnings.length > 0) {
           setToast({
@@ -139,7 +159,7 @@ export default function SummaryReviewPanel() {
     }
   };
 
-  const handleToneChange = async (newTone: 'professional' | 'casual' | 'detailed') => {
+  const handleToneChange = async (newTone: string) => {
     setTone(newTone);
     if (summary?.id) {
       await regenerateNarrative(summary.id, newTone);
@@ -224,13 +244,15 @@ export default function SummaryReviewPanel() {
             {/* Tone selector */}
             <select
               value={tone}
-              onChange={(e) => handleToneChange(e.target.value as 'professional' | 'casual' | 'detailed')}
+              onChange={(e) => handleToneChange(e.target.value)}
               disabled={regeneratingNarrative}
               className="rounded-md border border-gray-300 px-3 py-1 text-sm focus:border-blue-500 focus:outline-none focus:ring-1 focus:ring-blue-500 disabled:opacity-50"
             >
-              <option value="professional">Professional</option>
-              <option value="casual">Casual</option>
-              <option value="detailed">Detailed</option>
+              {tones.map((t) => (
+                <option key={t.name} value={t.name}>
+                  {t.builtin ? t.name.charAt(0).toUpperCase() + t.name.slice(1) : t.name}
+                </option>
+              ))}
             </select>
 
             {/* Edit/Save toggle */}
@@ -277,6 +299,17 @@ export default function SummaryReviewPanel() {
           placeholder="Click 'Generate Summary' to auto-generate narrative, or click 'Edit' to write manually."
         />
 
+        {narrativeWarnings.length > 0 && (
+          <div className="mt-2 rounded-md border border-yellow-200 bg-yellow-50 px-3 py-2 text-sm text-yellow-800">
+            <p className="font-medium">Check these claims against your data:</p>
+            <ul className="mt-1 list-disc pl-5">
+              {narrativeWarnings.map((warning, i) => (
+                <li key={i}>{warning}</li>
+              ))}
+            </ul>
+          </div>
+        )}
+
         {/* Retry button on error (shown via toast, but user can also retry manually) */}
         {summary?.id && !regeneratingNarrative && (
           <div className="mt-2 flex justify-end">
@@ -359,6 +392,54 @@ export default function SummaryReviewPanel() {
               <span className="font-medium text-gray-700">Focus Hours:</span>{' '}
               <span className="text-gray-900">{summary.focusHours.toFixed(1)}h</span>
             </div>
+            {(summary.timeBreakdown?.meetingMinutes ?? 0) + (summary.timeBreakdown?.adminMinutes ?? 0) > 0 && (
+              <div className="col-span-2">
+                <span className="font-medium text-gray-700">Other Tracked Time:</span>{' '}
+                <span className="text-gray-900">
+                  {(summary.timeBreakdown.meetingMinutes / 60).toFixed(1)}h meetings,{' '}
+                  {(summary.timeBreakdown.adminMinutes / 60).toFixed(1)}h admin
+                </span>
+              </div>
+            )}
+            {(summary.calendarBreakdown?.collaborationMinutes ?? 0) +
+              (summary.calendarBreakdown?.focusBlockMinutes ?? 0) > 0 && (
+              <div className="col-span-2">
+                <span className="font-medium text-gray-700">Calendar:</span>{' '}
+                <span className="text-gray-900">
+                  {(summary.calendarBreakdown.collaborationMinutes / 60).toFixed(1)}h collaboration,{' '}
+                  {(summary.calendarBreakdown.focusBlockMinutes / 60).toFixed(1)}h focus blocks,{' '}
+                  longest uninterrupted stretch {(summary.calendarBreakdown.longestGapMinutes / 60).toFixed(1)}h
+                </span>
+              </div>
+            )}
+            {summary.timeBreakdown?.projects?.length > 0 && (
+              <div className="col-span-2">
+                <span className="font-medium text-gray-700">By Project:</span>{' '}
+                <span className="text-gray-900">
+                  {summary.timeBreakdown.projects
+                    .map((p) => `${p.project} ${(p.minutes / 60).toFixed(1)}h`)
+                    .join(', ')}
+                </span>
+              </div>
+            )}
+            {summary.logEntries?.length > 0 && (
+              <div className="col-span-2">
+                <span className="font-medium text-gray-700">Activity Log:</span>
+                <ul className="mt-1 space-y-0.5 text-gray-900">
+                  {summary.logEntries.map((entry) => (
+                    <li key={entry.id}>
+                      <span className="text-gray-500">
+                        {new Date(entry.loggedAt).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' })}
+                      </span>{' '}
+                      {entry.ticketKey && !entry.text.includes(entry.ticketKey) && (
+                        <span className="font-mono text-xs">{entry.ticketKey} </span>
+                      )}
+                      {entry.text}
+                    </li>
+                  ))}
+                </ul>
+              </div>
+            )}
           </div>
 
           {/* Source status badges */}
@@ -387,7 +468,7 @@ export default function SummaryReviewPanel() {
                 summary.sourcesStatus.toggl.status === 'Failed' ? 'bg-red-100 text-red-800' :
                 'bg-gray-100 text-gray-600'
               }`}>
-                Toggl: {summary.sourcesStatus.toggl.status === 'NotConfigured' ? 'Not configured' : summary.sourcesStatus.toggl.status}
+                Time tracking: {summary.sourcesStatus.toggl.status === 'NotConfigured' ? 'Not configured' : summary.sourcesStatus.toggl.status}
               </span>
             )}
           </div>
@@ -6,6 +6,21 @@ interface Ticket {
   status: string;
   url: string;
   resolvedAt?: string;
+  activity?: TicketActivity;
+}
+
+interface StatusTransition {
+  from: string;
+  to: string;
+  at: string;
+}
+
+interface TicketActivity {
+  transitions: StatusTransition[];
+  edits: number;
+  commentsAuthored: number;
+  timeLoggedMinutes: number;
+  timeTrackedMinutes: number;
 }
 
 interface Meeting {
@@ -13,6 +28,11 @@ interface Meeting {
   start: string;
   end: string;
   durationMinutes: number;
+  attendeeCount?: number;
+  organizer?: string | null;
+  organizedByMe?: boolean;
+  hasConferenceLink?: boolean;
+  kind?: MeetingKind;
 }
 
 type SourceStatus = 'Ok' | 'Failed' | 'NotConfigured';
@@ -26,6 +46,8 @@ interface DataSourcesStatus {
 interface SourceStatusDetail {
   status: SourceStatus;
   fetchedAt?: string;
+  /** Parts that couldn't be read, e.g. one of several calendars */
+  skipped?: string[];
   error?: string;
 }
 
@@ -36,10 +58,19 @@ export interface SummaryResponse {
   ticketsInProgress: Ticket[];
   meetings: Meeting[];
   focusHours: number;
+  timeBreakdown: TimeBreakdown;
+  logEntries: LogEntry[];
+  calendarBreakdown: CalendarBreakdown;
+  /** IANA zone the day was cut in, or the computer's UTC offset ("UTC+02:00") */
+  timeZone: string;
+  /** HH:MM the summary day started */
+  dayStart: string;
   blockers: string;
   tomorrowPriorities: string;
   manualNotes: string;
   narrative: string;
+  narrativeWarnings: string[];
+  narrativeStructured: StructuredNarrative | null;
   tone: string;
   deliveredTo: string[];
   createdAt: string;
@@ -76,9 +107,163 @@ export interface Settings {
   llmModel: string;
   llmTemperature: number;
   llmTimeoutSecs: number;
+  llmOutputFormat: 'prose' | 'structured';
+  llmNumCtx: number;
+  llmRedactionProfileId: number | null;
   calendarSource: string;
   retentionDays: number;
   jiraBaseUrl: string | null;
   jiraProjectKey: string | null;
+  jiraJql: string | null;
+  jiraAuthMode: 'cloud' | 'datacenter';
+  jiraCaCertPath: string | null;
+  timeCategories: TimeCategories;
   togglWorkspaceId: string | null;
+  timeSource: TimeSourceKind;
+  clockifyWorkspaceId: string | null;
+  clockifyBaseUrl: string | null;
+  harvestAccountId: string | null;
+  harvestBaseUrl: string | null;
+  meetingRules: MeetingRules;
+  /** IANA zone summary days are cut in; null follows the computer */
+  timeZone: string | null;
+  /** HH:MM a summary day starts, before 12:00 */
+  dayStart: string;
+}
+
+/** Note captured during the day; loggedAt is RFC 3339 in the summary time zone */
+export interface LogEntry {
+  id: number;
+  loggedAt: string;
+  text: string;
+  ticketKey: string | null;
+  tags: string[];
+}
+
+export type TimeSourceKind = 'toggl' | 'clockify' | 'harvest' | 'native';
+
+export interface TimerTask {
+  description: string;
+  jiraKey: string | null;
+  project: string | null;
+  tags: string[];
+}
+
+/** Built-in timer entry; timestamps are RFC 3339 UTC */
+export interface TimerEntry extends TimerTask {
+  id: number;
+  startedAt: string;
+  stoppedAt: string | null;
+}
+
+export interface StructuredNarrative {
+  accomplishments: string[];
+  inProgress: string[];
+  collaboration: string[];
+  risks: string[];
+  tomorrow: string[];
+}
+
+export interface CheckedNarrative {
+  narrative: string;
+  structured: StructuredNarrative | null;
+  warnings: string[];
+}
+
+/** Built-in tones have no id and cannot be edited */
+export interface ToneTemplate {
+  id: number | null;
+  name: string;
+  template: string;
+  builtin: boolean;
+}
+
+export interface RedactionRules {
+  patterns: string[];
+  keywords: string[];
+  privateCalendars: string[];
+  maskPrivateEvents: boolean;
+  securityLevels: string[];
+  replacement: string;
+}
+
+export interface RedactionProfile {
+  id: number;
+  name: string;
+  rules: RedactionRules;
+}
+
+export interface RedactionPreview {
+  markdown: string;
+  redactions: { field: string; rule: string; original: string }[];
+}
+
+export type TimeCategory = 'focus' | 'meetings' | 'admin';
+
+export interface CategoryRule {
+  category: TimeCategory;
+  projects: string[];
+  clients: string[];
+  tags: string[];
+}
+
+export interface TimeCategories {
+  rules: CategoryRule[];
+}
+
+export interface ProjectTime {
+  project: string;
+  client?: string | null;
+  minutes: number;
+}
+
+export interface TimeBreakdown {
+  focusMinutes: number;
+  meetingMinutes: number;
+  adminMinutes: number;
+  projects: ProjectTime[];
+}
+
+export type MeetingKind =
+  | 'oneOnOne'
+  | 'teamSync'
+  | 'external'
+  | 'interview'
+  | 'focusBlock'
+  | 'outOfOffice'
+  | 'other';
+
+/** Addresses match case-insensitively; "@acme.com" matches a whole domain */
+export interface MeetingRule {
+  kind: MeetingKind;
+  titleKeywords: string[];
+  organizers: string[];
+  attendees: string[];
+}
+
+export interface MeetingRules {
+  rules: MeetingRule[];
+  internalDomains: string[];
+  /** Local HH:MM */
+  workdayStart: string;
+  workdayEnd: string;
+}
+
+export interface GoogleAccount {
+  email: string;
+  /** Empty means the primary calendar only */
+  calendarIds: string[];
+}
+
+export interface CalendarListEntry {
+  id: string;
+  summary: string;
+  primary: boolean;
+}
+
+export interface CalendarBreakdown {
+  collaborationMinutes: number;
+  focusBlockMinutes: number;
+  outOfOfficeMinutes: number;
+  longestGapMinutes: number;
 }